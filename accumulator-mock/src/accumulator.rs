@@ -0,0 +1,42 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+// Stands in for the XOXNO accumulator, only keeps track of the fees it receives
+#[multiversx_sc::derive::contract]
+pub trait AccumulatorMock {
+    #[init]
+    fn init(
+        &self,
+        xoxno_liquid_sc: ManagedAddress,
+        _burn_rate: BigUint,
+        _share_rate: BigUint,
+        _reward_token: EgldOrEsdtTokenIdentifier,
+        _liquid_reward_token: TokenIdentifier,
+        _ash_sc: ManagedAddress,
+    ) {
+        self.liquid_sc().set(xoxno_liquid_sc);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[payable("*")]
+    #[endpoint(deposit)]
+    fn deposit(&self) {
+        let payment = self.call_value().egld_or_single_esdt();
+        self.accumulated_token_balance(&payment.token_identifier)
+            .update(|value| *value += &payment.amount);
+    }
+
+    #[view(getLiquidSC)]
+    #[storage_mapper("liquidSC")]
+    fn liquid_sc(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getAccumulatedTokenBalance)]
+    #[storage_mapper("accumulatedTokenBalance")]
+    fn accumulated_token_balance(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+}
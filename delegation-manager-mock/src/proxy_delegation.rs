@@ -108,4 +108,99 @@ where
             .raw_call("claimRewards")
             .original_result()
     }
+
+    pub fn slash<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("slash")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn get_user_active_stake<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        _delegator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserActiveStake")
+            .argument(&_delegator)
+            .original_result()
+    }
+
+    pub fn set_contract_config<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        service_fee: Arg0,
+        max_delegation_cap: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setContractConfig")
+            .argument(&service_fee)
+            .argument(&max_delegation_cap)
+            .original_result()
+    }
+
+    pub fn set_contract_owner<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setContractOwner")
+            .argument(&owner)
+            .original_result()
+    }
+
+    pub fn set_node_states<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>>>>,
+    >(
+        self,
+        node_states: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setNodeStates")
+            .argument(&node_states)
+            .original_result()
+    }
+
+    pub fn get_contract_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue10<ManagedAddress<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractConfig")
+            .original_result()
+    }
+
+    pub fn get_total_active_stake(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalActiveStake")
+            .original_result()
+    }
+
+    pub fn get_all_node_states(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllNodeStates")
+            .original_result()
+    }
 }
@@ -26,6 +26,13 @@ pub trait DelegationMock {
     #[endpoint(delegate)]
     fn delegate(&self) {
         let payment_amount = self.call_value().egld().clone_value();
+        let max_delegation_cap = self.max_delegation_cap().get();
+        require!(
+            max_delegation_cap == 0
+                || self.get_total_active_stake() + &payment_amount <= max_delegation_cap,
+            "Delegation cap reached"
+        );
+
         self.address_deposit()
             .update(|value| *value += &payment_amount);
         self.egld_token_supply()
@@ -92,6 +99,124 @@ pub trait DelegationMock {
         BigUint::zero()
     }
 
+    // Slashes the delegated stake, to simulate a faulty provider
+    #[only_owner]
+    #[endpoint(slash)]
+    fn slash(&self, amount: BigUint) {
+        let total_deposit = self.address_deposit().get();
+        require!(amount <= total_deposit, "Invalid slash amount");
+        self.address_deposit().update(|value| *value -= &amount);
+        self.egld_token_supply().update(|value| *value -= &amount);
+    }
+
+    // The mock keeps a single delegator
+    #[view(getUserActiveStake)]
+    fn get_user_active_stake(&self, _delegator: ManagedAddress) -> BigUint {
+        self.address_deposit().get()
+    }
+
+    // A zero cap means the contract is not capped
+    #[only_owner]
+    #[endpoint(setContractConfig)]
+    fn set_contract_config(&self, service_fee: BigUint, max_delegation_cap: BigUint) {
+        self.service_fee().set(service_fee);
+        self.max_delegation_cap().set(max_delegation_cap);
+    }
+
+    // Reported as the owner by `getContractConfig` instead of the deployer
+    #[only_owner]
+    #[endpoint(setContractOwner)]
+    fn set_contract_owner(&self, owner: ManagedAddress) {
+        self.contract_owner().set(owner);
+    }
+
+    // Pairs of BLS key and node state
+    #[only_owner]
+    #[endpoint(setNodeStates)]
+    fn set_node_states(
+        &self,
+        node_states: MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>>,
+    ) {
+        self.node_states().clear();
+        for node_state in node_states {
+            let (bls_key, state) = node_state.into_tuple();
+            self.node_states().insert(bls_key, state);
+        }
+    }
+
+    // Same layout as the system delegation contract
+    #[view(getContractConfig)]
+    fn get_contract_config(
+        &self,
+    ) -> MultiValue10<
+        ManagedAddress,
+        BigUint,
+        BigUint,
+        BigUint,
+        ManagedBuffer,
+        ManagedBuffer,
+        ManagedBuffer,
+        ManagedBuffer,
+        u64,
+        u64,
+    > {
+        let max_delegation_cap = self.max_delegation_cap().get();
+        let with_delegation_cap: &[u8] = if max_delegation_cap > 0u64 {
+            b"true"
+        } else {
+            b"false"
+        };
+
+        let owner = if self.contract_owner().is_empty() {
+            self.blockchain().get_owner_address()
+        } else {
+            self.contract_owner().get()
+        };
+
+        (
+            owner,
+            self.service_fee().get(),
+            max_delegation_cap,
+            BigUint::zero(),
+            ManagedBuffer::from(b"true"),
+            ManagedBuffer::from(with_delegation_cap),
+            ManagedBuffer::from(b"true"),
+            ManagedBuffer::from(b"false"),
+            0u64,
+            UNBOND_PERIOD,
+        )
+            .into()
+    }
+
+    #[view(getTotalActiveStake)]
+    fn get_total_active_stake(&self) -> BigUint {
+        self.egld_token_supply().get() - self.address_undelegate_amount().get()
+    }
+
+    // Same layout as the system delegation contract, each node state is followed by the
+    // BLS keys of the nodes in that state
+    #[view(getAllNodeStates)]
+    fn get_all_node_states(&self) -> MultiValueEncoded<ManagedBuffer> {
+        let mut states = ManagedVec::<Self::Api, ManagedBuffer>::new();
+        for state in self.node_states().values() {
+            if !states.contains(&state) {
+                states.push(state);
+            }
+        }
+
+        let mut node_states = MultiValueEncoded::new();
+        for state in states.iter() {
+            node_states.push(state.clone_value());
+            for (bls_key, key_state) in self.node_states().iter() {
+                if key_state == *state {
+                    node_states.push(bls_key);
+                }
+            }
+        }
+
+        node_states
+    }
+
     #[storage_mapper("egldTokenSupply")]
     fn egld_token_supply(&self) -> SingleValueMapper<BigUint>;
 
@@ -106,4 +231,16 @@ pub trait DelegationMock {
 
     #[storage_mapper("addressUndelegateEpoch")]
     fn address_undelegate_epoch(&self) -> SingleValueMapper<Epoch>;
+
+    #[storage_mapper("serviceFee")]
+    fn service_fee(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("maxDelegationCap")]
+    fn max_delegation_cap(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("contractOwner")]
+    fn contract_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("nodeStates")]
+    fn node_states(&self) -> MapMapper<ManagedBuffer, ManagedBuffer>;
 }
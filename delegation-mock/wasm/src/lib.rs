@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            5
+// Endpoints:                           13
 // Async Callback (empty):               1
-// Total number of exported functions:   7
+// Total number of exported functions:  15
 
 #![no_std]
 
@@ -23,6 +23,14 @@ multiversx_sc_wasm_adapter::endpoints! {
         unDelegate => undelegate
         withdraw => withdraw
         claimRewards => claim_rewards
+        slash => slash
+        getUserActiveStake => get_user_active_stake
+        setContractConfig => set_contract_config
+        setContractOwner => set_contract_owner
+        setNodeStates => set_node_states
+        getContractConfig => get_contract_config
+        getTotalActiveStake => get_total_active_stake
+        getAllNodeStates => get_all_node_states
     )
 }
 
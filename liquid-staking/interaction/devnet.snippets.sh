@@ -163,35 +163,60 @@ updateMaxSelectedProviders() {
         --send || return
 }
 
-setUnbondPeriod() {
+# ConfigChange::UnbondPeriod, applied by executeConfigChange once the timelock delay has passed
+proposeUnbondPeriod() {
+    UNBOND_PERIOD_HEX=$(printf "%016x" ${UNBOND_PERIOD})
     mxpy contract call ${ADDRESS} --recall-nonce \
         --ledger --ledger-account-index=0 --ledger-address-index=0 \
         --proxy=${PROXY} --chain=D \
         --gas-limit=10000000 \
-        --function="setUnbondPeriod" \
-        --arguments ${UNBOND_PERIOD} \
+        --function="proposeConfigChange" \
+        --arguments 0x01${UNBOND_PERIOD_HEX} \
         --send || return
 }
 
-setManagers() {
-    MANAGER_ADDRESS="erd1fmd662htrgt07xxd8me09newa9s0euzvpz3wp0c4pz78f83grt9qm6pn57"
-    MANAGER_ADDRESS2="erd1vn9s8uj4e7r6skmqfw5py3hxnluw3ftv6dh47yt449vtvdnn9w2stmwm7l"
-    MANAGER_ADDRESS3="erd1cfyadenn4k9wndha0ljhlsdrww9k0jqafqq626hu9zt79urzvzasalgycz"
+# Usage: executeConfigChange <proposal_id>
+executeConfigChange() {
+    mxpy contract call ${ADDRESS} --recall-nonce \
+        --ledger --ledger-account-index=0 --ledger-address-index=0 \
+        --proxy=${PROXY} --chain=D \
+        --gas-limit=20000000 \
+        --function="executeConfigChange" \
+        --arguments ${1} \
+        --send || return
+}
+
+# Roles: 0 = ProviderCurator, 1 = ScoringAdmin, 2 = Keeper, 3 = Guardian
+# Usage: grantRole <address> <role>
+grantRole() {
     mxpy contract call ${ADDRESS} --recall-nonce \
         --ledger --ledger-account-index=0 --ledger-address-index=0 \
         --proxy=${PROXY} --chain=D \
         --gas-limit=12000000 \
-        --function="setManagers" \
-        --arguments ${MANAGER_ADDRESS} ${MANAGER_ADDRESS2} ${MANAGER_ADDRESS3} \
+        --function="grantRole" \
+        --arguments ${1} ${2} \
         --send || return
 }
 
-addMigrationScAddress() {
+grantManagerRoles() {
+    MANAGER_ADDRESS="erd1fmd662htrgt07xxd8me09newa9s0euzvpz3wp0c4pz78f83grt9qm6pn57"
+    MANAGER_ADDRESS2="erd1vn9s8uj4e7r6skmqfw5py3hxnluw3ftv6dh47yt449vtvdnn9w2stmwm7l"
+    MANAGER_ADDRESS3="erd1cfyadenn4k9wndha0ljhlsdrww9k0jqafqq626hu9zt79urzvzasalgycz"
+    for MANAGER in ${MANAGER_ADDRESS} ${MANAGER_ADDRESS2} ${MANAGER_ADDRESS3}; do
+        for ROLE in 0 1 2 3; do
+            grantRole ${MANAGER} ${ROLE} || return
+        done
+    done
+}
+
+# ConfigChange::MigrationScAddress, applied by executeConfigChange once the timelock delay has passed
+proposeMigrationScAddress() {
+    MIGRATION_SC_ADDRESS_HEX=$(mxpy wallet bech32 --decode ${MIGRATION_SC_ADDRESS})
     mxpy contract call ${ADDRESS} --recall-nonce \
         --ledger --ledger-account-index=0 --ledger-address-index=0 \
         --proxy=${PROXY} --chain=D \
         --gas-limit=12000000 \
-        --function="addMigrationScAddress" \
-        --arguments ${MIGRATION_SC_ADDRESS} \
+        --function="proposeConfigChange" \
+        --arguments 0x04${MIGRATION_SC_ADDRESS_HEX} \
         --send || return
 }
\ No newline at end of file
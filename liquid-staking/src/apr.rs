@@ -0,0 +1,199 @@
+multiversx_sc::imports!();
+use crate::{
+    constants::{BPS, EPOCHS_PER_YEAR, MAX_EPOCH_SNAPSHOTS, REALIZED_APR_EPOCHS},
+    errors::{
+        ERROR_INVALID_APR_WINDOW, ERROR_NOT_ENOUGH_EPOCH_SNAPSHOTS, ERROR_NOT_WHITELISTED,
+        ERROR_NO_PROVIDER_YIELD,
+    },
+    structs::{EpochSnapshot, ProviderYield},
+    StorageCache,
+};
+
+#[multiversx_sc::module]
+pub trait AprModule: crate::storage::StorageModule + crate::config::ConfigModule {
+    /// Realized APR of xEGLD in BPS, annualized from the exchange rate growth between
+    /// the latest epoch snapshot and the newest one taken at least `epochs` epochs before.
+    #[view(getRealizedApr)]
+    fn get_realized_apr(&self, epochs: u64) -> u64 {
+        let realized_apr = self.compute_realized_apr(epochs);
+        require!(realized_apr.is_some(), ERROR_NOT_ENOUGH_EPOCH_SNAPSHOTS);
+
+        realized_apr.unwrap()
+    }
+
+    /// Realized yield of a provider in BPS, annualized from the rewards claimed from it
+    /// against the stake of the contract on it. The last window of at least
+    /// `REALIZED_APR_EPOCHS` epochs is used, or the current one until a window is complete.
+    #[view(getProviderRealizedApy)]
+    fn get_provider_realized_apy(&self, delegation_address: ManagedAddress) -> u64 {
+        require!(
+            !self
+                .delegation_contract_data(&delegation_address)
+                .is_empty(),
+            ERROR_NOT_WHITELISTED
+        );
+
+        let realized_apy = self.compute_provider_realized_apy(&delegation_address);
+        require!(realized_apy.is_some(), ERROR_NO_PROVIDER_YIELD);
+
+        realized_apy.unwrap()
+    }
+
+    /// Returns the last `count` epoch snapshots, ordered from the oldest to the newest.
+    #[view(getEpochSnapshots)]
+    fn get_epoch_snapshots(&self, count: usize) -> MultiValueEncoded<EpochSnapshot<Self::Api>> {
+        let snapshots = self.get_ordered_epoch_snapshots();
+        let len = snapshots.len();
+        let skip = len.saturating_sub(count);
+
+        let mut result = MultiValueEncoded::new();
+        for snapshot in snapshots.iter().skip(skip) {
+            result.push(snapshot.clone());
+        }
+        result
+    }
+
+    fn record_epoch_snapshot(&self, storage_cache: &StorageCache<Self>, rewards: &BigUint) {
+        let epoch = self.blockchain().get_block_epoch();
+        let mut snapshots = self.epoch_snapshots();
+        let len = snapshots.len();
+        // The cursor stays at 0 until the ring buffer is full
+        let cursor_mapper = self.epoch_snapshots_cursor();
+        let cursor = cursor_mapper.get();
+
+        let mut snapshot = EpochSnapshot {
+            epoch,
+            virtual_egld_reserve: storage_cache.virtual_egld_reserve.clone(),
+            ls_token_supply: storage_cache.ls_token_supply.clone(),
+            rewards: rewards.clone(),
+        };
+
+        // Several rewards bookings in the same epoch are merged in a single snapshot
+        if len > 0 {
+            let newest_index = if cursor == 0 { len } else { cursor };
+            let last_snapshot = snapshots.get(newest_index);
+            if last_snapshot.epoch == epoch {
+                snapshot.rewards += &last_snapshot.rewards;
+                snapshots.set(newest_index, &snapshot);
+                return;
+            }
+        }
+
+        if len < MAX_EPOCH_SNAPSHOTS {
+            snapshots.push(&snapshot);
+            return;
+        }
+
+        // Overwrite the oldest snapshot and move the cursor to the next oldest one
+        snapshots.set(cursor + 1, &snapshot);
+        cursor_mapper.set((cursor + 1) % MAX_EPOCH_SNAPSHOTS);
+    }
+
+    fn get_ordered_epoch_snapshots(&self) -> ManagedVec<EpochSnapshot<Self::Api>> {
+        let snapshots = self.epoch_snapshots();
+        let len = snapshots.len();
+        let oldest = self.epoch_snapshots_cursor().get();
+
+        let mut ordered = ManagedVec::new();
+        for offset in 0..len {
+            ordered.push(snapshots.get((oldest + offset) % len + 1));
+        }
+        ordered
+    }
+
+    fn compute_realized_apr(&self, epochs: u64) -> Option<u64> {
+        require!(epochs > 0, ERROR_INVALID_APR_WINDOW);
+
+        let snapshots = self.get_ordered_epoch_snapshots();
+        let len = snapshots.len();
+        if len < 2 {
+            return None;
+        }
+
+        let latest = snapshots.get(len - 1).clone();
+        if latest.epoch < epochs {
+            return None;
+        }
+        let start_epoch = latest.epoch - epochs;
+
+        // Walk back to the newest snapshot taken at or before the start of the window
+        let mut index = len - 1;
+        let start = loop {
+            if index == 0 {
+                return None;
+            }
+            index -= 1;
+            let snapshot = snapshots.get(index);
+            if snapshot.epoch <= start_epoch {
+                break snapshot.clone();
+            }
+        };
+
+        let start_rate = start.exchange_rate();
+        let latest_rate = latest.exchange_rate();
+        if latest_rate <= start_rate {
+            return Some(0);
+        }
+
+        let elapsed_epochs = latest.epoch - start.epoch;
+        let realized_apr =
+            (latest_rate - &start_rate) * BPS * EPOCHS_PER_YEAR / (start_rate * elapsed_epochs);
+
+        Some(realized_apr.to_u64().unwrap_or(u64::MAX))
+    }
+
+    /// Books the rewards claimed from a provider against the stake the contract held on it
+    /// since the previous claim. A window of at least `REALIZED_APR_EPOCHS` epochs is closed
+    /// into the realized yield of the provider and a new one is started.
+    fn record_provider_rewards(
+        &self,
+        delegation_address: &ManagedAddress,
+        rewards: &BigUint,
+        staked: &BigUint,
+    ) {
+        let epoch = self.blockchain().get_block_epoch();
+        let yield_mapper = self.provider_yield(delegation_address);
+        if yield_mapper.is_empty() {
+            // Rewards accrue from the whitelisting on
+            let scoring_data_mapper = self.provider_scoring_data(delegation_address);
+            let added_epoch = if scoring_data_mapper.is_empty() {
+                None
+            } else {
+                scoring_data_mapper.get().added_epoch
+            };
+            yield_mapper.set(ProviderYield::new(added_epoch.unwrap_or(epoch)));
+        }
+
+        yield_mapper.update(|provider_yield| {
+            let elapsed_epochs = epoch - provider_yield.last_claim_epoch;
+            if elapsed_epochs == 0 && rewards == &BigUint::zero() {
+                return;
+            }
+
+            provider_yield.window_rewards += rewards;
+            provider_yield.window_stake_epochs += staked * &BigUint::from(elapsed_epochs);
+            provider_yield.last_claim_epoch = epoch;
+
+            if epoch - provider_yield.window_start_epoch >= REALIZED_APR_EPOCHS {
+                if let Some(window_apy) = provider_yield.window_apy() {
+                    provider_yield.realized_apy = Some(window_apy);
+                }
+                provider_yield.window_start_epoch = epoch;
+                provider_yield.window_rewards = BigUint::zero();
+                provider_yield.window_stake_epochs = BigUint::zero();
+            }
+        });
+    }
+
+    fn compute_provider_realized_apy(&self, delegation_address: &ManagedAddress) -> Option<u64> {
+        let yield_mapper = self.provider_yield(delegation_address);
+        if yield_mapper.is_empty() {
+            return None;
+        }
+
+        let provider_yield = yield_mapper.get();
+        provider_yield
+            .realized_apy
+            .or_else(|| provider_yield.window_apy())
+    }
+}
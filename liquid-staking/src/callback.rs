@@ -1,5 +1,16 @@
 multiversx_sc::imports!();
-use crate::StorageCache;
+use crate::{
+    errors::ERROR_PROVIDER_APPLICATION_NOT_FOUND,
+    structs::{
+        DelegationContractData, DelegationFailures, FeeMode, PendingUnbond, ProviderApplication,
+        ProviderScoringData,
+    },
+    StorageCache, CONTRACT_CONFIG_MAX_DELEGATION_CAP_INDEX, CONTRACT_CONFIG_OWNER_INDEX,
+    CONTRACT_CONFIG_SERVICE_FEE_INDEX, CONTRACT_CONFIG_TRUE,
+    CONTRACT_CONFIG_WITH_DELEGATION_CAP_INDEX, DELEGATION_FAILURE_COOLDOWN_EPOCHS,
+    MAX_DELEGATION_FAILURE_COOLDOWN_EPOCHS, MIN_EGLD_TO_DELEGATE, NODE_STATE_JAILED,
+    NODE_STATE_NOT_STAKED, NODE_STATE_STAKED, NODE_STATE_UNSTAKED,
+};
 
 #[multiversx_sc::module]
 pub trait CallbackModule:
@@ -10,6 +21,11 @@ pub trait CallbackModule:
     + crate::utils::generic::UtilsModule
     + crate::selection::SelectionModule
     + crate::liquidity_pool::LiquidityPoolModule
+    + crate::apr::AprModule
+    + crate::utils::un_delegation::UnDelegateUtilsModule
+    + crate::withdraw_queue::WithdrawQueueModule
+    + crate::referral::ReferralModule
+    + crate::insurance::InsuranceModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     #[promises_callback]
@@ -23,6 +39,7 @@ pub trait CallbackModule:
         match result {
             ManagedAsyncCallResult::Ok(()) => {
                 storage_cache.pending_egld_for_unbond += egld_to_unstake;
+                self.record_pending_unbond(delegation_contract, egld_to_unstake);
                 self.delegation_contract_data(delegation_contract)
                     .update(|contract_data| {
                         contract_data.total_staked_from_ls_contract -= egld_to_unstake;
@@ -48,60 +65,666 @@ pub trait CallbackModule:
         staked_tokens: &BigUint,
         #[call_result] result: ManagedAsyncCallResult<()>,
     ) {
-        let mut storage_cache = StorageCache::new(self);
         match result {
             ManagedAsyncCallResult::Ok(()) => {
-                self.delegation_contract_data(delegation_contract)
-                    .update(|contract_data| {
-                        contract_data.total_staked_from_ls_contract += staked_tokens;
-                        contract_data.pending_staking_callback_amount -= staked_tokens;
-                    });
+                self.book_delegation(delegation_contract, staked_tokens);
             }
             ManagedAsyncCallResult::Err(_) => {
+                let mut storage_cache = StorageCache::new(self);
                 storage_cache.pending_egld += staked_tokens;
-                self.delegation_contract_data(delegation_contract)
-                    .update(|contract_data| {
-                        contract_data.eligible = false;
-                        contract_data.pending_staking_callback_amount -= staked_tokens;
-                    });
+                self.book_failed_delegation(delegation_contract, staked_tokens);
                 self.emit_general_liquidity_event(&storage_cache);
             }
         }
     }
 
+    /// Books a delegation of evacuated stake, a failed one goes back to the rebalance bucket.
+    #[promises_callback]
+    fn rebalance_delegation_callback(
+        &self,
+        delegation_contract: &ManagedAddress,
+        staked_tokens: &BigUint,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                self.book_delegation(delegation_contract, staked_tokens);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.rebalance_pending_egld()
+                    .update(|rebalance_pending| *rebalance_pending += staked_tokens);
+                self.book_failed_delegation(delegation_contract, staked_tokens);
+            }
+        }
+    }
+
+    fn book_delegation(&self, delegation_contract: &ManagedAddress, staked_tokens: &BigUint) {
+        self.delegation_contract_data(delegation_contract)
+            .update(|contract_data| {
+                contract_data.total_staked_from_ls_contract += staked_tokens;
+                contract_data.pending_staking_callback_amount -= staked_tokens;
+            });
+
+        let failures_mapper = self.delegation_failures(delegation_contract);
+        if !failures_mapper.is_empty() {
+            failures_mapper.update(|failures| failures.consecutive_failures = 0);
+        }
+    }
+
+    fn book_failed_delegation(
+        &self,
+        delegation_contract: &ManagedAddress,
+        staked_tokens: &BigUint,
+    ) {
+        let was_eligible =
+            self.delegation_contract_data(delegation_contract)
+                .update(|contract_data| {
+                    contract_data.pending_staking_callback_amount -= staked_tokens;
+                    core::mem::replace(&mut contract_data.eligible, false)
+                });
+        self.record_delegation_failure(delegation_contract, was_eligible);
+    }
+
+    /// Makes a provider ineligible for a cooldown doubling with every failure since its last
+    /// successful delegation. Only a provider eligible until the failure, or already cooling
+    /// down, is re-admitted automatically: other ineligibility reasons are left untouched.
+    fn record_delegation_failure(&self, delegation_contract: &ManagedAddress, was_eligible: bool) {
+        let failures_mapper = self.delegation_failures(delegation_contract);
+        let mut failures = if failures_mapper.is_empty() {
+            DelegationFailures::default()
+        } else {
+            failures_mapper.get()
+        };
+
+        let mut cooling_down_providers = self.cooling_down_providers();
+        let cooling_down = cooling_down_providers.contains(delegation_contract);
+        let current_epoch = self.blockchain().get_block_epoch();
+        failures.total_failures += 1;
+        failures.last_failure_epoch = current_epoch;
+
+        // Delegations sent before the provider went cooling down do not extend the cooldown
+        if was_eligible {
+            failures.consecutive_failures += 1;
+            let backoff = (failures.consecutive_failures - 1).min(32);
+            let cooldown = DELEGATION_FAILURE_COOLDOWN_EPOCHS
+                .saturating_mul(1u64 << backoff)
+                .min(MAX_DELEGATION_FAILURE_COOLDOWN_EPOCHS);
+            failures.readmission_epoch = current_epoch + cooldown;
+            cooling_down_providers.insert(delegation_contract.clone());
+        } else if !cooling_down {
+            failures.readmission_epoch = 0;
+        }
+
+        failures_mapper.set(&failures);
+        self.provider_delegation_failed_event(delegation_contract, &failures);
+    }
+
+    /// Stops the automatic re-admission of a provider made ineligible for another reason.
+    fn cancel_delegation_cooldown(&self, delegation_contract: &ManagedAddress) {
+        if self.cooling_down_providers().remove(delegation_contract) {
+            self.delegation_failures(delegation_contract)
+                .update(|failures| failures.readmission_epoch = 0);
+        }
+    }
+
     #[promises_callback]
     fn withdraw_tokens_callback(&self, delegation_contract: &ManagedAddress) {
         let withdraw_amount = self.call_value().egld().clone_value();
         if withdraw_amount > BigUint::zero() {
+            // Only the seed is left on a provider whose seed is un-delegated
+            if !self
+                .provider_seed_unbond_epoch(delegation_contract)
+                .is_empty()
+            {
+                self.return_provider_seed(delegation_contract, &withdraw_amount);
+                return;
+            }
+
             let mut storage_cache = StorageCache::new(self);
             let delegation_contract_mapper = self.delegation_contract_data(delegation_contract);
 
-            storage_cache.total_withdrawn_egld += &withdraw_amount;
             storage_cache.pending_egld_for_unbond -= &withdraw_amount;
 
+            // Evacuated stake is staked again on the providers under their target share
+            let evacuated_amount =
+                self.take_evacuated_amount(delegation_contract, &withdraw_amount);
+            self.rebalance_pending_egld()
+                .update(|rebalance_pending| *rebalance_pending += &evacuated_amount);
+            let owed_amount = &withdraw_amount - &evacuated_amount;
+
+            // The matured claims are paid first, only then does the unbonded EGLD of early
+            // redeemed positions go back to the pool
+            storage_cache.total_withdrawn_egld += &owed_amount;
+            self.fill_withdraw_tickets(&mut storage_cache);
+            let restake_amount = self
+                .take_matured_restake(&owed_amount.min(storage_cache.total_withdrawn_egld.clone()));
+            storage_cache.total_withdrawn_egld -= &restake_amount;
+            storage_cache.pending_egld += &restake_amount;
+
             delegation_contract_mapper.update(|contract_data| {
                 contract_data.total_unstaked_from_ls_contract -= &withdraw_amount;
             });
+            self.consume_pending_unbonds(delegation_contract, &withdraw_amount);
             self.emit_withdraw_pending_event(&storage_cache, &withdraw_amount, delegation_contract);
         }
     }
 
+    /// Returns the unbonded EGLD of early redeemed positions to restake, at most `available`.
+    /// A position is only restaked once its unbond epoch passed, so it never takes the EGLD
+    /// owed to positions that matured before it.
+    fn take_matured_restake(&self, available: &BigUint) -> BigUint {
+        let restake_unbonds_mapper = self.pending_restake_unbonds();
+        if restake_unbonds_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let matured_restake =
+            self.sum_matured_unbonds(&restake_unbonds_mapper.get(), current_epoch);
+        let restake_amount = matured_restake.min(available.clone());
+        if restake_amount > BigUint::zero() {
+            // The ledger is ordered by unbond epoch, the matured entries are consumed first
+            self.consume_unbonds(restake_unbonds_mapper, &restake_amount);
+            self.pending_egld_for_restake()
+                .update(|pending_restake| *pending_restake -= &restake_amount);
+        }
+
+        restake_amount
+    }
+
+    /// Returns the evacuated stake paid by a withdraw. The matured un-delegations owed to
+    /// users are paid first, the rest of the withdrawn EGLD is the matured evacuated stake.
+    fn take_evacuated_amount(
+        &self,
+        delegation_contract: &ManagedAddress,
+        withdraw_amount: &BigUint,
+    ) -> BigUint {
+        let evacuation_unbonds_mapper = self.provider_evacuation_unbonds(delegation_contract);
+        if evacuation_unbonds_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let matured_evacuation =
+            self.sum_matured_unbonds(&evacuation_unbonds_mapper.get(), current_epoch);
+        let matured_total = self.sum_matured_unbonds(
+            &self.provider_pending_unbonds(delegation_contract).get(),
+            current_epoch,
+        );
+        let matured_owed = if matured_total > matured_evacuation {
+            matured_total - &matured_evacuation
+        } else {
+            BigUint::zero()
+        };
+        if withdraw_amount <= &matured_owed {
+            return BigUint::zero();
+        }
+
+        let evacuated_amount = (withdraw_amount - &matured_owed).min(matured_evacuation);
+        self.consume_unbonds(evacuation_unbonds_mapper, &evacuated_amount);
+        self.evacuating_egld()
+            .update(|evacuating| *evacuating -= &evacuated_amount);
+
+        evacuated_amount
+    }
+
+    fn sum_matured_unbonds(
+        &self,
+        unbonds: &ManagedVec<PendingUnbond<Self::Api>>,
+        current_epoch: u64,
+    ) -> BigUint {
+        let mut matured = BigUint::zero();
+        for unbond in unbonds.iter() {
+            if unbond.unbond_epoch <= current_epoch {
+                matured += &unbond.amount;
+            }
+        }
+
+        matured
+    }
+
+    /// Whether the liquid staked EGLD of a provider is evacuated and withdrawn.
+    fn is_provider_evacuated(&self, contract_data: &DelegationContractData<Self::Api>) -> bool {
+        contract_data.total_staked_from_ls_contract == BigUint::zero()
+            && contract_data.total_unstaked_from_ls_contract == BigUint::zero()
+            && contract_data.pending_staking_callback_amount == BigUint::zero()
+            && contract_data.pending_unstaking_callback_amount == BigUint::zero()
+    }
+
+    /// Sends the withdrawn seed of a retired provider back to its owner and deletes the
+    /// provider. Providers whitelisted before the seed owner was recorded leave their seed
+    /// to the pool.
+    fn return_provider_seed(&self, delegation_contract: &ManagedAddress, seed: &BigUint) {
+        let seed_owner_mapper = self.provider_seed_owner(delegation_contract);
+        if seed_owner_mapper.is_empty() {
+            let mut storage_cache = StorageCache::new(self);
+            storage_cache.pending_egld += seed;
+            storage_cache.virtual_egld_reserve += seed;
+            self.emit_general_liquidity_event(&storage_cache);
+        } else {
+            self.tx()
+                .to(&seed_owner_mapper.take())
+                .egld(seed)
+                .transfer();
+        }
+
+        self.remove_retired_provider(delegation_contract);
+    }
+
+    fn remove_retired_provider(&self, delegation_contract: &ManagedAddress) {
+        self.retiring_providers().remove(delegation_contract);
+        self.remove_delegation_address_from_list(delegation_contract);
+        self.remove_un_delegation_address_from_list(delegation_contract);
+        self.delegation_contract_data(delegation_contract).clear();
+        self.provider_seed_stake(delegation_contract).clear();
+        self.provider_seed_unbond_epoch(delegation_contract).clear();
+        self.provider_pending_unbonds(delegation_contract).clear();
+        self.provider_evacuation_unbonds(delegation_contract)
+            .clear();
+        self.delegation_failures(delegation_contract).clear();
+        self.provider_scoring_data(delegation_contract).clear();
+        self.provider_yield(delegation_contract).clear();
+        // Unsocialized losses are still paid through `socializeLosses`
+        if !self
+            .unsocialized_loss_providers()
+            .contains(delegation_contract)
+        {
+            self.provider_losses(delegation_contract).clear();
+        }
+
+        self.provider_removed_event(delegation_contract);
+    }
+
+    /// The rewards of the provider are received as EGLD, they are measured against the stake
+    /// of the contract on it, the seed included, before being booked for the xEGLD holders.
     #[promises_callback]
-    fn claim_rewards_callback(&self, #[call_result] result: ManagedAsyncCallResult<BigUint>) {
-        if let ManagedAsyncCallResult::Ok(total_rewards) = result {
+    fn claim_rewards_callback(
+        &self,
+        delegation_contract: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(_) = result {
+            let total_rewards = self.call_value().egld().clone_value();
+            let staked = self
+                .delegation_contract_data(delegation_contract)
+                .get()
+                .total_staked_from_ls_contract
+                + self.get_provider_seed_stake(delegation_contract);
+            self.record_provider_rewards(delegation_contract, &total_rewards, &staked);
+
             if total_rewards > BigUint::zero() {
                 let mut storage_cache = StorageCache::new(self);
-                let fees = self.calculate_share(&total_rewards, &self.fees().get());
+                let fees = self.distribute_rewards(&mut storage_cache, &total_rewards);
 
-                let post_fees_amount = &total_rewards - &fees;
+                self.emit_claim_rewards_event(&storage_cache, &total_rewards, &fees);
+            }
+        }
+    }
+
+    /// Takes the protocol fees, the referral and the insurance shares out of rewards received
+    /// as EGLD, the rest is added to the pending EGLD for the xEGLD holders. Returns the fees.
+    fn distribute_rewards(
+        &self,
+        storage_cache: &mut StorageCache<Self>,
+        total_rewards: &BigUint,
+    ) -> BigUint {
+        let fees = self.calculate_share(total_rewards, &self.fees().get());
+        let insurance = self.accrue_insurance(total_rewards);
 
-                storage_cache.fees_reserve += &fees;
-                storage_cache.pending_egld += &post_fees_amount;
-                storage_cache.virtual_egld_reserve += &post_fees_amount;
+        let post_fees_amount = total_rewards - &fees - &insurance;
 
-                self.emit_claim_rewards_event(&storage_cache, &total_rewards, &fees);
+        storage_cache.pending_egld += &post_fees_amount;
+        storage_cache.virtual_egld_reserve += &post_fees_amount;
+        let referral_fees = self.accrue_referral_fees(&fees);
+        self.book_fees(storage_cache, &(&fees - &referral_fees));
+
+        self.record_epoch_snapshot(storage_cache, &post_fees_amount);
+
+        fees
+    }
+
+    /// Keeps the fees as EGLD or, in the `MintedLs` mode, restakes them and mints their
+    /// LS tokens at the post-fee rate. Holders get the same exchange rate in both modes.
+    fn book_fees(&self, storage_cache: &mut StorageCache<Self>, fees: &BigUint) {
+        let ls_amount = match self.fee_mode().get() {
+            FeeMode::Egld => BigUint::zero(),
+            FeeMode::MintedLs => {
+                fees * &storage_cache.ls_token_supply / &storage_cache.virtual_egld_reserve
+            }
+        };
+
+        // Dust fees, or fees without any LS token left, are kept as EGLD
+        if ls_amount == BigUint::zero() {
+            storage_cache.fees_reserve += fees;
+            return;
+        }
+
+        storage_cache.ls_token_supply += &ls_amount;
+        storage_cache.virtual_egld_reserve += fees;
+        storage_cache.pending_egld += fees;
+        self.mint_ls_token(ls_amount.clone());
+        self.ls_fees_reserve()
+            .update(|reserve| *reserve += &ls_amount);
+    }
+
+    /// Compares the active stake reported by a provider with the booked one. A shortfall is
+    /// socialized as a loss and makes the provider ineligible, a surplus is booked as rewards,
+    /// with the same fees and insurance share as the claimed rewards.
+    #[promises_callback]
+    fn reconcile_provider_callback(
+        &self,
+        delegation_contract: &ManagedAddress,
+        booked_stake: &BigUint,
+        #[call_result] result: ManagedAsyncCallResult<BigUint>,
+    ) {
+        let active_stake = match result {
+            ManagedAsyncCallResult::Ok(active_stake) => active_stake,
+            ManagedAsyncCallResult::Err(_) => return,
+        };
+
+        let delegation_contract_mapper = self.delegation_contract_data(delegation_contract);
+        if delegation_contract_mapper.is_empty() {
+            return;
+        }
+
+        // Stake moved while the query was in flight, the answer cannot be compared
+        let contract_data = delegation_contract_mapper.get();
+        if &contract_data.total_staked_from_ls_contract != booked_stake
+            || contract_data.pending_staking_callback_amount > BigUint::zero()
+            || contract_data.pending_unstaking_callback_amount > BigUint::zero()
+        {
+            return;
+        }
+
+        let mut storage_cache = StorageCache::new(self);
+        let expected_stake = booked_stake + &self.get_provider_seed_stake(delegation_contract);
+        if active_stake < expected_stake {
+            let loss = (&expected_stake - &active_stake).min(booked_stake.clone());
+            delegation_contract_mapper.update(|contract_data| contract_data.eligible = false);
+            self.cancel_delegation_cooldown(delegation_contract);
+            self.book_provider_loss(delegation_contract, &loss);
+            self.socialize_provider_losses(&mut storage_cache, delegation_contract);
+        } else if active_stake > expected_stake {
+            // Stake booked as lost and still there is given back first, it is not a reward
+            let surplus = &active_stake - &expected_stake;
+            let reversed =
+                self.reverse_provider_losses(&mut storage_cache, delegation_contract, &surplus);
+            let surplus = surplus - reversed;
+
+            // The surplus is staked while the fees and the insurance are kept as EGLD, so the
+            // pending EGLD pays them and the surplus stake takes its place. Without enough
+            // pending EGLD the surplus waits for a later reconciliation.
+            let fees_and_insurance = self.calculate_share(&surplus, &self.fees().get())
+                + self.calculate_share(&surplus, &BigUint::from(self.insurance_share().get()));
+            if surplus > BigUint::zero() && storage_cache.pending_egld >= fees_and_insurance {
+                delegation_contract_mapper.update(|contract_data| {
+                    contract_data.total_staked_from_ls_contract += &surplus;
+                });
+                self.distribute_rewards(&mut storage_cache, &surplus);
+                storage_cache.pending_egld -= &surplus;
             }
         }
+
+        self.provider_reconciled_event(
+            delegation_contract,
+            booked_stake,
+            &active_stake,
+            self.blockchain().get_block_epoch(),
+        );
+        self.emit_general_liquidity_event(&storage_cache);
+    }
+
+    /// Books an un-delegation evacuating stake from a retiring or over-allocated provider.
+    /// The EGLD is staked again once withdrawn. A failed evacuation of a retiring provider
+    /// is retried by the next `unDelegatePending`.
+    #[promises_callback]
+    fn evacuate_provider_callback(
+        &self,
+        delegation_contract: &ManagedAddress,
+        evacuated_egld: &BigUint,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        let mut storage_cache = StorageCache::new(self);
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                storage_cache.pending_egld_for_unbond += evacuated_egld;
+                self.record_pending_unbond(delegation_contract, evacuated_egld);
+                self.record_unbond(
+                    self.provider_evacuation_unbonds(delegation_contract),
+                    evacuated_egld,
+                );
+                self.delegation_contract_data(delegation_contract)
+                    .update(|contract_data| {
+                        contract_data.total_staked_from_ls_contract -= evacuated_egld;
+                        contract_data.total_unstaked_from_ls_contract += evacuated_egld;
+                        contract_data.pending_unstaking_callback_amount -= evacuated_egld;
+                    });
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.evacuating_egld()
+                    .update(|evacuating| *evacuating -= evacuated_egld);
+                self.delegation_contract_data(delegation_contract)
+                    .update(|contract_data| {
+                        contract_data.pending_unstaking_callback_amount -= evacuated_egld;
+                    });
+            }
+        }
+        self.emit_general_liquidity_event(&storage_cache);
+    }
+
+    /// Books a failed seed un-delegation, retried by the next `unDelegatePending`.
+    #[promises_callback]
+    fn evacuate_seed_callback(
+        &self,
+        delegation_contract: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        if let ManagedAsyncCallResult::Err(_) = result {
+            self.provider_seed_unbond_epoch(delegation_contract).clear();
+        }
+    }
+
+    fn get_provider_seed_stake(&self, delegation_contract: &ManagedAddress) -> BigUint {
+        // An un-delegated seed is no longer staked
+        if !self
+            .provider_seed_unbond_epoch(delegation_contract)
+            .is_empty()
+        {
+            return BigUint::zero();
+        }
+
+        let seed_stake_mapper = self.provider_seed_stake(delegation_contract);
+
+        // Providers whitelisted before the seed was recorded were seeded with the minimum
+        if seed_stake_mapper.is_empty() {
+            BigUint::from(MIN_EGLD_TO_DELEGATE)
+        } else {
+            seed_stake_mapper.get()
+        }
+    }
+
+    /// The config is read as raw values, the delegation contract returns its flags as text.
+    /// The reads of `syncProvider` and of a provider application land in the same callbacks.
+    #[promises_callback]
+    fn sync_contract_config_callback(
+        &self,
+        delegation_contract: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(config) = result {
+            let config = config.to_vec();
+            if config.len() <= CONTRACT_CONFIG_WITH_DELEGATION_CAP_INDEX {
+                return;
+            }
+
+            // An uncapped delegation contract is stored with a zero cap
+            let delegation_contract_cap = if *config.get(CONTRACT_CONFIG_WITH_DELEGATION_CAP_INDEX)
+                == ManagedBuffer::from(CONTRACT_CONFIG_TRUE)
+            {
+                BigUint::from_bytes_be_buffer(&config.get(CONTRACT_CONFIG_MAX_DELEGATION_CAP_INDEX))
+            } else {
+                BigUint::zero()
+            };
+
+            let service_fee =
+                BigUint::from_bytes_be_buffer(&config.get(CONTRACT_CONFIG_SERVICE_FEE_INDEX))
+                    .to_u64()
+                    .unwrap_or(u64::MAX);
+            let application_mapper = self.provider_application(delegation_contract);
+            if !application_mapper.is_empty() {
+                let owner =
+                    ManagedAddress::try_from((*config.get(CONTRACT_CONFIG_OWNER_INDEX)).clone())
+                        .ok();
+                // Only the owner can apply, a squatted application would block it
+                if owner.as_ref() != Some(&application_mapper.get().admin_address) {
+                    self.drop_provider_application(delegation_contract);
+                    return;
+                }
+
+                application_mapper.update(|application| {
+                    application.service_fee = Some(service_fee);
+                    application.delegation_contract_cap = Some(delegation_contract_cap);
+                    application.owner = owner;
+                });
+                return;
+            }
+
+            self.update_provider_scoring_data(delegation_contract, |scoring_data| {
+                scoring_data.service_fee = Some(service_fee);
+            });
+            self.update_synced_provider(delegation_contract, |contract_data| {
+                contract_data.delegation_contract_cap = delegation_contract_cap;
+            });
+        }
+    }
+
+    #[promises_callback]
+    fn sync_total_stake_callback(
+        &self,
+        delegation_contract: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<BigUint>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(total_staked) = result {
+            let application_mapper = self.provider_application(delegation_contract);
+            if !application_mapper.is_empty() {
+                application_mapper
+                    .update(|application| application.total_staked = Some(total_staked));
+                return;
+            }
+
+            self.update_synced_provider(delegation_contract, |contract_data| {
+                contract_data.total_staked = total_staked;
+            });
+        }
+    }
+
+    #[promises_callback]
+    fn sync_node_states_callback(
+        &self,
+        delegation_contract: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(node_states) = result {
+            let staked_state = ManagedBuffer::from(NODE_STATE_STAKED);
+            let jailed_state = ManagedBuffer::from(NODE_STATE_JAILED);
+            let state_labels = [
+                staked_state.clone(),
+                ManagedBuffer::from(NODE_STATE_NOT_STAKED),
+                ManagedBuffer::from(NODE_STATE_UNSTAKED),
+                jailed_state.clone(),
+            ];
+
+            // Each state label is followed by the BLS keys of the nodes in that state
+            let mut current_state = ManagedBuffer::new();
+            let mut nr_nodes = 0u64;
+            let mut nr_jailed_nodes = 0u64;
+            for node_state in node_states {
+                if state_labels.contains(&node_state) {
+                    current_state = node_state;
+                } else if current_state == staked_state {
+                    nr_nodes += 1;
+                } else if current_state == jailed_state {
+                    nr_jailed_nodes += 1;
+                }
+            }
+
+            let application_mapper = self.provider_application(delegation_contract);
+            if !application_mapper.is_empty() {
+                application_mapper.update(|application| application.nr_nodes = Some(nr_nodes));
+                return;
+            }
+
+            self.update_provider_scoring_data(delegation_contract, |scoring_data| {
+                scoring_data.nr_jailed_nodes = Some(nr_jailed_nodes);
+            });
+            self.update_synced_provider(delegation_contract, |contract_data| {
+                contract_data.nr_nodes = nr_nodes;
+            });
+        }
+    }
+
+    fn update_provider_scoring_data<F: FnOnce(&mut ProviderScoringData)>(
+        &self,
+        delegation_contract: &ManagedAddress,
+        update: F,
+    ) {
+        if self
+            .delegation_contract_data(delegation_contract)
+            .is_empty()
+        {
+            return;
+        }
+
+        let mut scoring_data = self.get_provider_scoring_data(delegation_contract);
+        update(&mut scoring_data);
+        self.provider_scoring_data(delegation_contract)
+            .set(scoring_data);
+    }
+
+    fn take_provider_application(
+        &self,
+        contract_address: &ManagedAddress,
+    ) -> ProviderApplication<Self::Api> {
+        let application_mapper = self.provider_application(contract_address);
+        require!(
+            !application_mapper.is_empty(),
+            ERROR_PROVIDER_APPLICATION_NOT_FOUND
+        );
+
+        let application = application_mapper.take();
+        self.provider_applications().remove(contract_address);
+        self.provider_applications_reserve()
+            .update(|reserve| *reserve -= &application.seed);
+
+        application
+    }
+
+    /// Removes an application and sends its seed back to the admin.
+    fn drop_provider_application(&self, contract_address: &ManagedAddress) {
+        let application = self.take_provider_application(contract_address);
+        self.tx()
+            .to(&application.admin_address)
+            .egld(&application.seed)
+            .transfer();
+
+        self.provider_application_cancelled_event(contract_address);
+    }
+
+    fn update_synced_provider<F: FnOnce(&mut DelegationContractData<Self::Api>)>(
+        &self,
+        delegation_contract: &ManagedAddress,
+        update: F,
+    ) {
+        let delegation_contract_mapper = self.delegation_contract_data(delegation_contract);
+        if delegation_contract_mapper.is_empty() {
+            return;
+        }
+
+        let contract_data = delegation_contract_mapper.update(|contract_data| {
+            update(contract_data);
+            contract_data.clone()
+        });
+        self.provider_synced_event(delegation_contract, &contract_data);
     }
 
     #[promises_callback]
@@ -114,11 +737,19 @@ pub trait CallbackModule:
     ) {
         match result {
             ManagedAsyncCallResult::Ok(()) => {
+                self.provider_seed_stake(&contract_address)
+                    .set(staked_tokens);
+                self.provider_seed_owner(&contract_address).set(caller);
+                let added_epoch = self.blockchain().get_block_epoch();
+                self.update_provider_scoring_data(&contract_address, |scoring_data| {
+                    scoring_data.added_epoch = Some(added_epoch);
+                });
                 self.add_delegation_address_in_list(contract_address.clone());
                 self.add_un_delegation_address_in_list(contract_address);
             }
             ManagedAsyncCallResult::Err(_) => {
                 self.delegation_contract_data(&contract_address).clear();
+                self.provider_scoring_data(&contract_address).clear();
                 self.tx().to(caller).egld(staked_tokens).transfer();
             }
         }
@@ -143,7 +774,12 @@ pub trait CallbackModule:
                 let ls_amount = self.pool_add_liquidity(staked_tokens, &mut storage_cache);
                 let user_payment = self.mint_ls_token(ls_amount);
 
-                self.emit_add_liquidity_event(&storage_cache, staked_tokens, Some(caller.clone()));
+                self.emit_add_liquidity_event(
+                    &storage_cache,
+                    staked_tokens,
+                    Some(caller.clone()),
+                    None,
+                );
                 self.tx().to(caller).esdt(user_payment).transfer();
             }
             ManagedAsyncCallResult::Err(_) => {
@@ -151,4 +787,61 @@ pub trait CallbackModule:
             }
         }
     }
+
+    fn record_pending_unbond(&self, delegation_contract: &ManagedAddress, amount: &BigUint) {
+        self.record_unbond(self.provider_pending_unbonds(delegation_contract), amount);
+    }
+
+    // The provider pays the matured un-delegations, which are always the oldest entries
+    fn consume_pending_unbonds(&self, delegation_contract: &ManagedAddress, amount: &BigUint) {
+        self.consume_unbonds(self.provider_pending_unbonds(delegation_contract), amount);
+    }
+
+    fn record_unbond(
+        &self,
+        unbonds_mapper: SingleValueMapper<ManagedVec<PendingUnbond<Self::Api>>>,
+        amount: &BigUint,
+    ) {
+        let unbond_epoch = self.blockchain().get_block_epoch() + self.unbond_period().get();
+        unbonds_mapper.update(|pending_unbonds| {
+            // Un-delegations unbonding in the same epoch are kept as a single entry
+            let len = pending_unbonds.len();
+            if len > 0 {
+                let mut last = pending_unbonds.get(len - 1).clone();
+                if last.unbond_epoch == unbond_epoch {
+                    last.amount += amount;
+                    let _ = pending_unbonds.set(len - 1, last);
+                    return;
+                }
+            }
+
+            pending_unbonds.push(PendingUnbond {
+                unbond_epoch,
+                amount: amount.clone(),
+            });
+        });
+    }
+
+    fn consume_unbonds(
+        &self,
+        unbonds_mapper: SingleValueMapper<ManagedVec<PendingUnbond<Self::Api>>>,
+        amount: &BigUint,
+    ) {
+        unbonds_mapper.update(|pending_unbonds| {
+            let mut remaining = amount.clone();
+            let mut still_unbonding = ManagedVec::new();
+            for pending_unbond in pending_unbonds.iter() {
+                let mut pending_unbond = pending_unbond.clone();
+                let consumed = pending_unbond.amount.clone().min(remaining.clone());
+                pending_unbond.amount -= &consumed;
+                remaining -= &consumed;
+
+                if pending_unbond.amount > BigUint::zero() {
+                    still_unbonding.push(pending_unbond);
+                }
+            }
+
+            *pending_unbonds = still_unbonding;
+        });
+    }
 }
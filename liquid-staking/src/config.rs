@@ -1,8 +1,8 @@
 multiversx_sc::imports!();
 use crate::{
-    structs::{ScoringConfig, State},
-    ERROR_MAX_CHANGED_DELEGATION_ADDRESSES, ERROR_MAX_SELECTED_PROVIDERS, ERROR_NOT_MANAGER,
-    ERROR_WEIGHTS_MUST_SUM_TO_100,
+    structs::{PausableAction, Role, ScoringConfig, ScoringCurve, State},
+    BPS, ERROR_ACTION_PAUSED, ERROR_INVALID_SCORING_CONFIG, ERROR_MAX_SELECTED_PROVIDERS,
+    ERROR_MISSING_ROLE, ERROR_WEIGHTS_MUST_SUM_TO_100, MAX_SCORING_CURVE_EXPONENT,
 };
 
 #[multiversx_sc::module]
@@ -58,16 +58,27 @@ pub trait ConfigModule: crate::storage::StorageModule {
         self.state().set(State::Inactive);
     }
 
-    #[only_owner]
-    #[endpoint(setAccumulatorContract)]
-    fn set_accumulator_contract(&self, accumulator_contract: ManagedAddress) {
-        self.accumulator_contract().set(accumulator_contract);
+    /// Pauses the given actions while the rest of the protocol keeps running, e.g. deposits
+    /// can be stopped while users are still able to exit. Callable by the guardians.
+    #[endpoint(pauseActions)]
+    fn pause_actions(&self, actions: MultiValueEncoded<PausableAction>) {
+        self.has_role(&self.blockchain().get_caller(), Role::Guardian, true);
+
+        let flags = self.get_action_flags(actions);
+        self.paused_actions().update(|paused| *paused |= flags);
     }
 
+    /// Resumes the given actions. Only the owner can unpause, a guardian key can only stop the protocol.
     #[only_owner]
-    #[endpoint(setFees)]
-    fn set_fees(&self, fees: BigUint) {
-        self.fees().set(fees);
+    #[endpoint(unpauseActions)]
+    fn unpause_actions(&self, actions: MultiValueEncoded<PausableAction>) {
+        let flags = self.get_action_flags(actions);
+        self.paused_actions().update(|paused| *paused &= !flags);
+    }
+
+    #[view(isActionPaused)]
+    fn is_action_paused(&self, action: PausableAction) -> bool {
+        (self.paused_actions().get() & action.flag()) != 0
     }
 
     #[only_owner]
@@ -78,32 +89,30 @@ pub trait ConfigModule: crate::storage::StorageModule {
     }
 
     #[only_owner]
-    #[endpoint(setMaxSelectedProviders)]
-    fn set_max_selected_providers(&self, number: BigUint) {
-        require!(
-            number >= 1u64,
-            ERROR_MAX_CHANGED_DELEGATION_ADDRESSES
-        );
-
-        self.max_selected_providers().set(number);
+    #[endpoint(grantRole)]
+    fn grant_role(&self, address: ManagedAddress, role: Role) {
+        self.add_role(address, role);
     }
 
     #[only_owner]
-    #[endpoint(setUnbondPeriod)]
-    fn set_unbond_period(&self, period: u64) {
-        self.unbond_period().set(period);
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, address: ManagedAddress, role: Role) {
+        self.address_roles(&address)
+            .update(|roles| *roles &= !role.flag());
+        self.role_members(role).swap_remove(&address);
     }
 
-    #[only_owner]
-    #[endpoint(addManagers)]
-    fn set_managers(&self, managers: MultiValueEncoded<ManagedAddress>) {
-        self.managers().extend(managers);
-    }
+    #[view(getAddressRoles)]
+    fn get_address_roles(&self, address: ManagedAddress) -> MultiValueEncoded<Role> {
+        let roles = self.address_roles(&address).get();
 
-    #[only_owner]
-    #[endpoint(removeManager)]
-    fn remove_manager(&self, manager: ManagedAddress) {
-        self.managers().swap_remove(&manager);
+        let mut result = MultiValueEncoded::new();
+        for role in Role::ALL {
+            if (roles & role.flag()) != 0 {
+                result.push(role);
+            }
+        }
+        result
     }
 
     #[only_owner]
@@ -120,20 +129,89 @@ pub trait ConfigModule: crate::storage::StorageModule {
 
     #[endpoint(setScoringConfig)]
     fn set_scoring_config(&self, config: ScoringConfig) {
-        self.is_manager(&self.blockchain().get_caller(), true);
+        self.has_role(&self.blockchain().get_caller(), Role::ScoringAdmin, true);
         require!(
-            config.stake_weight + config.apy_weight + config.nodes_weight == 100,
+            config.stake_weight
+                + config.apy_weight
+                + config.nodes_weight
+                + config.service_fee_weight
+                + config.uptime_weight
+                + config.age_weight
+                + config.top_up_weight
+                == 100,
             ERROR_WEIGHTS_MUST_SUM_TO_100
         );
+        require!(
+            config.min_nodes < config.max_nodes
+                && config.min_apy < config.max_apy
+                && config.min_service_fee < config.max_service_fee
+                && config.min_uptime < config.max_uptime
+                && config.min_age < config.max_age
+                && config.min_top_up < config.max_top_up,
+            ERROR_INVALID_SCORING_CONFIG
+        );
+        for curve in [
+            config.stake_curve,
+            config.apy_curve,
+            config.nodes_curve,
+            config.service_fee_curve,
+            config.uptime_curve,
+            config.age_curve,
+            config.top_up_curve,
+        ] {
+            require!(
+                self.is_valid_scoring_curve(curve),
+                ERROR_INVALID_SCORING_CONFIG
+            );
+        }
+
         self.scoring_config().set(config);
     }
 
-    fn is_manager(&self, address: &ManagedAddress, required: bool) -> bool {
+    /// When enabled, providers are scored with the yield measured from the rewards claimed
+    /// from them instead of their declared APY, see `getProviderRealizedApy`. Providers
+    /// without any measured yield keep their declared APY.
+    #[endpoint(setRealizedApyScoring)]
+    fn set_realized_apy_scoring(&self, enabled: bool) {
+        self.has_role(&self.blockchain().get_caller(), Role::ScoringAdmin, true);
+        self.realized_apy_scoring().set(enabled);
+    }
+
+    fn is_valid_scoring_curve(&self, curve: ScoringCurve) -> bool {
+        match curve {
+            ScoringCurve::Linear => true,
+            ScoringCurve::Power(exponent) => exponent > 0 && exponent <= MAX_SCORING_CURVE_EXPONENT,
+            ScoringCurve::Logistic(steepness) => steepness > 0,
+            ScoringCurve::Step(threshold) => threshold <= BPS,
+        }
+    }
+
+    fn require_action_not_paused(&self, action: PausableAction) {
+        require!(!self.is_action_paused(action), ERROR_ACTION_PAUSED);
+    }
+
+    fn get_action_flags(&self, actions: MultiValueEncoded<PausableAction>) -> u64 {
+        let mut flags = 0u64;
+        for action in actions {
+            flags |= action.flag();
+        }
+        flags
+    }
+
+    fn add_role(&self, address: ManagedAddress, role: Role) {
+        self.address_roles(&address)
+            .update(|roles| *roles |= role.flag());
+        self.role_members(role).insert(address);
+    }
+
+    // The owner holds every role
+    fn has_role(&self, address: &ManagedAddress, role: Role, required: bool) -> bool {
         let owner = self.blockchain().get_owner_address();
-        let is_manager = self.managers().contains(address) || address == &owner;
-        if required && !is_manager {
-            sc_panic!(ERROR_NOT_MANAGER);
+        let roles = self.address_roles(address).get();
+        let has_role = (roles & role.flag()) != 0 || address == &owner;
+        if required && !has_role {
+            sc_panic!(ERROR_MISSING_ROLE);
         }
-        is_manager
+        has_role
     }
 }
@@ -4,6 +4,7 @@ pub const MIN_GAS_FOR_ASYNC_CALL: u64 = 12_000_000;
 pub const MIN_GAS_FOR_ASYNC_CALL_CLAIM_REWARDS: u64 = 2_500_000;
 pub const MIN_GAS_FOR_CALLBACK: u64 = 6_000_000;
 pub const MIN_GAS_FOR_WHITELIST_CALLBACK: u64 = 20_000_000;
+pub const MIN_GAS_TO_FINISH_BATCH: u64 = 5_000_000;
 pub const MIN_EGLD_TO_DELEGATE: u64 = 1_000_000_000_000_000_000;
 
 pub const MAX_PERCENTAGE: u64 = 100_000;
@@ -13,6 +14,52 @@ pub const ROUNDS_LEFT_TO_END_EPOCH: u64 = 1000;
 pub const DECIMALS: usize = 18;
 
 pub const BPS: u64 = 10_000; // 100%
+pub const MAX_FEES: u64 = 2_000; // 20% of the rewards
+pub const MAX_FEES_CHANGE: u64 = 500; // Per executed change
+pub const MIN_EPOCHS_BETWEEN_FEES_CHANGES: u64 = 7;
+pub const MAX_FEE_RECIPIENTS: usize = 10;
+pub const MIN_UNBOND_PERIOD: u64 = 10; // Epochs the providers need to release un-delegated EGLD
+pub const MAX_UNBOND_PERIOD: u64 = 30;
+pub const ACCUMULATOR_DEPOSIT_ENDPOINT: &[u8] = b"deposit";
+pub const MAX_REFERRAL_CODE_LENGTH: usize = 32;
+pub const REFERRAL_REWARD_PRECISION: u64 = 1_000_000_000_000_000_000;
+pub const REFERRAL_ATTRIBUTION_EPOCHS: u64 = 180; // Counted from the last referred deposit
+pub const MAX_INSURANCE_SHARE: u64 = 2_000; // 20% of the rewards
 
+// 1 EGLD = 10^18 atomic units
+pub const INITIAL_EXCHANGE_RATE: u64 = 1_000_000_000_000_000_000;
+pub const MAX_RATE_SNAPSHOTS: usize = 60; // One snapshot per epoch
+pub const EPOCHS_PER_YEAR: u64 = 365;
+pub const MAX_EPOCH_SNAPSHOTS: usize = 60; // One snapshot per epoch with rewards
+pub const REALIZED_APR_EPOCHS: u64 = 7; // Window used when scoring with the realized APY
+pub const MAX_WITHDRAW_TICKETS_FILLED: usize = 50; // Bounds the gas of a single queue fill
+pub const MAX_WITHDRAW_PENDING_BATCH: usize = 20;
+pub const MAX_RECONCILE_BATCH: usize = 20;
+pub const MAX_PROVIDER_APPLICATIONS: usize = 20;
+pub const PROVIDER_APPLICATION_EXPIRY_EPOCHS: u64 = 30; // Anyone can drop an older application
+pub const MAX_RETIRING_PROVIDERS: usize = 5; // Evacuated together by `unDelegatePending`
+pub const MAX_REBALANCE_PROVIDERS: usize = 3; // Un-delegated from by a single `rebalance`
+pub const MAX_REBALANCE_SHARE: u64 = 500; // 5% of the liquid staked EGLD per `rebalance`
+pub const DELEGATION_FAILURE_COOLDOWN_EPOCHS: u64 = 2; // Doubled on every repeated failure
+pub const MAX_DELEGATION_FAILURE_COOLDOWN_EPOCHS: u64 = 64;
+
+// Layout of the delegation contract views read by `syncProvider`
+pub const CONTRACT_CONFIG_OWNER_INDEX: usize = 0;
+pub const CONTRACT_CONFIG_SERVICE_FEE_INDEX: usize = 1;
+pub const CONTRACT_CONFIG_MAX_DELEGATION_CAP_INDEX: usize = 2;
+pub const CONTRACT_CONFIG_WITH_DELEGATION_CAP_INDEX: usize = 5;
+pub const CONTRACT_CONFIG_TRUE: &[u8] = b"true";
+pub const NODE_STATE_STAKED: &[u8] = b"staked";
+pub const NODE_STATE_NOT_STAKED: &[u8] = b"notStaked";
+pub const NODE_STATE_UNSTAKED: &[u8] = b"unStaked";
+pub const NODE_STATE_JAILED: &[u8] = b"jailed";
+pub const NODE_STAKE_EGLD: u64 = 2_500; // Locked per node, the rest of the stake is top-up
+pub const ONE_EGLD: u64 = 1_000_000_000_000_000_000;
+pub const MAX_SCORING_CURVE_EXPONENT: u64 = 8;
+pub const LEGACY_SCORING_CONFIG_LENGTH: usize = 80; // Ten u64 fields
+pub const DEFAULT_CONFIG_TIMELOCK_DELAY: u64 = 3; // Epochs, also the lowest delay a proposal can set
+
+// Shared by the system smart contracts, the delegation contracts included
+pub const SYSTEM_SC_ADDRESS_PREFIX: [u8; 10] = hex!("00000000000000000001");
 pub const DELEGATION_MANAGER: [u8; 32] =
     hex!("000000000000000000010000000000000000000000000000000000000004ffff");
@@ -1,7 +1,11 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::State;
+use crate::{
+    constants::{INITIAL_EXCHANGE_RATE, MAX_RATE_SNAPSHOTS},
+    structs::RateObservation,
+    State,
+};
 
 pub struct StorageCache<'a, C>
 where
@@ -37,6 +41,66 @@ where
             sc_ref,
         }
     }
+
+    /// EGLD atomic units received for 1 LS token, scaled by 10^18.
+    pub fn exchange_rate(&self) -> BigUint<C::Api> {
+        // When no liquidity, 1 LS token = 1 EGLD
+        if self.ls_token_supply == BigUint::zero() {
+            return BigUint::from(INITIAL_EXCHANGE_RATE);
+        }
+
+        &self.virtual_egld_reserve * &BigUint::from(INITIAL_EXCHANGE_RATE) / &self.ls_token_supply
+    }
+
+    /// Accumulates the previous rate over the elapsed time and stores the new one.
+    /// The first observation of every epoch is also kept in the snapshots ring buffer.
+    fn record_rate_observation(&self) {
+        let epoch = self.sc_ref.blockchain().get_block_epoch();
+        let timestamp = self.sc_ref.blockchain().get_block_timestamp();
+        let exchange_rate = self.exchange_rate();
+
+        let last_observation_mapper = self.sc_ref.last_rate_observation();
+        let (rate_cumulative, is_new_epoch) = if last_observation_mapper.is_empty() {
+            (BigUint::zero(), true)
+        } else {
+            let last_observation = last_observation_mapper.get();
+            // The accumulator grows linearly while the rate is unchanged
+            if last_observation.epoch == epoch && last_observation.exchange_rate == exchange_rate {
+                return;
+            }
+            (
+                last_observation.cumulative_at(timestamp),
+                last_observation.epoch < epoch,
+            )
+        };
+
+        let observation = RateObservation {
+            epoch,
+            timestamp,
+            exchange_rate,
+            rate_cumulative,
+        };
+
+        if is_new_epoch {
+            self.push_rate_snapshot(&observation);
+        }
+
+        last_observation_mapper.set(observation);
+    }
+
+    fn push_rate_snapshot(&self, observation: &RateObservation<C::Api>) {
+        let mut snapshots = self.sc_ref.rate_snapshots();
+        if snapshots.len() < MAX_RATE_SNAPSHOTS {
+            snapshots.push(observation);
+            return;
+        }
+
+        // Overwrite the oldest snapshot and move the cursor to the next oldest one
+        let cursor_mapper = self.sc_ref.rate_snapshots_cursor();
+        let cursor = cursor_mapper.get();
+        snapshots.set(cursor + 1, observation);
+        cursor_mapper.set((cursor + 1) % MAX_RATE_SNAPSHOTS);
+    }
 }
 
 impl<C> Drop for StorageCache<'_, C>
@@ -60,5 +124,7 @@ where
         self.sc_ref
             .pending_egld_for_unbond()
             .set(&self.pending_egld_for_unbond);
+
+        self.record_rate_observation();
     }
 }
@@ -1,14 +1,15 @@
 multiversx_sc::imports!();
 use crate::{
     callback::{CallbackModule, CallbackProxy},
+    constants::MAX_RETIRING_PROVIDERS,
     errors::{
         ERROR_ALREADY_WHITELISTED, ERROR_DELEGATION_CAP, ERROR_NOT_WHITELISTED,
-        ERROR_ONLY_DELEGATION_ADMIN,
+        ERROR_ONLY_DELEGATION_ADMIN, ERROR_PROVIDER_RETIRING, ERROR_TOO_MANY_RETIRING_PROVIDERS,
     },
     proxy::proxy_delegation,
-    structs::DelegationContractData,
+    structs::{DelegationContractData, Role},
     utils, ERROR_MAX_DELEGATION_ADDRESSES, ERROR_MAX_UN_DELEGATION_ADDRESSES,
-    ERROR_MIN_EGLD_TO_DELEGATE, MIN_EGLD_TO_DELEGATE, MIN_GAS_FOR_ASYNC_CALL,
+    ERROR_MIN_EGLD_TO_DELEGATE, MIN_EGLD_TO_DELEGATE, MIN_GAS_FOR_ASYNC_CALL, MIN_GAS_FOR_CALLBACK,
     MIN_GAS_FOR_WHITELIST_CALLBACK,
 };
 
@@ -35,22 +36,10 @@ pub trait DelegationModule:
         nr_nodes: u64,
         apy: u64,
     ) {
-        let mapper = self.max_delegation_addresses();
-        require!(
-            self.delegation_addresses_list().len() <= mapper.get(),
-            ERROR_MAX_DELEGATION_ADDRESSES
-        );
-
-        require!(
-            self.un_delegation_addresses_list().len() <= mapper.get(),
-            ERROR_MAX_UN_DELEGATION_ADDRESSES
-        );
-
         let caller = self.blockchain().get_caller();
-        self.is_manager(&caller, true);
-        let map_delegation_contract_data = self.delegation_contract_data(&contract_address);
+        self.has_role(&caller, Role::ProviderCurator, true);
         require!(
-            map_delegation_contract_data.is_empty(),
+            self.delegation_contract_data(&contract_address).is_empty(),
             ERROR_ALREADY_WHITELISTED
         );
 
@@ -60,10 +49,7 @@ pub trait DelegationModule:
         );
 
         let payment = self.call_value().egld().clone_value();
-        require!(
-            payment >= MIN_EGLD_TO_DELEGATE,
-            ERROR_MIN_EGLD_TO_DELEGATE
-        );
+        require!(payment >= MIN_EGLD_TO_DELEGATE, ERROR_MIN_EGLD_TO_DELEGATE);
 
         let contract_data = DelegationContractData {
             admin_address,
@@ -78,23 +64,7 @@ pub trait DelegationModule:
             eligible: true,
         };
 
-        map_delegation_contract_data.set(contract_data);
-
-        self.tx()
-            .to(&contract_address)
-            .typed(proxy_delegation::DelegationMockProxy)
-            .delegate()
-            .egld(&payment)
-            .gas(MIN_GAS_FOR_ASYNC_CALL)
-            .callback(
-                CallbackModule::callbacks(self).whitelist_delegation_contract_callback(
-                    contract_address.clone(),
-                    &payment,
-                    &caller,
-                ),
-            )
-            .gas_for_callback(MIN_GAS_FOR_WHITELIST_CALLBACK)
-            .register_promise();
+        self.add_delegation_contract(&contract_address, contract_data, &payment, &caller);
     }
 
     #[endpoint(changeDelegationContractAdmin)]
@@ -105,7 +75,7 @@ pub trait DelegationModule:
     ) {
         let delegation_address_mapper = self.delegation_contract_data(&contract_address);
         require!(!delegation_address_mapper.is_empty(), ERROR_NOT_WHITELISTED);
-        self.is_manager(&self.blockchain().get_caller(), true);
+        self.has_role(&self.blockchain().get_caller(), Role::ProviderCurator, true);
         delegation_address_mapper.update(|contract_data| {
             contract_data.admin_address = admin_address;
         });
@@ -128,7 +98,8 @@ pub trait DelegationModule:
         require!(!delegation_address_mapper.is_empty(), ERROR_NOT_WHITELISTED);
 
         require!(
-            old_contract_data.admin_address == caller || self.is_manager(&caller, false),
+            old_contract_data.admin_address == caller
+                || self.has_role(&caller, Role::ProviderCurator, false),
             ERROR_ONLY_DELEGATION_ADMIN
         );
 
@@ -137,6 +108,11 @@ pub trait DelegationModule:
             ERROR_DELEGATION_CAP
         );
 
+        require!(
+            !is_eligible || !self.retiring_providers().contains(&contract_address),
+            ERROR_PROVIDER_RETIRING
+        );
+
         delegation_address_mapper.update(|contract_data| {
             contract_data.total_staked = total_staked;
             contract_data.delegation_contract_cap = delegation_contract_cap;
@@ -144,5 +120,149 @@ pub trait DelegationModule:
             contract_data.apy = apy;
             contract_data.eligible = is_eligible;
         });
+        // The eligibility set by the admin replaces a pending automatic re-admission
+        self.cancel_delegation_cooldown(&contract_address);
+    }
+
+    /// Starts removing a provider. It stops receiving delegations, its whole stake is
+    /// un-delegated by the next `unDelegatePending` calls and staked again on the remaining
+    /// providers once withdrawn. Its seed is un-delegated last and sent back to its owner
+    /// once withdrawn, then the provider is deleted.
+    #[endpoint(retireProvider)]
+    fn retire_provider(&self, contract_address: ManagedAddress) {
+        self.has_role(&self.blockchain().get_caller(), Role::ProviderCurator, true);
+
+        // A provider still waiting for its whitelisting callback cannot be retired
+        require!(
+            self.un_delegation_addresses_list()
+                .contains(&contract_address),
+            ERROR_NOT_WHITELISTED
+        );
+
+        let mut retiring_providers = self.retiring_providers();
+        require!(
+            !retiring_providers.contains(&contract_address),
+            ERROR_PROVIDER_RETIRING
+        );
+        require!(
+            retiring_providers.len() < MAX_RETIRING_PROVIDERS,
+            ERROR_TOO_MANY_RETIRING_PROVIDERS
+        );
+
+        self.delegation_contract_data(&contract_address)
+            .update(|contract_data| contract_data.eligible = false);
+        self.cancel_delegation_cooldown(&contract_address);
+        retiring_providers.insert(contract_address.clone());
+
+        self.provider_retiring_event(&contract_address);
+    }
+
+    /// Refreshes the total stake, the cap and the number of staked nodes of a provider from
+    /// its delegation contract. Callable by anyone, the values are read on-chain. The APY is
+    /// not exposed by the delegation contracts and stays set by `changeDelegationContractParams`.
+    /// Also refreshes the checks of a pending provider application.
+    #[endpoint(syncProvider)]
+    fn sync_provider(&self, contract_address: ManagedAddress) {
+        require!(
+            !self.delegation_contract_data(&contract_address).is_empty()
+                || !self.provider_application(&contract_address).is_empty(),
+            ERROR_NOT_WHITELISTED
+        );
+
+        self.request_provider_data(&contract_address);
+    }
+
+    /// Reads the config, the total stake and the node states of a delegation contract.
+    /// The callbacks update the provider, or its pending application.
+    fn request_provider_data(&self, contract_address: &ManagedAddress) {
+        self.tx()
+            .to(contract_address)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .get_contract_config()
+            .gas(MIN_GAS_FOR_ASYNC_CALL)
+            .callback(
+                CallbackModule::callbacks(self).sync_contract_config_callback(contract_address),
+            )
+            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+            .register_promise();
+
+        self.tx()
+            .to(contract_address)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .get_total_active_stake()
+            .gas(MIN_GAS_FOR_ASYNC_CALL)
+            .callback(CallbackModule::callbacks(self).sync_total_stake_callback(contract_address))
+            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+            .register_promise();
+
+        self.tx()
+            .to(contract_address)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .get_all_node_states()
+            .gas(MIN_GAS_FOR_ASYNC_CALL)
+            .callback(CallbackModule::callbacks(self).sync_node_states_callback(contract_address))
+            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+            .register_promise();
+    }
+
+    /// Stores the provider and delegates its seed. The provider joins the address lists once
+    /// the delegation succeeds, otherwise the seed is sent back to `seed_owner`.
+    fn add_delegation_contract(
+        &self,
+        contract_address: &ManagedAddress,
+        contract_data: DelegationContractData<Self::Api>,
+        seed: &BigUint,
+        seed_owner: &ManagedAddress,
+    ) {
+        let mapper = self.max_delegation_addresses();
+        require!(
+            self.delegation_addresses_list().len() <= mapper.get(),
+            ERROR_MAX_DELEGATION_ADDRESSES
+        );
+
+        require!(
+            self.un_delegation_addresses_list().len() <= mapper.get(),
+            ERROR_MAX_UN_DELEGATION_ADDRESSES
+        );
+
+        self.delegation_contract_data(contract_address)
+            .set(contract_data);
+
+        self.tx()
+            .to(contract_address)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .delegate()
+            .egld(seed)
+            .gas(MIN_GAS_FOR_ASYNC_CALL)
+            .callback(
+                CallbackModule::callbacks(self).whitelist_delegation_contract_callback(
+                    contract_address.clone(),
+                    seed,
+                    seed_owner,
+                ),
+            )
+            .gas_for_callback(MIN_GAS_FOR_WHITELIST_CALLBACK)
+            .register_promise();
+    }
+
+    /// Makes eligible again the providers whose delegation failure cooldown is over.
+    fn readmit_cooled_down_providers(&self) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut readmitted_providers = ManagedVec::<Self::Api, ManagedAddress>::new();
+        for provider in self.cooling_down_providers().iter() {
+            if self.delegation_failures(&provider).get().readmission_epoch <= current_epoch {
+                readmitted_providers.push(provider);
+            }
+        }
+
+        for provider in readmitted_providers.iter() {
+            self.cooling_down_providers().remove(&provider);
+            self.delegation_failures(&provider)
+                .update(|failures| failures.readmission_epoch = 0);
+            self.delegation_contract_data(&provider)
+                .update(|contract_data| contract_data.eligible = true);
+
+            self.provider_readmitted_event(&provider);
+        }
     }
 }
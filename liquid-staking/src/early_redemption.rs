@@ -0,0 +1,217 @@
+multiversx_sc::imports!();
+use crate::{
+    constants::{BPS, MIN_EGLD_TO_DELEGATE},
+    errors::{
+        ERROR_BAD_PAYMENT_AMOUNT, ERROR_BAD_PAYMENT_TOKEN, ERROR_EARLY_REDEMPTION_DISABLED,
+        ERROR_INSUFFICIENT_EGLD_OUT, ERROR_INSUFFICIENT_PENDING_EGLD,
+        ERROR_INVALID_EARLY_REDEMPTION_FEE, ERROR_UNBOND_PERIOD_PASSED,
+    },
+    structs::{EarlyRedemptionConfig, PausableAction, PendingUnbond, UnstakeTokenAttributes},
+    StorageCache,
+};
+
+#[multiversx_sc::module]
+pub trait EarlyRedemptionModule:
+    crate::config::ConfigModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::score::ScoreModule
+    + crate::selection::SelectionModule
+    + crate::utils::generic::UtilsModule
+    + crate::liquidity_pool::LiquidityPoolModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    /// Configures the early redemption discount, in BPS. The fee decays linearly from
+    /// `max_fee`, right after un-delegation, to `min_fee`, right before the unbond epoch.
+    ///
+    /// Arguments:
+    /// - `fee_to_holders`: When `true` the fee accrues to xEGLD holders through the
+    ///                     virtual EGLD reserve, otherwise it is added to the fees reserve.
+    #[only_owner]
+    #[endpoint(setEarlyRedemptionConfig)]
+    fn set_early_redemption_config(&self, max_fee: u64, min_fee: u64, fee_to_holders: bool) {
+        require!(
+            min_fee <= max_fee && max_fee <= BPS,
+            ERROR_INVALID_EARLY_REDEMPTION_FEE
+        );
+
+        self.early_redemption_config().set(EarlyRedemptionConfig {
+            max_fee,
+            min_fee,
+            fee_to_holders,
+        });
+    }
+
+    #[only_owner]
+    #[endpoint(disableEarlyRedemption)]
+    fn disable_early_redemption(&self) {
+        self.early_redemption_config().clear();
+    }
+
+    /// Redeems an unbonding position before its unbond epoch against the pending EGLD,
+    /// paying the current early redemption fee. The EGLD of the position is either netted
+    /// against the pending un-delegations or re-staked once it is withdrawn from the providers.
+    ///
+    /// Arguments:
+    /// - `min_egld_out`: Optional. Reverts when the EGLD received after the fee is lower.
+    #[payable("*")]
+    #[endpoint(redeemEarly)]
+    fn redeem_early(&self, min_egld_out: OptionalValue<BigUint>) {
+        let mut storage_cache = StorageCache::new(self);
+        self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::EarlyRedemption);
+
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.unstake_token().get_token_id(),
+            ERROR_BAD_PAYMENT_TOKEN
+        );
+        require!(payment.amount > BigUint::zero(), ERROR_BAD_PAYMENT_AMOUNT);
+
+        let config = self.get_early_redemption_config();
+        let attributes: UnstakeTokenAttributes = self
+            .unstake_token()
+            .get_token_attributes(payment.token_nonce);
+        let fee = self.compute_early_redemption_fee(&config, &attributes, &payment.amount);
+        let egld_out = &payment.amount - &fee;
+
+        if let Some(min_egld_out) = min_egld_out.into_option() {
+            require!(egld_out >= min_egld_out, ERROR_INSUFFICIENT_EGLD_OUT);
+        }
+
+        self.burn_unstake_tokens(payment.token_nonce, &payment.amount);
+
+        // A fee kept by the protocol has to leave the pending EGLD as well
+        let egld_from_pending = if config.fee_to_holders {
+            egld_out.clone()
+        } else {
+            storage_cache.fees_reserve += &fee;
+            payment.amount.clone()
+        };
+
+        require!(
+            storage_cache.pending_egld >= egld_from_pending,
+            ERROR_INSUFFICIENT_PENDING_EGLD
+        );
+        storage_cache.pending_egld -= &egld_from_pending;
+        require!(
+            storage_cache.pending_egld >= MIN_EGLD_TO_DELEGATE
+                || storage_cache.pending_egld == BigUint::zero(),
+            ERROR_INSUFFICIENT_PENDING_EGLD
+        );
+
+        // The pool pays from the pending EGLD and takes over the full position,
+        // a fee routed to the holders is the difference left in the reserve
+        storage_cache.virtual_egld_reserve += &payment.amount;
+        storage_cache.virtual_egld_reserve -= &egld_from_pending;
+
+        self.reclaim_redeemed_position(
+            &mut storage_cache,
+            &payment.amount,
+            attributes.unbond_epoch,
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.tx().to(&caller).egld(&egld_out).transfer();
+
+        self.emit_early_redemption_event(&storage_cache, &payment.amount, &fee);
+    }
+
+    /// Returns the EGLD received and the fee paid for an early redemption of `amount`
+    /// unstake tokens of the given nonce, in the current epoch.
+    #[view(getEarlyRedemptionQuote)]
+    fn get_early_redemption_quote(
+        &self,
+        token_nonce: u64,
+        amount: BigUint,
+    ) -> MultiValue2<BigUint, BigUint> {
+        let config = self.get_early_redemption_config();
+        let attributes: UnstakeTokenAttributes =
+            self.unstake_token().get_token_attributes(token_nonce);
+        let fee = self.compute_early_redemption_fee(&config, &attributes, &amount);
+
+        (&amount - &fee, fee).into()
+    }
+
+    fn get_early_redemption_config(&self) -> EarlyRedemptionConfig {
+        let config_mapper = self.early_redemption_config();
+        require!(!config_mapper.is_empty(), ERROR_EARLY_REDEMPTION_DISABLED);
+
+        config_mapper.get()
+    }
+
+    fn compute_early_redemption_fee(
+        &self,
+        config: &EarlyRedemptionConfig,
+        attributes: &UnstakeTokenAttributes,
+        amount: &BigUint,
+    ) -> BigUint {
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            current_epoch < attributes.unbond_epoch,
+            ERROR_UNBOND_PERIOD_PASSED
+        );
+
+        let remaining_epochs = attributes.unbond_epoch - current_epoch;
+        let total_epochs = attributes.unbond_epoch - attributes.unstake_epoch;
+        let fee_bps = if remaining_epochs >= total_epochs {
+            config.max_fee
+        } else {
+            config.min_fee + (config.max_fee - config.min_fee) * remaining_epochs / total_epochs
+        };
+
+        amount * fee_bps / BPS
+    }
+
+    /// The redeemed position now belongs to the pool. While the un-delegations are still
+    /// pending it is simply kept staked, otherwise the unbonded EGLD is re-staked once it is
+    /// withdrawn after the unbond epoch of the position, see `take_matured_restake`.
+    fn reclaim_redeemed_position(
+        &self,
+        storage_cache: &mut StorageCache<Self>,
+        amount: &BigUint,
+        unbond_epoch: u64,
+    ) {
+        let can_net_pending_unstake = storage_cache.pending_egld_for_unstake == *amount
+            || (storage_cache.pending_egld_for_unstake > *amount
+                && &storage_cache.pending_egld_for_unstake - amount >= MIN_EGLD_TO_DELEGATE);
+
+        if can_net_pending_unstake {
+            storage_cache.pending_egld_for_unstake -= amount;
+        } else {
+            self.pending_egld_for_restake()
+                .update(|restake_amount| *restake_amount += amount);
+            self.record_restake_unbond(amount, unbond_epoch);
+        }
+    }
+
+    // The restake ledger is kept ordered by unbond epoch, redemptions can come in any order
+    fn record_restake_unbond(&self, amount: &BigUint, unbond_epoch: u64) {
+        self.pending_restake_unbonds().update(|restake_unbonds| {
+            let mut updated = ManagedVec::new();
+            let mut recorded = false;
+            for restake_unbond in restake_unbonds.iter() {
+                let mut restake_unbond = restake_unbond.clone();
+                if !recorded && restake_unbond.unbond_epoch == unbond_epoch {
+                    restake_unbond.amount += amount;
+                    recorded = true;
+                } else if !recorded && restake_unbond.unbond_epoch > unbond_epoch {
+                    updated.push(PendingUnbond {
+                        unbond_epoch,
+                        amount: amount.clone(),
+                    });
+                    recorded = true;
+                }
+                updated.push(restake_unbond);
+            }
+
+            if !recorded {
+                updated.push(PendingUnbond {
+                    unbond_epoch,
+                    amount: amount.clone(),
+                });
+            }
+            *restake_unbonds = updated;
+        });
+    }
+}
@@ -7,7 +7,8 @@ pub static ERROR_OLD_CLAIM_START: &[u8] =
 pub static ERROR_RECOMPUTE_RESERVES: &[u8] = b"Claim operation must be in the finished status";
 pub static ERROR_CLAIM_EPOCH: &[u8] = b"The rewards were already claimed for this epoch";
 pub static ERROR_UNSTAKE_PERIOD_NOT_PASSED: &[u8] = b"The unstake period has not passed";
-pub static ERROR_ROUNDS_NOT_PASSED: &[u8] = b"Not enough rounds passed since the start of the epoch";
+pub static ERROR_ROUNDS_NOT_PASSED: &[u8] =
+    b"Not enough rounds passed since the start of the epoch";
 
 pub static ERROR_BAD_PAYMENT_TOKEN: &[u8] = b"Bad payment token";
 pub static ERROR_BAD_PAYMENT_AMOUNT: &[u8] = b"Insufficient delegated amount";
@@ -17,6 +18,10 @@ pub static ERROR_INSUFFICIENT_UNSTAKE_AMOUNT: &[u8] = b"Insufficient unstake amo
 pub static ERROR_INSUFFICIENT_UNBONDED_AMOUNT: &[u8] = b"Insufficient incoming withdraw amount";
 pub static ERROR_INSUFFICIENT_LIQUIDITY: &[u8] = b"Insufficient liquidity minted";
 pub static ERROR_INSUFFICIENT_LIQ_BURNED: &[u8] = b"Insufficient liquidity burned";
+pub static ERROR_INSUFFICIENT_LS_OUT: &[u8] = b"Minted xEGLD is lower than the minimum requested";
+pub static ERROR_INSUFFICIENT_EGLD_OUT: &[u8] = b"EGLD amount is lower than the minimum requested";
+pub static ERROR_INSTANT_UNDELEGATE_NOT_POSSIBLE: &[u8] =
+    b"Not enough pending EGLD for an instant un-delegation";
 
 pub static ERROR_NOT_ENOUGH_LP: &[u8] = b"Not enough LP token supply";
 
@@ -43,7 +48,7 @@ pub static ERROR_MIN_EGLD_TO_DELEGATE: &[u8] =
 pub static ERROR_MIGRATION_SC_NOT_SET: &[u8] = b"Migration SC not set";
 pub static ERROR_MIGRATION_NOT_ALLOWED: &[u8] = b"Migration not allowed";
 
-pub static ERROR_NOT_MANAGER: &[u8] = b"Caller is not authorized as a manager";
+pub static ERROR_MISSING_ROLE: &[u8] = b"Caller does not have the required role";
 pub static ERROR_NOT_LIQUIDITY_PROVIDER: &[u8] =
     b"Caller is not authorized as a liquidity provider";
 
@@ -54,3 +59,122 @@ pub static ERROR_WEIGHTS_MUST_SUM_TO_100: &[u8] = b"Weights must sum to 100";
 pub static ERROR_INSUFFICIENT_FEES_RESERVE: &[u8] = b"Insufficient fees reserve";
 
 pub static ERROR_PROVIDER_NOT_ELIGIBLE: &[u8] = b"The provider is not eligible";
+
+pub static ERROR_NO_RATE_OBSERVATIONS: &[u8] = b"No exchange rate observations recorded";
+
+pub static ERROR_INVALID_TWAP_WINDOW: &[u8] = b"Invalid TWAP window";
+
+pub static ERROR_TWAP_WINDOW_TOO_LONG: &[u8] = b"Not enough rate history for the requested window";
+
+pub static ERROR_NOT_ENOUGH_RATE_SNAPSHOTS: &[u8] = b"Not enough rate snapshots";
+
+pub static ERROR_INVALID_APR_WINDOW: &[u8] = b"Invalid APR window";
+
+pub static ERROR_NOT_ENOUGH_EPOCH_SNAPSHOTS: &[u8] =
+    b"Not enough epoch snapshots for the requested window";
+
+pub static ERROR_NO_PROVIDER_YIELD: &[u8] = b"No rewards measured for the provider yet";
+
+pub static ERROR_EARLY_REDEMPTION_DISABLED: &[u8] = b"Early redemption is not enabled";
+
+pub static ERROR_INVALID_EARLY_REDEMPTION_FEE: &[u8] = b"Invalid early redemption fee";
+
+pub static ERROR_UNBOND_PERIOD_PASSED: &[u8] = b"The unbond period has passed, withdraw instead";
+
+pub static ERROR_INVALID_SPLIT_AMOUNT: &[u8] = b"Split amount must be lower than the position";
+
+pub static ERROR_INVALID_WITHDRAW_AMOUNT: &[u8] = b"Invalid withdraw amount";
+
+pub static ERROR_INVALID_RECEIVER: &[u8] = b"Invalid receiver address";
+
+pub static ERROR_WITHDRAW_TICKET_NOT_FOUND: &[u8] = b"Withdraw ticket not found";
+
+pub static ERROR_WITHDRAW_TICKET_TOO_SMALL: &[u8] = b"Withdraw ticket below the minimum amount";
+
+pub static ERROR_NOT_WITHDRAW_TICKET_OWNER: &[u8] = b"Only the ticket owner can claim it";
+
+pub static ERROR_NOTHING_TO_CLAIM: &[u8] = b"Nothing to claim";
+
+pub static ERROR_WITHDRAW_QUEUE_NOT_EMPTY: &[u8] =
+    b"The unbonded EGLD is owed to the queued withdraw tickets, use requestWithdraw";
+
+pub static ERROR_NO_WITHDRAWABLE_PROVIDERS: &[u8] = b"No provider has withdrawable EGLD";
+
+pub static ERROR_INVALID_BATCH_SIZE: &[u8] = b"Invalid batch size";
+
+pub static ERROR_ACTION_PAUSED: &[u8] = b"Action is paused";
+
+pub static ERROR_CONFIG_PROPOSAL_NOT_FOUND: &[u8] = b"Configuration proposal not found";
+
+pub static ERROR_TIMELOCK_NOT_EXPIRED: &[u8] = b"Configuration change is still timelocked";
+
+pub static ERROR_TIMELOCK_DELAY_TOO_SHORT: &[u8] = b"Timelock delay is below the minimum";
+
+pub static ERROR_FEES_TOO_HIGH: &[u8] = b"Fees are above the maximum";
+
+pub static ERROR_FEES_CHANGE_TOO_LARGE: &[u8] = b"Fees change is above the maximum delta";
+
+pub static ERROR_FEES_CHANGE_COOLDOWN: &[u8] = b"Fees were changed too recently";
+
+pub static ERROR_INVALID_UNBOND_PERIOD: &[u8] = b"Unbond period is out of bounds";
+
+pub static ERROR_INVALID_FEE_SPLIT: &[u8] = b"Fee recipient shares must add up to 100%";
+
+pub static ERROR_TOO_MANY_FEE_RECIPIENTS: &[u8] = b"Too many fee recipients";
+
+pub static ERROR_INVALID_REFERRAL_CODE: &[u8] = b"Invalid referral code";
+
+pub static ERROR_REFERRAL_CODE_TAKEN: &[u8] = b"Referral code already taken";
+
+pub static ERROR_REFERRAL_CODE_NOT_FOUND: &[u8] = b"Referral code not found";
+
+pub static ERROR_REFERRER_ALREADY_REGISTERED: &[u8] = b"Referrer already registered";
+
+pub static ERROR_NOT_A_REFERRER: &[u8] = b"Address is not a referrer";
+
+pub static ERROR_SELF_REFERRAL: &[u8] = b"Cannot use your own referral code";
+
+pub static ERROR_REFERRAL_FOR_OTHER_ADDRESS: &[u8] =
+    b"A referral code can only be used for the caller's own deposits";
+
+pub static ERROR_INVALID_REFERRAL_FEE_SHARE: &[u8] = b"Invalid referral fee share";
+
+pub static ERROR_INSURANCE_SHARE_TOO_HIGH: &[u8] = b"Insurance share is above the maximum";
+
+pub static ERROR_LOSS_ABOVE_PROVIDER_STAKE: &[u8] = b"Invalid provider loss amount";
+
+pub static ERROR_NO_RECORDED_LOSS: &[u8] = b"No recorded loss to socialize";
+
+pub static ERROR_PROVIDER_APPLICATIONS_DISABLED: &[u8] = b"Provider applications are disabled";
+
+pub static ERROR_INVALID_PROVIDER_APPLICATION_CONFIG: &[u8] =
+    b"Invalid provider application configuration";
+
+pub static ERROR_PROVIDER_APPLICATION_EXISTS: &[u8] = b"Provider application already submitted";
+
+pub static ERROR_PROVIDER_APPLICATION_NOT_FOUND: &[u8] = b"Provider application not found";
+
+pub static ERROR_PROVIDER_APPLICATION_EXPIRED: &[u8] = b"Provider application expired";
+
+pub static ERROR_TOO_MANY_PROVIDER_APPLICATIONS: &[u8] = b"Too many pending provider applications";
+
+pub static ERROR_PROVIDER_CHECKS_PENDING: &[u8] = b"Provider checks are not completed";
+
+pub static ERROR_PROVIDER_CHECKS_FAILED: &[u8] = b"Provider does not pass the checks";
+
+pub static ERROR_NOT_DELEGATION_CONTRACT: &[u8] = b"Address is not a delegation contract";
+
+pub static ERROR_NOT_DELEGATION_CONTRACT_OWNER: &[u8] =
+    b"Caller is not the owner of the delegation contract";
+
+pub static ERROR_AUTO_APPROVAL_NOT_REACHED: &[u8] = b"Application cannot be auto approved yet";
+
+pub static ERROR_PROVIDER_RETIRING: &[u8] = b"Provider is retiring";
+
+pub static ERROR_TOO_MANY_RETIRING_PROVIDERS: &[u8] = b"Too many retiring providers";
+
+pub static ERROR_INVALID_REBALANCE_AMOUNT: &[u8] = b"Invalid rebalance amount";
+
+pub static ERROR_NOTHING_TO_REBALANCE: &[u8] = b"Providers are within their target share";
+
+pub static ERROR_INVALID_SCORING_CONFIG: &[u8] = b"Invalid scoring configuration";
@@ -1,6 +1,12 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
-use crate::contexts::base::StorageCache;
+use crate::{
+    contexts::base::StorageCache,
+    structs::{
+        ConfigChange, ConfigProposal, DelegationContractData, DelegationFailures,
+        ProviderApplication, WithdrawTicket,
+    },
+};
 
 #[type_abi]
 #[derive(TopEncode)]
@@ -17,6 +23,7 @@ pub struct ChangeLiquidityEvent<M: ManagedTypeApi> {
     block: u64,
     epoch: u64,
     timestamp: u64,
+    receiver: ManagedAddress<M>,
 }
 
 #[multiversx_sc::module]
@@ -26,24 +33,10 @@ pub trait EventsModule:
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     fn emit_add_rewards_event(&self, storage_cache: &StorageCache<Self>, egld_amount: &BigUint) {
-        let epoch = self.blockchain().get_block_epoch();
         let caller = self.blockchain().get_caller();
         self.add_rewards_event(
             egld_amount,
-            &ChangeLiquidityEvent {
-                caller: caller.clone(),
-                ls_token_id: storage_cache.ls_token_id.clone(),
-                ls_token_supply: storage_cache.ls_token_supply.clone(),
-                virtual_egld_reserve: storage_cache.virtual_egld_reserve.clone(),
-                fees_reserve: storage_cache.fees_reserve.clone(),
-                total_withdrawn_egld: storage_cache.total_withdrawn_egld.clone(),
-                pending_egld: storage_cache.pending_egld.clone(),
-                pending_egld_for_unstake: storage_cache.pending_egld_for_unstake.clone(),
-                pending_egld_for_unbond: storage_cache.pending_egld_for_unbond.clone(),
-                block: self.blockchain().get_block_nonce(),
-                epoch,
-                timestamp: self.blockchain().get_block_timestamp(),
-            },
+            &self.build_change_liquidity_event(storage_cache, &caller, &caller),
         )
     }
 
@@ -52,50 +45,29 @@ pub trait EventsModule:
         storage_cache: &StorageCache<Self>,
         egld_amount: &BigUint,
         external_caller: Option<ManagedAddress>,
+        receiver: Option<ManagedAddress>,
     ) {
-        let epoch = self.blockchain().get_block_epoch();
         let caller = match external_caller {
             Some(external_caller) => external_caller,
             None => self.blockchain().get_caller(),
         };
+        let receiver = receiver.unwrap_or_else(|| caller.clone());
         self.add_liquidity_event(
             egld_amount,
-            &ChangeLiquidityEvent {
-                caller: caller.clone(),
-                ls_token_id: storage_cache.ls_token_id.clone(),
-                ls_token_supply: storage_cache.ls_token_supply.clone(),
-                virtual_egld_reserve: storage_cache.virtual_egld_reserve.clone(),
-                fees_reserve: storage_cache.fees_reserve.clone(),
-                total_withdrawn_egld: storage_cache.total_withdrawn_egld.clone(),
-                pending_egld: storage_cache.pending_egld.clone(),
-                pending_egld_for_unstake: storage_cache.pending_egld_for_unstake.clone(),
-                pending_egld_for_unbond: storage_cache.pending_egld_for_unbond.clone(),
-                block: self.blockchain().get_block_nonce(),
-                epoch,
-                timestamp: self.blockchain().get_block_timestamp(),
-            },
+            &self.build_change_liquidity_event(storage_cache, &caller, &receiver),
         )
     }
 
-    fn emit_remove_liquidity_event(&self, storage_cache: &StorageCache<Self>, ls_amount: &BigUint) {
-        let epoch = self.blockchain().get_block_epoch();
+    fn emit_remove_liquidity_event(
+        &self,
+        storage_cache: &StorageCache<Self>,
+        ls_amount: &BigUint,
+        receiver: &ManagedAddress,
+    ) {
         let caller = self.blockchain().get_caller();
         self.remove_liquidity_event(
             ls_amount,
-            &ChangeLiquidityEvent {
-                caller: caller.clone(),
-                ls_token_id: storage_cache.ls_token_id.clone(),
-                ls_token_supply: storage_cache.ls_token_supply.clone(),
-                virtual_egld_reserve: storage_cache.virtual_egld_reserve.clone(),
-                fees_reserve: storage_cache.fees_reserve.clone(),
-                total_withdrawn_egld: storage_cache.total_withdrawn_egld.clone(),
-                pending_egld: storage_cache.pending_egld.clone(),
-                pending_egld_for_unstake: storage_cache.pending_egld_for_unstake.clone(),
-                pending_egld_for_unbond: storage_cache.pending_egld_for_unbond.clone(),
-                block: self.blockchain().get_block_nonce(),
-                epoch,
-                timestamp: self.blockchain().get_block_timestamp(),
-            },
+            &self.build_change_liquidity_event(storage_cache, &caller, receiver),
         )
     }
 
@@ -111,20 +83,7 @@ pub trait EventsModule:
         self.protocol_revenue_event(fees, epoch);
         self.claim_rewards_event(
             egld_amount,
-            &ChangeLiquidityEvent {
-                caller: caller.clone(),
-                ls_token_id: storage_cache.ls_token_id.clone(),
-                ls_token_supply: storage_cache.ls_token_supply.clone(),
-                virtual_egld_reserve: storage_cache.virtual_egld_reserve.clone(),
-                fees_reserve: storage_cache.fees_reserve.clone(),
-                total_withdrawn_egld: storage_cache.total_withdrawn_egld.clone(),
-                pending_egld: storage_cache.pending_egld.clone(),
-                pending_egld_for_unstake: storage_cache.pending_egld_for_unstake.clone(),
-                pending_egld_for_unbond: storage_cache.pending_egld_for_unbond.clone(),
-                block: self.blockchain().get_block_nonce(),
-                epoch,
-                timestamp: self.blockchain().get_block_timestamp(),
-            },
+            &self.build_change_liquidity_event(storage_cache, &caller, &caller),
         )
     }
 
@@ -134,32 +93,57 @@ pub trait EventsModule:
         egld_amount: &BigUint,
         delegation_contract: &ManagedAddress,
     ) {
-        let epoch = self.blockchain().get_block_epoch();
         let caller = self.blockchain().get_caller();
         self.withdraw_pending_event(
             egld_amount,
-            &ChangeLiquidityEvent {
-                caller: caller.clone(),
-                ls_token_id: storage_cache.ls_token_id.clone(),
-                ls_token_supply: storage_cache.ls_token_supply.clone(),
-                virtual_egld_reserve: storage_cache.virtual_egld_reserve.clone(),
-                fees_reserve: storage_cache.fees_reserve.clone(),
-                total_withdrawn_egld: storage_cache.total_withdrawn_egld.clone(),
-                pending_egld: storage_cache.pending_egld.clone(),
-                pending_egld_for_unstake: storage_cache.pending_egld_for_unstake.clone(),
-                pending_egld_for_unbond: storage_cache.pending_egld_for_unbond.clone(),
-                block: self.blockchain().get_block_nonce(),
-                epoch,
-                timestamp: self.blockchain().get_block_timestamp(),
-            },
+            &self.build_change_liquidity_event(storage_cache, &caller, &caller),
             delegation_contract,
         )
     }
 
+    fn emit_early_redemption_event(
+        &self,
+        storage_cache: &StorageCache<Self>,
+        egld_amount: &BigUint,
+        fee: &BigUint,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.early_redemption_event(
+            egld_amount,
+            fee,
+            &self.build_change_liquidity_event(storage_cache, &caller, &caller),
+        )
+    }
+
     fn emit_general_liquidity_event(&self, storage_cache: &StorageCache<Self>) {
-        let epoch = self.blockchain().get_block_epoch();
         let caller = self.blockchain().get_caller();
-        self.general_liquidity_event(&ChangeLiquidityEvent {
+        self.emit_general_liquidity_event_for(storage_cache, &caller);
+    }
+
+    fn emit_general_liquidity_event_for(
+        &self,
+        storage_cache: &StorageCache<Self>,
+        receiver: &ManagedAddress,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.general_liquidity_event(&self.build_change_liquidity_event(
+            storage_cache,
+            &caller,
+            receiver,
+        ))
+    }
+
+    fn emit_withdraw_ticket_event(&self, ticket_id: u64, ticket: &WithdrawTicket<Self::Api>) {
+        self.withdraw_ticket_event(ticket_id, ticket, self.blockchain().get_block_epoch());
+    }
+
+    fn build_change_liquidity_event(
+        &self,
+        storage_cache: &StorageCache<Self>,
+        caller: &ManagedAddress,
+        receiver: &ManagedAddress,
+    ) -> ChangeLiquidityEvent<Self::Api> {
+        ChangeLiquidityEvent {
             caller: caller.clone(),
             ls_token_id: storage_cache.ls_token_id.clone(),
             ls_token_supply: storage_cache.ls_token_supply.clone(),
@@ -170,9 +154,10 @@ pub trait EventsModule:
             pending_egld_for_unstake: storage_cache.pending_egld_for_unstake.clone(),
             pending_egld_for_unbond: storage_cache.pending_egld_for_unbond.clone(),
             block: self.blockchain().get_block_nonce(),
-            epoch,
+            epoch: self.blockchain().get_block_epoch(),
             timestamp: self.blockchain().get_block_timestamp(),
-        })
+            receiver: receiver.clone(),
+        }
     }
 
     #[event("add_rewards")]
@@ -211,6 +196,134 @@ pub trait EventsModule:
         #[indexed] change_liquidity_event: &ChangeLiquidityEvent<Self::Api>,
     );
 
+    #[event("early_redemption")]
+    fn early_redemption_event(
+        &self,
+        #[indexed] amount: &BigUint,
+        #[indexed] fee: &BigUint,
+        #[indexed] change_liquidity_event: &ChangeLiquidityEvent<Self::Api>,
+    );
+
+    #[event("withdraw_ticket")]
+    fn withdraw_ticket_event(
+        &self,
+        #[indexed] ticket_id: u64,
+        #[indexed] ticket: &WithdrawTicket<Self::Api>,
+        #[indexed] epoch: u64,
+    );
+
+    #[event("config_change_proposed")]
+    fn config_change_proposed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] proposal: &ConfigProposal<Self::Api>,
+    );
+
+    #[event("config_change_executed")]
+    fn config_change_executed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] change: &ConfigChange<Self::Api>,
+    );
+
+    #[event("config_change_cancelled")]
+    fn config_change_cancelled_event(&self, #[indexed] proposal_id: u64);
+
+    #[event("fees_claimed")]
+    fn fees_claimed_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("referrer_registered")]
+    fn referrer_registered_event(
+        &self,
+        #[indexed] referrer: &ManagedAddress,
+        #[indexed] code: &ManagedBuffer,
+    );
+
+    #[event("referral_rewards_claimed")]
+    fn referral_rewards_claimed_event(
+        &self,
+        #[indexed] referrer: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("provider_loss_recorded")]
+    fn provider_loss_recorded_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("provider_loss_reversed")]
+    fn provider_loss_reversed_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("provider_application_submitted")]
+    fn provider_application_submitted_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] application: &ProviderApplication<Self::Api>,
+    );
+
+    #[event("provider_application_approved")]
+    fn provider_application_approved_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] approver: &ManagedAddress,
+    );
+
+    #[event("provider_application_cancelled")]
+    fn provider_application_cancelled_event(&self, #[indexed] contract_address: &ManagedAddress);
+
+    #[event("provider_retiring")]
+    fn provider_retiring_event(&self, #[indexed] contract_address: &ManagedAddress);
+
+    #[event("provider_removed")]
+    fn provider_removed_event(&self, #[indexed] contract_address: &ManagedAddress);
+
+    #[event("provider_delegation_failed")]
+    fn provider_delegation_failed_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] failures: &DelegationFailures,
+    );
+
+    #[event("provider_readmitted")]
+    fn provider_readmitted_event(&self, #[indexed] contract_address: &ManagedAddress);
+
+    #[event("provider_rebalanced")]
+    fn provider_rebalanced_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("provider_synced")]
+    fn provider_synced_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] contract_data: &DelegationContractData<Self::Api>,
+    );
+
+    #[event("provider_reconciled")]
+    fn provider_reconciled_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] booked_stake: &BigUint,
+        #[indexed] active_stake: &BigUint,
+        #[indexed] epoch: u64,
+    );
+
+    #[event("losses_socialized")]
+    fn losses_socialized_event(&self, #[indexed] covered: &BigUint, #[indexed] uncovered: &BigUint);
+
     #[event("protocol_revenue")]
     fn protocol_revenue_event(&self, #[indexed] amount: &BigUint, #[indexed] epoch: u64);
 
@@ -0,0 +1,223 @@
+multiversx_sc::imports!();
+use crate::{
+    constants::BPS,
+    errors::{ERROR_LOSS_ABOVE_PROVIDER_STAKE, ERROR_NOT_WHITELISTED, ERROR_NO_RECORDED_LOSS},
+    structs::{ProviderLosses, Role},
+    StorageCache,
+};
+
+#[multiversx_sc::module]
+pub trait InsuranceModule:
+    crate::config::ConfigModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    /// Records EGLD lost by a provider, e.g. after a slashing or a stuck delegation.
+    /// The provider stake is reduced right away, the loss stays recorded until it is
+    /// socialized through `socializeLosses`.
+    #[endpoint(recordProviderLoss)]
+    fn record_provider_loss(&self, contract_address: ManagedAddress, amount: BigUint) {
+        self.has_role(&self.blockchain().get_caller(), Role::Guardian, true);
+
+        let contract_data_mapper = self.delegation_contract_data(&contract_address);
+        require!(!contract_data_mapper.is_empty(), ERROR_NOT_WHITELISTED);
+        require!(
+            amount > BigUint::zero()
+                && amount <= contract_data_mapper.get().total_staked_from_ls_contract,
+            ERROR_LOSS_ABOVE_PROVIDER_STAKE
+        );
+
+        self.book_provider_loss(&contract_address, &amount);
+    }
+
+    /// Covers the recorded losses with the insurance reserve first. The covered EGLD is
+    /// staked again, only the uncovered remainder lowers the virtual EGLD reserve.
+    ///
+    /// Returns the covered and the uncovered amounts.
+    #[endpoint(socializeLosses)]
+    fn socialize_losses(&self) -> MultiValue2<BigUint, BigUint> {
+        self.has_role(&self.blockchain().get_caller(), Role::Guardian, true);
+
+        require!(
+            self.recorded_losses().get() > BigUint::zero(),
+            ERROR_NO_RECORDED_LOSS
+        );
+
+        let mut storage_cache = StorageCache::new(self);
+        let mut providers = ManagedVec::<Self::Api, ManagedAddress>::new();
+        for provider in self.unsocialized_loss_providers().iter() {
+            providers.push(provider);
+        }
+        let mut total_covered = BigUint::zero();
+        let mut total_uncovered = BigUint::zero();
+        for provider in providers.iter() {
+            let (covered, uncovered) =
+                self.socialize_provider_losses(&mut storage_cache, &provider);
+            total_covered += covered;
+            total_uncovered += uncovered;
+        }
+        self.emit_general_liquidity_event(&storage_cache);
+
+        (total_covered, total_uncovered).into()
+    }
+
+    /// Removes a loss from the provider stake and records it until it is socialized.
+    fn book_provider_loss(&self, contract_address: &ManagedAddress, amount: &BigUint) {
+        self.delegation_contract_data(contract_address)
+            .update(|contract_data| {
+                contract_data.total_staked_from_ls_contract -= amount;
+            });
+        self.recorded_losses().update(|losses| *losses += amount);
+        self.update_provider_losses(contract_address, |losses| {
+            losses.unsocialized += amount;
+        });
+        self.unsocialized_loss_providers()
+            .insert(contract_address.clone());
+
+        self.provider_loss_recorded_event(contract_address, amount);
+    }
+
+    /// Socializes the recorded losses of one provider and keeps how they were paid, so
+    /// they can be refunded if the stake shows up again.
+    fn socialize_provider_losses(
+        &self,
+        storage_cache: &mut StorageCache<Self>,
+        contract_address: &ManagedAddress,
+    ) -> (BigUint, BigUint) {
+        self.unsocialized_loss_providers()
+            .swap_remove(contract_address);
+
+        let mut socialized = (BigUint::zero(), BigUint::zero());
+        self.update_provider_losses(contract_address, |losses| {
+            let unsocialized = core::mem::replace(&mut losses.unsocialized, BigUint::zero());
+            socialized = self.socialize_loss(storage_cache, &unsocialized);
+            losses.covered += &socialized.0;
+            losses.uncovered += &socialized.1;
+        });
+
+        // A removed provider cannot be reconciled anymore
+        if self.delegation_contract_data(contract_address).is_empty() {
+            self.provider_losses(contract_address).clear();
+        }
+
+        socialized
+    }
+
+    /// Reverses up to `amount` of the losses booked on the provider, e.g. a loss recorded by
+    /// mistake whose stake shows up again. The unsocialized part is recorded back on the
+    /// provider, the socialized part refunds the xEGLD holders and then the insurance reserve.
+    /// Returns the amount added back to the provider stake.
+    fn reverse_provider_losses(
+        &self,
+        storage_cache: &mut StorageCache<Self>,
+        contract_address: &ManagedAddress,
+        amount: &BigUint,
+    ) -> BigUint {
+        if self.provider_losses(contract_address).is_empty() {
+            return BigUint::zero();
+        }
+
+        let mut reversed = BigUint::zero();
+        self.update_provider_losses(contract_address, |losses| {
+            let unsocialized = losses.unsocialized.clone().min(amount.clone());
+            losses.unsocialized -= &unsocialized;
+            self.recorded_losses()
+                .update(|recorded| *recorded -= &unsocialized);
+            reversed += unsocialized;
+
+            let uncovered = losses.uncovered.clone().min(amount - &reversed);
+            losses.uncovered -= &uncovered;
+            storage_cache.virtual_egld_reserve += &uncovered;
+            reversed += uncovered;
+
+            // The covered EGLD was staked again, the pending EGLD gives it back to the reserve
+            let covered = losses
+                .covered
+                .clone()
+                .min(amount - &reversed)
+                .min(storage_cache.pending_egld.clone());
+            losses.covered -= &covered;
+            storage_cache.pending_egld -= &covered;
+            self.insurance_reserve()
+                .update(|reserve| *reserve += &covered);
+            reversed += covered;
+        });
+
+        let losses_mapper = self.provider_losses(contract_address);
+        if losses_mapper.is_empty() || losses_mapper.get().unsocialized == BigUint::zero() {
+            self.unsocialized_loss_providers()
+                .swap_remove(contract_address);
+        }
+
+        if reversed > BigUint::zero() {
+            self.delegation_contract_data(contract_address)
+                .update(|contract_data| {
+                    contract_data.total_staked_from_ls_contract += &reversed;
+                });
+            self.provider_loss_reversed_event(contract_address, &reversed);
+        }
+
+        reversed
+    }
+
+    // Fully reversed losses are cleared
+    fn update_provider_losses<F: FnOnce(&mut ProviderLosses<Self::Api>)>(
+        &self,
+        contract_address: &ManagedAddress,
+        f: F,
+    ) {
+        let losses_mapper = self.provider_losses(contract_address);
+        let mut losses = if losses_mapper.is_empty() {
+            ProviderLosses {
+                unsocialized: BigUint::zero(),
+                covered: BigUint::zero(),
+                uncovered: BigUint::zero(),
+            }
+        } else {
+            losses_mapper.get()
+        };
+
+        f(&mut losses);
+
+        if losses.is_empty() {
+            losses_mapper.clear();
+        } else {
+            losses_mapper.set(&losses);
+        }
+    }
+
+    /// Covers `amount` of the recorded losses, with the insurance reserve first.
+    /// Returns the covered and the uncovered amounts.
+    fn socialize_loss(
+        &self,
+        storage_cache: &mut StorageCache<Self>,
+        amount: &BigUint,
+    ) -> (BigUint, BigUint) {
+        self.recorded_losses().update(|losses| *losses -= amount);
+
+        let insurance_reserve_mapper = self.insurance_reserve();
+        let covered = insurance_reserve_mapper.get().min(amount.clone());
+        let uncovered = amount - &covered;
+
+        insurance_reserve_mapper.update(|reserve| *reserve -= &covered);
+        storage_cache.pending_egld += &covered;
+        storage_cache.virtual_egld_reserve -= &uncovered;
+
+        self.losses_socialized_event(&covered, &uncovered);
+
+        (covered, uncovered)
+    }
+
+    /// Moves the insurance share of the claimed rewards to the insurance reserve.
+    /// Returns the part of the rewards taken.
+    fn accrue_insurance(&self, rewards: &BigUint) -> BigUint {
+        let insurance = rewards * &BigUint::from(self.insurance_share().get()) / BPS;
+        if insurance > BigUint::zero() {
+            self.insurance_reserve()
+                .update(|reserve| *reserve += &insurance);
+        }
+
+        insurance
+    }
+}
@@ -3,30 +3,37 @@
 multiversx_sc::derive_imports!();
 multiversx_sc::imports!();
 
+pub mod apr;
 pub mod callback;
 pub mod config;
 pub mod constants;
 pub mod contexts;
 pub mod delegation;
+pub mod early_redemption;
 pub mod errors;
 pub mod events;
+pub mod insurance;
 pub mod liquidity_pool;
 pub mod manage;
 pub mod migrate;
+pub mod provider_application;
 pub mod proxy;
+pub mod referral;
 pub mod score;
 pub mod selection;
 pub mod storage;
 pub mod structs;
+pub mod timelock;
 pub mod utils;
 pub mod views;
+pub mod withdraw_queue;
 
 use callback::{CallbackModule, CallbackProxy};
 use constants::*;
 use contexts::base::*;
 use errors::*;
 use proxy::proxy_delegation;
-use structs::{State, UnstakeTokenAttributes};
+use structs::{PausableAction, Role, ScoringConfig, State};
 
 #[multiversx_sc::contract]
 pub trait LiquidStaking<ContractReader>:
@@ -44,11 +51,40 @@ pub trait LiquidStaking<ContractReader>:
     + liquidity_pool::LiquidityPoolModule
     + utils::delegate::DelegateUtilsModule
     + utils::un_delegation::UnDelegateUtilsModule
+    + apr::AprModule
+    + early_redemption::EarlyRedemptionModule
+    + withdraw_queue::WithdrawQueueModule
+    + timelock::TimelockModule
+    + referral::ReferralModule
+    + insurance::InsuranceModule
+    + provider_application::ProviderApplicationModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     #[upgrade]
     fn upgrade(&self) {
         self.state().set(State::Inactive);
+
+        if self.config_timelock_delay().is_empty() {
+            self.config_timelock_delay()
+                .set(DEFAULT_CONFIG_TIMELOCK_DELAY);
+        }
+
+        // Managers keep what they were allowed to do, guardians are granted explicitly
+        for manager in self.managers().iter() {
+            for role in Role::ALL {
+                if role != Role::Guardian {
+                    self.add_role(manager.clone(), role);
+                }
+            }
+        }
+        self.managers().clear();
+
+        // Configs stored before the scoring curves keep scoring the same way
+        if self.scoring_config().raw_byte_length() == LEGACY_SCORING_CONFIG_LENGTH {
+            let legacy_config = self.legacy_scoring_config().get();
+            self.scoring_config()
+                .set(ScoringConfig::from(legacy_config));
+        }
     }
 
     /// Initializes the Liquid Staking contract with essential parameters, setting up
@@ -72,6 +108,7 @@ pub trait LiquidStaking<ContractReader>:
         self.state().set(State::Inactive);
 
         require!(max_selected_providers >= 1, ERROR_MAX_SELECTED_PROVIDERS);
+        require!(fees <= MAX_FEES, ERROR_FEES_TOO_HIGH);
 
         require!(
             max_delegation_addresses >= 1,
@@ -85,6 +122,8 @@ pub trait LiquidStaking<ContractReader>:
 
         self.accumulator_contract().set(accumulator_contract);
         self.fees().set(fees);
+        self.config_timelock_delay()
+            .set(DEFAULT_CONFIG_TIMELOCK_DELAY);
     }
 
     /// Delegates EGLD to the staking pool by minting xEGLD tokens for the user,
@@ -105,6 +144,8 @@ pub trait LiquidStaking<ContractReader>:
         let caller = self.blockchain().get_caller();
 
         if let Some(provider) = to.into_option() {
+            self.require_action_not_paused(PausableAction::InstantDelegation);
+
             let min_egld_amount = BigUint::from(MIN_EGLD_TO_DELEGATE);
             let map_delegation_contract_data = self.delegation_contract_data(&provider);
 
@@ -145,31 +186,98 @@ pub trait LiquidStaking<ContractReader>:
             let (pending, extra) =
                 self.get_action_amount(&storage_cache.pending_egld_for_unstake, &payment);
 
-            let result = self.process_delegation(&mut storage_cache, &pending, &extra, &caller);
+            let result = self.process_delegation(
+                &mut storage_cache,
+                &pending,
+                &extra,
+                &caller,
+                &BigUint::zero(),
+            );
 
             return OptionalValue::Some(result);
         }
     }
 
+    /// Delegates EGLD to the staking pool like `delegate` without a provider, but reverts
+    /// when the minted xEGLD is lower than `min_ls_out`. Protects integrators against
+    /// exchange rate moves (rewards claims, `addRewards`) between quote and execution.
+    ///
+    /// Arguments:
+    /// - `min_ls_out`: Reverts when the minted xEGLD is lower, zero disables the check.
+    /// - `referrer_code`: Optional. Attributes the minted xEGLD to the referrer of the code.
+    #[payable("EGLD")]
+    #[endpoint(delegateWithMinOut)]
+    fn delegate_with_min_out(
+        &self,
+        min_ls_out: BigUint,
+        referrer_code: OptionalValue<ManagedBuffer>,
+    ) -> EsdtTokenPayment {
+        let caller = self.blockchain().get_caller();
+
+        self.delegate_to_pool(&caller, &min_ls_out, referrer_code)
+    }
+
+    /// Delegates EGLD to the staking pool and sends the minted xEGLD to `receiver`
+    /// instead of the caller.
+    ///
+    /// Arguments:
+    /// - `receiver`: Address receiving the minted xEGLD.
+    /// - `min_ls_out`: Optional. Reverts when the minted xEGLD is lower.
+    /// - `referrer_code`: Optional. Attributes the xEGLD minted to `receiver` to the
+    ///                    referrer of the code, only accepted when the caller is `receiver`.
+    #[allow_multiple_var_args]
+    #[payable("EGLD")]
+    #[endpoint(delegateFor)]
+    fn delegate_for(
+        &self,
+        receiver: ManagedAddress,
+        min_ls_out: OptionalValue<BigUint>,
+        referrer_code: OptionalValue<ManagedBuffer>,
+    ) -> EsdtTokenPayment {
+        self.validate_receiver(&receiver);
+
+        let min_ls_out = min_ls_out.into_option().unwrap_or_else(BigUint::zero);
+        self.delegate_to_pool(&receiver, &min_ls_out, referrer_code)
+    }
+
     /// Initiates the un-delegation process, enabling users to withdraw their stake.
     /// Depending on the available pending EGLD in the contract, users can receive
     /// an instant return without fees or enter a 10-day unbonding period.
+    ///
+    /// Arguments:
+    /// - `min_egld_out`: Optional. Reverts when the EGLD value of the burned xEGLD is lower.
+    /// - `instant_only`: Optional. When `true`, reverts instead of minting an unstake MetaESDT
+    ///                   if the pending EGLD cannot cover the full amount instantly.
+    #[allow_multiple_var_args]
     #[payable("*")]
     #[endpoint(unDelegate)]
-    fn un_delegate(&self) {
+    fn un_delegate(&self, min_egld_out: OptionalValue<BigUint>, instant_only: OptionalValue<bool>) {
         let mut storage_cache = StorageCache::new(self);
+        let caller = self.blockchain().get_caller();
 
-        let payment = self.call_value().single_esdt();
-
-        self.validate_undelegate_conditions(&mut storage_cache, &payment);
-
-        let unstaked_egld = self.pool_remove_liquidity(&payment.amount, &mut storage_cache);
-        self.burn_ls_token(&payment.amount);
+        self.un_delegate_to(&mut storage_cache, &caller, min_egld_out, instant_only);
+    }
 
-        let (instant, undelegate) =
-            self.get_action_amount(&storage_cache.pending_egld, &unstaked_egld);
+    /// Same as `unDelegate`, but the instant EGLD and the unstake MetaESDT are sent to
+    /// `receiver` instead of the caller. Lets smart contract vaults relay positions for end users.
+    ///
+    /// Arguments:
+    /// - `receiver`: Address receiving the EGLD and the unstake MetaESDT.
+    /// - `min_egld_out`: Optional. Reverts when the EGLD value of the burned xEGLD is lower.
+    /// - `instant_only`: Optional. Reverts if the full amount cannot be redeemed instantly.
+    #[allow_multiple_var_args]
+    #[payable("*")]
+    #[endpoint(unDelegateFor)]
+    fn un_delegate_for(
+        &self,
+        receiver: ManagedAddress,
+        min_egld_out: OptionalValue<BigUint>,
+        instant_only: OptionalValue<bool>,
+    ) {
+        let mut storage_cache = StorageCache::new(self);
+        self.validate_receiver(&receiver);
 
-        self.process_un_delegation(&mut storage_cache, &instant, &undelegate);
+        self.un_delegate_to(&mut storage_cache, &receiver, min_egld_out, instant_only);
     }
 
     /// Withdraws funds once the un-delegation process is complete. If the unbonding period
@@ -178,70 +286,177 @@ pub trait LiquidStaking<ContractReader>:
     #[payable("*")]
     #[endpoint(withdraw)]
     fn withdraw(&self) {
+        let mut storage_cache = StorageCache::new(self);
+        let caller = self.blockchain().get_caller();
+
+        self.withdraw_to(&mut storage_cache, &caller);
+    }
+
+    /// Same as `withdraw`, but the EGLD and any remaining unstake MetaESDT are sent to `receiver`.
+    ///
+    /// Arguments:
+    /// - `receiver`: Address receiving the withdrawn EGLD.
+    #[payable("*")]
+    #[endpoint(withdrawFor)]
+    fn withdraw_for(&self, receiver: ManagedAddress) {
+        let mut storage_cache = StorageCache::new(self);
+        self.validate_receiver(&receiver);
+
+        self.withdraw_to(&mut storage_cache, &receiver);
+    }
+
+    /// Withdraws an explicit amount from a single unbonded position, the rest of the
+    /// unstake MetaESDT is sent back to the caller.
+    ///
+    /// Arguments:
+    /// - `amount`: EGLD amount to withdraw, at most the amount of the sent position.
+    #[payable("*")]
+    #[endpoint(withdrawAmount)]
+    fn withdraw_amount(&self, amount: BigUint) {
         let mut storage_cache = StorageCache::new(self);
         self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::Withdraw);
+
+        let available = self.get_direct_withdraw_available(&storage_cache);
 
         let caller = self.blockchain().get_caller();
-        let payments = self.call_value().all_esdt_transfers();
-        let unstake_token_id = self.unstake_token().get_token_id();
-        let current_epoch = self.blockchain().get_block_epoch();
+        let payment = self.call_value().single_esdt();
+        let unstake_token_attributes = self.get_unstake_token_attributes(&payment);
 
-        let mut to_send = BigUint::zero();
+        require!(
+            self.blockchain().get_block_epoch() >= unstake_token_attributes.unbond_epoch,
+            ERROR_UNSTAKE_PERIOD_NOT_PASSED
+        );
+        require!(
+            amount > BigUint::zero() && amount <= payment.amount,
+            ERROR_INVALID_WITHDRAW_AMOUNT
+        );
+        require!(available >= amount, ERROR_INSUFFICIENT_UNBONDED_AMOUNT);
 
-        for payment in payments.clone().into_iter() {
-            require!(
-                payment.token_identifier == unstake_token_id,
-                ERROR_BAD_PAYMENT_TOKEN
-            );
+        self.burn_unstake_tokens(payment.token_nonce, &amount);
+        storage_cache.total_withdrawn_egld -= &amount;
+
+        let remaining_amount = &payment.amount - &amount;
+        if remaining_amount > BigUint::zero() {
+            self.tx()
+                .to(&caller)
+                .single_esdt(
+                    &payment.token_identifier,
+                    payment.token_nonce,
+                    &remaining_amount,
+                )
+                .transfer();
+        }
 
-            require!(payment.amount > BigUint::zero(), ERROR_BAD_PAYMENT_AMOUNT);
+        self.tx().to(&caller).egld(&amount).transfer();
+        self.emit_general_liquidity_event(&storage_cache);
+    }
 
-            let unstake_token_attributes: UnstakeTokenAttributes = self
-                .unstake_token()
-                .get_token_attributes(payment.token_nonce);
+    /// Splits an unstake MetaESDT in two positions with the same unbond epoch: `amount` moves
+    /// to a new nonce and the rest stays on the sent nonce, so each part can be transferred
+    /// or withdrawn separately.
+    #[payable("*")]
+    #[endpoint(splitUnstakeTokens)]
+    fn split_unstake_tokens(
+        &self,
+        amount: BigUint,
+    ) -> MultiValue2<EsdtTokenPayment, EsdtTokenPayment> {
+        let storage_cache = StorageCache::new(self);
+        self.require_unstake_positions_editable(&storage_cache);
 
-            require!(
-                current_epoch >= unstake_token_attributes.unbond_epoch,
-                ERROR_UNSTAKE_PERIOD_NOT_PASSED
-            );
+        let payment = self.call_value().single_esdt().clone_value();
+        let unstake_token_attributes = self.get_unstake_token_attributes(&payment);
 
-            if storage_cache.total_withdrawn_egld >= payment.amount {
-                self.burn_unstake_tokens(payment.token_nonce, &payment.amount);
+        require!(
+            amount > BigUint::zero() && amount < payment.amount,
+            ERROR_INVALID_SPLIT_AMOUNT
+        );
 
-                storage_cache.total_withdrawn_egld -= &payment.amount;
-                to_send += payment.amount;
-            } else if storage_cache.total_withdrawn_egld > BigUint::zero() {
-                // In this case the required amount of the MetaESDT is higher than the available amount
-                // This case can happen only when the amount from the providers didn't arrive yet in the protocol
-                // In this case we partially give to the user the available amount and return the remaining MetaESDT to the user
-                self.burn_unstake_tokens(
-                    payment.token_nonce,
-                    &storage_cache.total_withdrawn_egld,
-                );
-
-                let remaining_amount = payment.amount - &storage_cache.total_withdrawn_egld;
-
-                // Send the remaining amount to the user
-                self.tx()
-                    .to(&caller)
-                    .single_esdt(
-                        &payment.token_identifier,
-                        payment.token_nonce,
-                        &remaining_amount,
-                    )
-                    .transfer();
-
-                // Send the amount to the user
-                to_send += storage_cache.total_withdrawn_egld.clone();
-
-                // Reset the total withdrawn amount to 0
-                storage_cache.total_withdrawn_egld = BigUint::zero();
+        let split_nonce = self.create_unstake_token_nonce(
+            &unstake_token_attributes,
+            &amount,
+            unstake_token_attributes.unbond_epoch,
+        );
+        self.burn_unstake_tokens(payment.token_nonce, &amount);
+        // A split nonce never receives later quantities, the retained unit is not needed
+        self.burn_unstake_tokens(split_nonce, &BigUint::from(1u64));
+
+        let remaining_amount = &payment.amount - &amount;
+        let first_part =
+            EsdtTokenPayment::new(payment.token_identifier.clone(), split_nonce, amount);
+        let second_part = EsdtTokenPayment::new(
+            payment.token_identifier,
+            payment.token_nonce,
+            remaining_amount,
+        );
+
+        let mut payments = ManagedVec::new();
+        payments.push(first_part.clone());
+        payments.push(second_part.clone());
+
+        let caller = self.blockchain().get_caller();
+        self.tx().to(&caller).multi_esdt(payments).transfer();
+
+        (first_part, second_part).into()
+    }
+
+    /// Merges unstake positions into the sent position with the latest unbond epoch.
+    /// The merged EGLD can only be withdrawn once that latest unbond epoch is reached.
+    #[payable("*")]
+    #[endpoint(mergeUnstakeTokens)]
+    fn merge_unstake_tokens(&self) -> EsdtTokenPayment {
+        let storage_cache = StorageCache::new(self);
+        self.require_unstake_positions_editable(&storage_cache);
+
+        let payments = self.call_value().all_esdt_transfers();
+        require!(!payments.is_empty(), ERROR_BAD_PAYMENT_AMOUNT);
+
+        let mut target_nonce = 0u64;
+        let mut target_unbond_epoch = 0u64;
+        let mut total_amount = BigUint::zero();
+        for payment in payments.clone().into_iter() {
+            let unstake_token_attributes = self.get_unstake_token_attributes(&payment);
+            if target_nonce == 0 || unstake_token_attributes.unbond_epoch > target_unbond_epoch {
+                target_nonce = payment.token_nonce;
+                target_unbond_epoch = unstake_token_attributes.unbond_epoch;
+            }
+            total_amount += &payment.amount;
+        }
+
+        let mut target_amount = BigUint::zero();
+        for payment in payments.clone().into_iter() {
+            if payment.token_nonce == target_nonce {
+                target_amount += &payment.amount;
             } else {
-                sc_panic!(ERROR_INSUFFICIENT_UNBONDED_AMOUNT);
+                self.burn_unstake_tokens(payment.token_nonce, &payment.amount);
             }
         }
 
-        self.tx().to(&caller).egld(&to_send).transfer();
-        self.emit_general_liquidity_event(&storage_cache);
+        // The contract holds the received target position, so it can add quantity to its nonce
+        if total_amount > target_amount {
+            self.unstake_token()
+                .nft_add_quantity(target_nonce, &total_amount - &target_amount);
+        }
+
+        let merged_payment = EsdtTokenPayment::new(
+            self.unstake_token().get_token_id(),
+            target_nonce,
+            total_amount,
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.tx()
+            .to(&caller)
+            .esdt(merged_payment.clone())
+            .transfer();
+
+        merged_payment
+    }
+
+    // Positions are not reshaped while the exits are paused
+    fn require_unstake_positions_editable(&self, storage_cache: &StorageCache<Self>) {
+        self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::UnDelegate);
+        self.require_action_not_paused(PausableAction::Withdraw);
     }
 }
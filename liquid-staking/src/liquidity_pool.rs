@@ -86,36 +86,48 @@ pub trait LiquidityPoolModule: config::ConfigModule + storage::StorageModule {
     ) -> EsdtTokenPayment<Self::Api> {
         let nonce = self.unstake_token_nonce(unbond_epoch);
         if nonce.is_empty() {
-            let uri = ManagedBuffer::from(UNDELEGATE_TOKEN_URI);
             let token_id = self.unstake_token().get_token_id();
-
-            // Always add extra one to the initial MetaESDT amount
-            // The extra 1 will remain in the contract and will be used to add later quantities for the same epoch
-            let new_nonce = self.send().esdt_nft_create(
-                &token_id,
-                &amount.add(&BigUint::from(1u64)),
-                &sc_format!("Release epoch #{}", unbond_epoch),
-                &BigUint::zero(),
-                &ManagedBuffer::new(),
-                attributes,
-                &ManagedVec::from_single_item(uri),
-            );
+            let new_nonce = self.create_unstake_token_nonce(attributes, amount, unbond_epoch);
 
             nonce.set(new_nonce);
 
-            if new_nonce > 1 {
-                self.clean_old_unbond_epochs(new_nonce - 1, current_epoch);
+            // The previous nonce can be a split position, only the epoch nonces are cleaned
+            let last_epoch_nonce = self.last_unbond_epoch_nonce().replace(new_nonce);
+            if last_epoch_nonce > 0 {
+                self.clean_old_unbond_epochs(last_epoch_nonce, current_epoch);
             }
 
             EsdtTokenPayment::new(token_id, new_nonce, amount.clone())
         } else {
-            
-            self
-                .unstake_token()
+            self.unstake_token()
                 .nft_add_quantity(nonce.get(), amount.clone())
         }
     }
 
+    /// Creates a new nonce of the unstake token and returns it, the caller sends `amount`.
+    fn create_unstake_token_nonce<T: TopEncode>(
+        &self,
+        attributes: &T,
+        amount: &BigUint,
+        unbond_epoch: u64,
+    ) -> u64 {
+        let uri = ManagedBuffer::from(UNDELEGATE_TOKEN_URI);
+        let token_id = self.unstake_token().get_token_id();
+
+        // Always add extra one to the initial MetaESDT amount
+        // The extra 1 will remain in the contract and will be used to add later quantities for the same epoch
+        // and to read the attributes when the nonce is cleaned
+        self.send().esdt_nft_create(
+            &token_id,
+            &amount.add(&BigUint::from(1u64)),
+            &sc_format!("Release epoch #{}", unbond_epoch),
+            &BigUint::zero(),
+            &ManagedBuffer::new(),
+            attributes,
+            &ManagedVec::from_single_item(uri),
+        )
+    }
+
     fn burn_unstake_tokens(&self, token_nonce: u64, amount: &BigUint) {
         self.unstake_token().nft_burn(token_nonce, amount);
     }
@@ -126,6 +138,7 @@ pub trait LiquidityPoolModule: config::ConfigModule + storage::StorageModule {
         let attributes: UnstakeTokenAttributes = map_token.get_token_attributes(nonce);
         if attributes.unstake_epoch < current_epoch {
             self.unstake_token_nonce(attributes.unbond_epoch).clear();
+
             // The protocol always holds 1 unit of the MetaESDT token in the contract
             let balance = map_token.get_balance(nonce);
             map_token.nft_burn(nonce, &balance);
@@ -1,11 +1,16 @@
 multiversx_sc::imports!();
 use crate::{
     callback::{CallbackModule, CallbackProxy},
-    errors::ERROR_NO_DELEGATION_CONTRACTS,
-    proxy::{proxy_accumulator, proxy_delegation, proxy_delegation_manager},
-    StorageCache, DELEGATION_MANAGER, ERROR_INSUFFICIENT_FEES_RESERVE,
-    ERROR_INSUFFICIENT_PENDING_EGLD, ERROR_NOT_WHITELISTED, MIN_EGLD_TO_DELEGATE,
-    MIN_GAS_FOR_ASYNC_CALL, MIN_GAS_FOR_ASYNC_CALL_CLAIM_REWARDS, MIN_GAS_FOR_CALLBACK,
+    errors::{
+        ERROR_INVALID_BATCH_SIZE, ERROR_INVALID_REBALANCE_AMOUNT, ERROR_NOTHING_TO_REBALANCE,
+        ERROR_NO_DELEGATION_CONTRACTS, ERROR_NO_WITHDRAWABLE_PROVIDERS,
+    },
+    proxy::proxy_delegation,
+    structs::{PausableAction, ProviderAllocation, Role},
+    StorageCache, BPS, ERROR_INSUFFICIENT_FEES_RESERVE, ERROR_INSUFFICIENT_PENDING_EGLD,
+    ERROR_NOT_WHITELISTED, MAX_REBALANCE_PROVIDERS, MAX_REBALANCE_SHARE, MAX_RECONCILE_BATCH,
+    MAX_WITHDRAW_PENDING_BATCH, MIN_EGLD_TO_DELEGATE, MIN_GAS_FOR_ASYNC_CALL,
+    MIN_GAS_FOR_ASYNC_CALL_CLAIM_REWARDS, MIN_GAS_FOR_CALLBACK, MIN_GAS_TO_FINISH_BATCH,
 };
 
 #[multiversx_sc::module]
@@ -21,6 +26,7 @@ pub trait ManageModule:
     + crate::utils::delegate::DelegateUtilsModule
     + crate::utils::un_delegation::UnDelegateUtilsModule
     + crate::liquidity_pool::LiquidityPoolModule
+    + crate::views::ViewsModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     /// Delegates pending EGLD from the liquid staking contract to a list of providers,
@@ -34,9 +40,13 @@ pub trait ManageModule:
         let mut storage_cache = StorageCache::new(self);
 
         self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::DelegatePending);
 
         self.require_rounds_passed();
 
+        // Providers whose delegation failure cooldown is over take part in the selection again
+        self.readmit_cooled_down_providers();
+
         require!(
             storage_cache.pending_egld >= MIN_EGLD_TO_DELEGATE,
             ERROR_INSUFFICIENT_PENDING_EGLD
@@ -113,14 +123,22 @@ pub trait ManageModule:
         let mut storage_cache = StorageCache::new(self);
 
         self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::UnDelegatePending);
 
         if providers.is_some() {
             let caller = self.blockchain().get_caller();
-            self.is_manager(&caller, true);
+            self.has_role(&caller, Role::Keeper, true);
         } else {
             self.require_rounds_passed();
         }
 
+        // Retiring providers are evacuated first, the selection below then finds no stake left
+        // on them. A batch can evacuate them without any pending un-delegation.
+        let evacuating = self.evacuate_retiring_providers();
+        if evacuating && storage_cache.pending_egld_for_unstake < MIN_EGLD_TO_DELEGATE {
+            return;
+        }
+
         require!(
             storage_cache.pending_egld_for_unstake >= MIN_EGLD_TO_DELEGATE,
             ERROR_INSUFFICIENT_PENDING_EGLD
@@ -181,6 +199,342 @@ pub trait ManageModule:
         self.emit_general_liquidity_event(&storage_cache);
     }
 
+    /// Un-delegates the whole stake left on the retiring providers. Their seed is
+    /// un-delegated last, once the liquid staked EGLD is evacuated and withdrawn.
+    /// Returns whether any un-delegation was issued.
+    fn evacuate_retiring_providers(&self) -> bool {
+        let mut evacuating = false;
+        for provider in self.retiring_providers().iter() {
+            let contract_data = self.delegation_contract_data(&provider).get();
+            if self.is_provider_evacuated(&contract_data) {
+                if self.provider_seed_unbond_epoch(&provider).is_empty() {
+                    self.evacuate_seed(&provider);
+                    evacuating = true;
+                }
+                continue;
+            }
+
+            if contract_data.total_staked_from_ls_contract
+                <= contract_data.pending_unstaking_callback_amount
+            {
+                continue;
+            }
+
+            let amount = &contract_data.total_staked_from_ls_contract
+                - &contract_data.pending_unstaking_callback_amount;
+            self.evacuate_stake(&provider, &amount);
+            evacuating = true;
+        }
+
+        evacuating
+    }
+
+    /// Moves stake away from the providers holding more than their target share, the share
+    /// of the liquid staked EGLD the scoring config gives them (none for ineligible ones).
+    /// The most over-allocated providers are un-delegated from, at most
+    /// `MAX_REBALANCE_PROVIDERS` of them and `max_amount` in total, capped to
+    /// `MAX_REBALANCE_SHARE` of the liquid staked EGLD. The EGLD is not paid to user
+    /// withdrawals: once withdrawn it is delegated again by `delegateRebalancePending`.
+    /// Returns the un-delegated amount.
+    #[endpoint(rebalance)]
+    fn rebalance(&self, max_amount: BigUint) -> BigUint {
+        let storage_cache = StorageCache::new(self);
+
+        self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::UnDelegatePending);
+        self.has_role(&self.blockchain().get_caller(), Role::Keeper, true);
+
+        let allocations = self.compute_provider_allocations();
+        let mut total_stake = BigUint::zero();
+        let mut overweights = ManagedVec::<Self::Api, BigUint>::new();
+        for allocation in allocations.iter() {
+            total_stake += &allocation.staked;
+            overweights.push(if allocation.staked > allocation.target {
+                &allocation.staked - &allocation.target
+            } else {
+                BigUint::zero()
+            });
+        }
+
+        let mut budget = max_amount.min(total_stake * &BigUint::from(MAX_REBALANCE_SHARE) / BPS);
+        require!(
+            budget >= MIN_EGLD_TO_DELEGATE,
+            ERROR_INVALID_REBALANCE_AMOUNT
+        );
+
+        let mut rebalanced = BigUint::zero();
+        for _ in 0..MAX_REBALANCE_PROVIDERS {
+            if budget < MIN_EGLD_TO_DELEGATE {
+                break;
+            }
+
+            let mut most_overweight = None;
+            let mut max_overweight = BigUint::zero();
+            for (index, overweight) in overweights.iter().enumerate() {
+                if *overweight > max_overweight {
+                    max_overweight = overweight.clone();
+                    most_overweight = Some(index);
+                }
+            }
+
+            let Some(index) = most_overweight else {
+                break;
+            };
+            let _ = overweights.set(index, BigUint::zero());
+
+            let provider = allocations.get(index).address.clone();
+            let contract_data = self.delegation_contract_data(&provider).get();
+            if contract_data.total_staked_from_ls_contract
+                <= contract_data.pending_unstaking_callback_amount
+            {
+                continue;
+            }
+
+            let available = &contract_data.total_staked_from_ls_contract
+                - &contract_data.pending_unstaking_callback_amount;
+            let amount = self.get_rebalance_amount(&max_overweight, &available, &budget);
+            if amount < MIN_EGLD_TO_DELEGATE {
+                continue;
+            }
+
+            budget -= &amount;
+            rebalanced += &amount;
+            self.evacuate_stake(&provider, &amount);
+            self.provider_rebalanced_event(&provider, &amount);
+        }
+
+        require!(rebalanced > BigUint::zero(), ERROR_NOTHING_TO_REBALANCE);
+
+        rebalanced
+    }
+
+    /// Delegates the evacuated stake withdrawn from the retiring and over-allocated providers
+    /// to the providers under their target share. The most under-allocated providers are
+    /// delegated to, at most `MAX_REBALANCE_PROVIDERS` of them, the EGLD left waits for the
+    /// next call.
+    /// Returns the delegated amount.
+    #[endpoint(delegateRebalancePending)]
+    fn delegate_rebalance_pending(&self) -> BigUint {
+        let storage_cache = StorageCache::new(self);
+
+        self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::DelegatePending);
+
+        let rebalance_pending_mapper = self.rebalance_pending_egld();
+        let mut budget = rebalance_pending_mapper.get();
+        require!(
+            budget >= MIN_EGLD_TO_DELEGATE,
+            ERROR_INSUFFICIENT_PENDING_EGLD
+        );
+
+        let allocations = self.compute_provider_allocations();
+        let mut underweights = ManagedVec::<Self::Api, BigUint>::new();
+        for allocation in allocations.iter() {
+            underweights.push(if allocation.target > allocation.staked {
+                &allocation.target - &allocation.staked
+            } else {
+                BigUint::zero()
+            });
+        }
+
+        let mut delegated = BigUint::zero();
+        for _ in 0..MAX_REBALANCE_PROVIDERS {
+            if budget < MIN_EGLD_TO_DELEGATE {
+                break;
+            }
+
+            let mut most_underweight = None;
+            let mut max_underweight = BigUint::zero();
+            for (index, underweight) in underweights.iter().enumerate() {
+                if *underweight > max_underweight {
+                    max_underweight = underweight.clone();
+                    most_underweight = Some(index);
+                }
+            }
+
+            let Some(index) = most_underweight else {
+                break;
+            };
+            let _ = underweights.set(index, BigUint::zero());
+
+            // The budget is left either empty or with at least the minimum delegation
+            let amount = self.get_rebalance_amount(&max_underweight, &budget, &budget);
+            if amount < MIN_EGLD_TO_DELEGATE {
+                continue;
+            }
+
+            let provider = allocations.get(index).address.clone();
+            budget -= &amount;
+            delegated += &amount;
+            self.delegation_contract_data(&provider)
+                .update(|contract_data| {
+                    contract_data.pending_staking_callback_amount += &amount;
+                });
+            self.tx()
+                .to(&provider)
+                .typed(proxy_delegation::DelegationMockProxy)
+                .delegate()
+                .egld(&amount)
+                .gas(MIN_GAS_FOR_ASYNC_CALL)
+                .callback(
+                    CallbackModule::callbacks(self)
+                        .rebalance_delegation_callback(&provider, &amount),
+                )
+                .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+                .register_promise();
+        }
+
+        require!(delegated > BigUint::zero(), ERROR_NOTHING_TO_REBALANCE);
+        rebalance_pending_mapper.set(budget);
+
+        delegated
+    }
+
+    /// Returns the stake and target share of every provider, retiring ones excluded.
+    /// Targets are the delegation scores of the eligible providers applied to the whole
+    /// liquid staked EGLD, evacuated stake included. A provider above its target is
+    /// un-delegated from by `rebalance`, one below it gets the evacuated stake.
+    #[view(getProviderAllocations)]
+    fn get_provider_allocations(&self) -> MultiValueEncoded<ProviderAllocation<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for allocation in self.compute_provider_allocations().iter() {
+            result.push(allocation.clone());
+        }
+
+        result
+    }
+
+    fn compute_provider_allocations(&self) -> ManagedVec<ProviderAllocation<Self::Api>> {
+        let config = self.get_scoring_config();
+        let realized_apy_scoring = self.realized_apy_scoring().get();
+        let retiring_providers = self.retiring_providers();
+
+        let mut allocations = ManagedVec::new();
+        let mut eligible_providers = ManagedVec::new();
+        let mut total_stake = BigUint::zero();
+        let mut eligible_stake = BigUint::zero();
+        for address in self.delegation_addresses_list().iter() {
+            if retiring_providers.contains(&address) {
+                continue;
+            }
+
+            let contract_data = self.delegation_contract_data(&address).get();
+            let staked = contract_data.get_total_amount_with_pending_callbacks();
+            total_stake += &staked;
+            if contract_data.eligible {
+                eligible_stake += &staked;
+                eligible_providers.push(self.create_selection_info(
+                    &address,
+                    &contract_data,
+                    realized_apy_scoring,
+                ));
+            }
+
+            allocations.push(ProviderAllocation {
+                address,
+                staked,
+                target: BigUint::zero(),
+            });
+        }
+
+        // Evacuated stake is staked again on the providers under their target
+        total_stake += self.evacuating_egld().get() + self.rebalance_pending_egld().get();
+
+        let total_score = self.update_selected_addresses_scores(
+            &mut eligible_providers,
+            true,
+            &eligible_stake,
+            &config,
+        );
+        if total_score == BigUint::zero() {
+            return allocations;
+        }
+
+        // Eligible providers come in the same order as the allocations
+        let mut eligible_index = 0;
+        for index in 0..allocations.len() {
+            if eligible_index == eligible_providers.len() {
+                break;
+            }
+
+            let mut allocation = allocations.get(index).clone();
+            let info = eligible_providers.get(eligible_index);
+            if info.address != allocation.address {
+                continue;
+            }
+
+            allocation.target = &total_stake * &info.score / &total_score;
+            let _ = allocations.set(index, allocation);
+            eligible_index += 1;
+        }
+
+        allocations
+    }
+
+    /// Bounds a rebalance of a provider to its available stake and the budget left, leaving
+    /// it either no stake or at least the minimum delegation.
+    fn get_rebalance_amount(
+        &self,
+        overweight: &BigUint,
+        available: &BigUint,
+        budget: &BigUint,
+    ) -> BigUint {
+        let min_egld = BigUint::from(MIN_EGLD_TO_DELEGATE);
+        let amount = overweight
+            .clone()
+            .min(available.clone())
+            .min(budget.clone());
+        let left_over = available - &amount;
+        if left_over == BigUint::zero() || left_over >= min_egld {
+            return amount;
+        }
+
+        if available <= budget {
+            available.clone()
+        } else if available > &min_egld {
+            available - &min_egld
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    /// Un-delegates stake from a provider to be delegated again to the other providers once
+    /// withdrawn, see `evacuate_provider_callback`.
+    fn evacuate_stake(&self, provider: &ManagedAddress, amount: &BigUint) {
+        self.delegation_contract_data(provider)
+            .update(|contract_data| {
+                contract_data.pending_unstaking_callback_amount += amount;
+            });
+        self.evacuating_egld()
+            .update(|evacuating_egld| *evacuating_egld += amount);
+
+        self.tx()
+            .to(provider)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .undelegate(amount)
+            .gas(MIN_GAS_FOR_ASYNC_CALL)
+            .callback(CallbackModule::callbacks(self).evacuate_provider_callback(provider, amount))
+            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+            .register_promise();
+    }
+
+    /// Un-delegates the seed of a retired provider, sent back to its owner once withdrawn.
+    /// The unbond epoch is set right away so the seed is un-delegated once.
+    fn evacuate_seed(&self, provider: &ManagedAddress) {
+        let seed = self.get_provider_seed_stake(provider);
+        let unbond_epoch = self.blockchain().get_block_epoch() + self.unbond_period().get();
+        self.provider_seed_unbond_epoch(provider).set(unbond_epoch);
+
+        self.tx()
+            .to(provider)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .undelegate(&seed)
+            .gas(MIN_GAS_FOR_ASYNC_CALL)
+            .callback(CallbackModule::callbacks(self).evacuate_seed_callback(provider))
+            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+            .register_promise();
+    }
+
     /// Withdraws pending funds from a specified delegation contract, an essential function
     /// to maintain liquidity for instant withdrawals when users leave the staking pool.
     ///
@@ -192,70 +546,180 @@ pub trait ManageModule:
         let storage_cache = StorageCache::new(self);
 
         self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::WithdrawPending);
 
         require!(
             !self.delegation_contract_data(&contract).is_empty(),
             ERROR_NOT_WHITELISTED
         );
 
-        self.tx()
-            .to(&contract)
-            .typed(proxy_delegation::DelegationMockProxy)
-            .withdraw()
-            .gas(MIN_GAS_FOR_ASYNC_CALL)
-            .callback(CallbackModule::callbacks(self).withdraw_tokens_callback(&contract))
-            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
-            .register_promise();
+        self.withdraw_from_provider(&contract);
+    }
+
+    /// Withdraws the unbonded EGLD of every provider with a matured un-delegation in one call.
+    /// Only providers whose un-delegations tracked by `unDelegatePending` reached their unbond
+    /// epoch are called, at most `max_providers` of them and as many as the gas left allows.
+    ///
+    /// Arguments:
+    /// - `max_providers`: Optional. Caps the batch, defaults to `MAX_WITHDRAW_PENDING_BATCH`.
+    ///
+    /// Returns the number of providers a withdraw was issued for.
+    #[endpoint(withdrawPendingBatch)]
+    fn withdraw_pending_batch(&self, max_providers: OptionalValue<usize>) -> usize {
+        let storage_cache = StorageCache::new(self);
+
+        self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::WithdrawPending);
+
+        let max_providers = max_providers
+            .into_option()
+            .unwrap_or(MAX_WITHDRAW_PENDING_BATCH);
+        require!(
+            max_providers > 0 && max_providers <= MAX_WITHDRAW_PENDING_BATCH,
+            ERROR_INVALID_BATCH_SIZE
+        );
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let gas_per_withdraw = MIN_GAS_FOR_ASYNC_CALL + MIN_GAS_FOR_CALLBACK;
+        let mut reserved_gas = MIN_GAS_TO_FINISH_BATCH;
+        let mut issued = 0;
+
+        for provider in self.un_delegation_addresses_list().iter() {
+            if issued == max_providers
+                || self.blockchain().get_gas_left() < reserved_gas + gas_per_withdraw
+            {
+                break;
+            }
+
+            if !self.is_provider_withdrawable(&provider, current_epoch) {
+                continue;
+            }
+
+            self.withdraw_from_provider(&provider);
+            reserved_gas += gas_per_withdraw;
+            issued += 1;
+        }
+
+        require!(issued > 0, ERROR_NO_WITHDRAWABLE_PROVIDERS);
+
+        issued
+    }
+
+    /// Queries the active stake of the contract on each given provider and reconciles it
+    /// with the booked stake in the callback, see `reconcile_provider_callback`.
+    #[endpoint(reconcileProviders)]
+    fn reconcile_providers(&self, providers: MultiValueEncoded<ManagedAddress>) {
+        self.has_role(&self.blockchain().get_caller(), Role::Keeper, true);
+
+        require!(
+            !providers.is_empty() && providers.len() <= MAX_RECONCILE_BATCH,
+            ERROR_INVALID_BATCH_SIZE
+        );
+
+        let sc_address = self.blockchain().get_sc_address();
+        for provider in providers {
+            let contract_data_mapper = self.delegation_contract_data(&provider);
+            require!(!contract_data_mapper.is_empty(), ERROR_NOT_WHITELISTED);
+
+            let booked_stake = contract_data_mapper.get().total_staked_from_ls_contract;
+            self.tx()
+                .to(&provider)
+                .typed(proxy_delegation::DelegationMockProxy)
+                .get_user_active_stake(&sc_address)
+                .gas(MIN_GAS_FOR_ASYNC_CALL)
+                .callback(
+                    CallbackModule::callbacks(self)
+                        .reconcile_provider_callback(&provider, &booked_stake),
+                )
+                .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+                .register_promise();
+        }
     }
 
     /// Claims accumulated staking rewards from the providers, optimizing the process
     /// by delegating these rewards directly back into the contract to generate compounding
     /// returns for xEGLD holders. This endpoint prevents repeated withdrawals and staking,
-    /// improving gas efficiency and yield.
+    /// improving gas efficiency and yield. Each provider is claimed separately so the rewards
+    /// it paid are measured against its stake, see `getProviderRealizedApy`.
     #[endpoint(claimRewards)]
     fn claim_rewards(&self) {
         let storage_cache = StorageCache::new(self);
 
         self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::ClaimRewards);
 
         let list_mapper = self.delegation_addresses_list();
 
         require!(!list_mapper.is_empty(), ERROR_NO_DELEGATION_CONTRACTS);
 
-        let mut addresses = MultiValueEncoded::new();
-
         for provider in list_mapper.iter() {
-            addresses.push(provider);
+            self.tx()
+                .to(&provider)
+                .typed(proxy_delegation::DelegationMockProxy)
+                .claim_rewards()
+                .gas(MIN_GAS_FOR_ASYNC_CALL_CLAIM_REWARDS)
+                .callback(CallbackModule::callbacks(self).claim_rewards_callback(&provider))
+                .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+                .register_promise();
         }
-
-        let gas = MIN_GAS_FOR_ASYNC_CALL_CLAIM_REWARDS * addresses.len() as u64;
-
-        self.tx()
-            .to(&ManagedAddress::new_from_bytes(&DELEGATION_MANAGER))
-            .typed(proxy_delegation_manager::DelegationManagerMockProxy)
-            .claim_multiple(addresses)
-            .gas(gas)
-            .callback(CallbackModule::callbacks(self).claim_rewards_callback())
-            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
-            .register_promise();
     }
 
+    /// Sends the fees reserves to the fee recipients. Callable by anyone, the fees can only
+    /// go to the recipients set through the timelock.
     #[endpoint(claimFees)]
     fn claim_fees(&self) {
         let mut storage_cache = StorageCache::new(self);
 
+        let ls_fees = self.ls_fees_reserve().take();
         require!(
-            storage_cache.fees_reserve > BigUint::zero(),
+            storage_cache.fees_reserve > BigUint::zero() || ls_fees > BigUint::zero(),
             ERROR_INSUFFICIENT_FEES_RESERVE
         );
 
-        self.tx()
-            .to(&self.accumulator_contract().get())
-            .typed(proxy_accumulator::AccumulatorProxy)
-            .deposit()
-            .egld(&storage_cache.fees_reserve)
-            .sync_call();
-
+        let egld_fees = storage_cache.fees_reserve.clone();
         storage_cache.fees_reserve = BigUint::zero();
+
+        self.send_fees(&EgldOrEsdtTokenIdentifier::egld(), &egld_fees);
+        self.send_fees(
+            &EgldOrEsdtTokenIdentifier::esdt(storage_cache.ls_token_id.clone()),
+            &ls_fees,
+        );
+    }
+
+    fn send_fees(&self, token: &EgldOrEsdtTokenIdentifier, total_fees: &BigUint) {
+        let (recipients, amounts) = self.split_fees(total_fees);
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            if *amount == BigUint::zero() {
+                continue;
+            }
+
+            match &recipient.endpoint {
+                Some(endpoint) => {
+                    self.tx()
+                        .to(&recipient.address)
+                        .raw_call(endpoint.clone())
+                        .egld_or_single_esdt(token, 0, &*amount)
+                        .sync_call();
+                }
+                None => self
+                    .tx()
+                    .to(&recipient.address)
+                    .egld_or_single_esdt(token, 0, &*amount)
+                    .transfer(),
+            }
+
+            self.fees_claimed_event(&recipient.address, token, &amount);
+        }
+    }
+
+    fn withdraw_from_provider(&self, contract: &ManagedAddress) {
+        self.tx()
+            .to(contract)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .withdraw()
+            .gas(MIN_GAS_FOR_ASYNC_CALL)
+            .callback(CallbackModule::callbacks(self).withdraw_tokens_callback(contract))
+            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+            .register_promise();
     }
 }
@@ -10,6 +10,7 @@ pub trait MigrateModule:
     + crate::score::ScoreModule
     + crate::selection::SelectionModule
     + crate::liquidity_pool::LiquidityPoolModule
+    + crate::apr::AprModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     #[endpoint(migrate)]
@@ -35,7 +36,12 @@ pub trait MigrateModule:
         let user_payment = self.mint_ls_token(ls_amount);
 
         // Emit the add liquidity event
-        self.emit_add_liquidity_event(&storage_cache, virtual_egld_amount, Some(original_caller));
+        self.emit_add_liquidity_event(
+            &storage_cache,
+            virtual_egld_amount,
+            Some(original_caller),
+            None,
+        );
         // Send the final amount to the user
         self.tx().to(&caller).esdt(user_payment).transfer();
     }
@@ -77,20 +83,10 @@ pub trait MigrateModule:
         storage_cache.virtual_egld_reserve += amount.clone_value();
         storage_cache.pending_egld += amount.clone_value();
 
+        self.record_epoch_snapshot(&storage_cache, &amount);
         self.emit_add_rewards_event(&storage_cache, &amount);
     }
 
-    #[only_owner]
-    #[endpoint(setMigrationScAddress)]
-    fn add_migration_sc_address(&self, address: &ManagedAddress) {
-        // Double check that the caller is a smart contract
-        require!(
-            self.blockchain().is_smart_contract(address),
-            ERROR_MIGRATION_NOT_ALLOWED
-        );
-        self.migration_sc_address().set(address);
-    }
-
     #[view(getMigrationScAddress)]
     #[storage_mapper("migrationScAddress")]
     fn migration_sc_address(&self) -> SingleValueMapper<ManagedAddress>;
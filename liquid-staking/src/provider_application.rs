@@ -0,0 +1,279 @@
+multiversx_sc::imports!();
+use crate::{
+    constants::{
+        BPS, DELEGATION_MANAGER, MAX_PROVIDER_APPLICATIONS, MIN_EGLD_TO_DELEGATE,
+        PROVIDER_APPLICATION_EXPIRY_EPOCHS, SYSTEM_SC_ADDRESS_PREFIX,
+    },
+    errors::{
+        ERROR_ALREADY_WHITELISTED, ERROR_AUTO_APPROVAL_NOT_REACHED,
+        ERROR_INVALID_PROVIDER_APPLICATION_CONFIG, ERROR_MIN_EGLD_TO_DELEGATE,
+        ERROR_NOT_DELEGATION_CONTRACT, ERROR_NOT_DELEGATION_CONTRACT_OWNER,
+        ERROR_ONLY_DELEGATION_ADMIN, ERROR_PROVIDER_APPLICATIONS_DISABLED,
+        ERROR_PROVIDER_APPLICATION_EXISTS, ERROR_PROVIDER_APPLICATION_EXPIRED,
+        ERROR_PROVIDER_APPLICATION_NOT_FOUND, ERROR_PROVIDER_CHECKS_FAILED,
+        ERROR_PROVIDER_CHECKS_PENDING, ERROR_TOO_MANY_PROVIDER_APPLICATIONS,
+    },
+    structs::{
+        DelegationContractData, ProviderApplication, ProviderApplicationConfig,
+        ProviderScoringData, Role,
+    },
+};
+
+#[multiversx_sc::module]
+pub trait ProviderApplicationModule:
+    crate::config::ConfigModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::callback::CallbackModule
+    + crate::delegation::DelegationModule
+    + crate::utils::generic::UtilsModule
+    + crate::score::ScoreModule
+    + crate::selection::SelectionModule
+    + crate::liquidity_pool::LiquidityPoolModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    /// Sets the checks a provider application must pass. Applications are disabled until
+    /// a configuration is set.
+    #[only_owner]
+    #[endpoint(setProviderApplicationConfig)]
+    fn set_provider_application_config(&self, config: ProviderApplicationConfig<Self::Api>) {
+        require!(
+            config.min_service_fee <= config.max_service_fee && config.max_service_fee <= BPS,
+            ERROR_INVALID_PROVIDER_APPLICATION_CONFIG
+        );
+        // Applications must be approvable before they expire
+        require!(
+            config.auto_approval_epochs < PROVIDER_APPLICATION_EXPIRY_EPOCHS,
+            ERROR_INVALID_PROVIDER_APPLICATION_CONFIG
+        );
+
+        self.provider_application_config().set(config);
+    }
+
+    /// Submits a system delegation contract to join the providers, the caller becomes its
+    /// admin and must own it. The paid seed is delegated once the application is approved.
+    /// The owner, service fee, cap, stake and nodes of the delegation contract are read
+    /// right away for the checks, an application of another address than the owner is
+    /// dropped and its seed sent back. An expired application of the same contract is
+    /// replaced.
+    #[payable("EGLD")]
+    #[endpoint(applyAsProvider)]
+    fn apply_as_provider(&self, contract_address: ManagedAddress, apy: u64) {
+        require!(
+            !self.provider_application_config().is_empty(),
+            ERROR_PROVIDER_APPLICATIONS_DISABLED
+        );
+        require!(
+            self.is_system_delegation_contract(&contract_address),
+            ERROR_NOT_DELEGATION_CONTRACT
+        );
+        require!(
+            self.delegation_contract_data(&contract_address).is_empty(),
+            ERROR_ALREADY_WHITELISTED
+        );
+
+        let application_mapper = self.provider_application(&contract_address);
+        if !application_mapper.is_empty()
+            && self.is_provider_application_expired(&application_mapper.get())
+        {
+            self.drop_provider_application(&contract_address);
+        }
+        require!(
+            application_mapper.is_empty(),
+            ERROR_PROVIDER_APPLICATION_EXISTS
+        );
+        require!(
+            self.provider_applications().len() < MAX_PROVIDER_APPLICATIONS,
+            ERROR_TOO_MANY_PROVIDER_APPLICATIONS
+        );
+
+        let seed = self.call_value().egld().clone_value();
+        require!(seed >= MIN_EGLD_TO_DELEGATE, ERROR_MIN_EGLD_TO_DELEGATE);
+
+        let application = ProviderApplication {
+            admin_address: self.blockchain().get_caller(),
+            seed: seed.clone(),
+            apy,
+            submitted_epoch: self.blockchain().get_block_epoch(),
+            service_fee: None,
+            total_staked: None,
+            delegation_contract_cap: None,
+            nr_nodes: None,
+            owner: None,
+        };
+
+        application_mapper.set(&application);
+        self.provider_applications()
+            .insert(contract_address.clone());
+        self.provider_applications_reserve()
+            .update(|reserve| *reserve += &seed);
+
+        self.provider_application_submitted_event(&contract_address, &application);
+        self.request_provider_data(&contract_address);
+    }
+
+    /// Promotes an application passing the checks into the providers. A provider curator
+    /// can approve it right away, anyone else once `auto_approval_epochs` have passed, in
+    /// which case the declared APY is capped at `max_auto_approval_apy`.
+    #[endpoint(approveProviderApplication)]
+    fn approve_provider_application(&self, contract_address: ManagedAddress) {
+        require!(
+            self.delegation_contract_data(&contract_address).is_empty(),
+            ERROR_ALREADY_WHITELISTED
+        );
+
+        let application = self.take_provider_application(&contract_address);
+        require!(
+            !self.is_provider_application_expired(&application),
+            ERROR_PROVIDER_APPLICATION_EXPIRED
+        );
+
+        let caller = self.blockchain().get_caller();
+        let mut contract_data = self.check_provider_application(&application);
+        if !self.has_role(&caller, Role::ProviderCurator, false) {
+            let config = self.provider_application_config().get();
+            require!(
+                config.auto_approval_epochs > 0
+                    && self.blockchain().get_block_epoch()
+                        >= application.submitted_epoch + config.auto_approval_epochs,
+                ERROR_AUTO_APPROVAL_NOT_REACHED
+            );
+
+            // The APY is declared by the applicant, only a curator can vouch for more
+            contract_data.apy = contract_data.apy.min(config.max_auto_approval_apy);
+        }
+
+        self.provider_scoring_data(&contract_address)
+            .set(ProviderScoringData {
+                service_fee: application.service_fee,
+                ..Default::default()
+            });
+        self.add_delegation_contract(
+            &contract_address,
+            contract_data,
+            &application.seed,
+            &application.admin_address,
+        );
+
+        self.provider_application_approved_event(&contract_address, &caller);
+    }
+
+    /// Drops an application and sends its seed back to the admin. Callable by the admin
+    /// of the application or a provider curator, and by anyone once the application is
+    /// `PROVIDER_APPLICATION_EXPIRY_EPOCHS` old.
+    #[endpoint(cancelProviderApplication)]
+    fn cancel_provider_application(&self, contract_address: ManagedAddress) {
+        let application_mapper = self.provider_application(&contract_address);
+        require!(
+            !application_mapper.is_empty(),
+            ERROR_PROVIDER_APPLICATION_NOT_FOUND
+        );
+
+        let application = application_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(
+            application.admin_address == caller
+                || self.has_role(&caller, Role::ProviderCurator, false)
+                || self.is_provider_application_expired(&application),
+            ERROR_ONLY_DELEGATION_ADMIN
+        );
+
+        self.drop_provider_application(&contract_address);
+    }
+
+    #[view(getProviderApplication)]
+    fn get_provider_application(
+        &self,
+        contract_address: ManagedAddress,
+    ) -> ProviderApplication<Self::Api> {
+        let application_mapper = self.provider_application(&contract_address);
+        require!(
+            !application_mapper.is_empty(),
+            ERROR_PROVIDER_APPLICATION_NOT_FOUND
+        );
+
+        application_mapper.get()
+    }
+
+    /// Returns the pending applications, in submission order.
+    #[view(getPendingProviderApplications)]
+    fn get_pending_provider_applications(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, ProviderApplication<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for contract_address in self.provider_applications().iter() {
+            let application = self.provider_application(&contract_address).get();
+            result.push((contract_address, application).into());
+        }
+
+        result
+    }
+
+    fn is_provider_application_expired(
+        &self,
+        application: &ProviderApplication<Self::Api>,
+    ) -> bool {
+        self.blockchain().get_block_epoch()
+            >= application.submitted_epoch + PROVIDER_APPLICATION_EXPIRY_EPOCHS
+    }
+
+    /// Delegation contracts are deployed by the delegation manager in the system SC range.
+    fn is_system_delegation_contract(&self, contract_address: &ManagedAddress) -> bool {
+        let address = contract_address.to_byte_array();
+        address.starts_with(&SYSTEM_SC_ADDRESS_PREFIX) && address != DELEGATION_MANAGER
+    }
+
+    /// Returns the provider data of an application passing the checks.
+    fn check_provider_application(
+        &self,
+        application: &ProviderApplication<Self::Api>,
+    ) -> DelegationContractData<Self::Api> {
+        let (service_fee, total_staked, delegation_contract_cap, nr_nodes, owner) = match (
+            application.service_fee,
+            &application.total_staked,
+            &application.delegation_contract_cap,
+            application.nr_nodes,
+            &application.owner,
+        ) {
+            (Some(service_fee), Some(total_staked), Some(cap), Some(nr_nodes), Some(owner)) => (
+                service_fee,
+                total_staked.clone(),
+                cap.clone(),
+                nr_nodes,
+                owner,
+            ),
+            _ => sc_panic!(ERROR_PROVIDER_CHECKS_PENDING),
+        };
+
+        require!(
+            owner == &application.admin_address,
+            ERROR_NOT_DELEGATION_CONTRACT_OWNER
+        );
+
+        let config = self.provider_application_config().get();
+        require!(
+            service_fee >= config.min_service_fee && service_fee <= config.max_service_fee,
+            ERROR_PROVIDER_CHECKS_FAILED
+        );
+        require!(nr_nodes >= config.min_nodes, ERROR_PROVIDER_CHECKS_FAILED);
+        // Uncapped delegation contracts have a zero cap
+        require!(
+            delegation_contract_cap == BigUint::zero()
+                || delegation_contract_cap >= &total_staked + &config.min_cap_headroom,
+            ERROR_PROVIDER_CHECKS_FAILED
+        );
+
+        DelegationContractData {
+            admin_address: application.admin_address.clone(),
+            total_staked,
+            delegation_contract_cap,
+            nr_nodes,
+            apy: application.apy,
+            total_staked_from_ls_contract: BigUint::zero(),
+            total_unstaked_from_ls_contract: BigUint::zero(),
+            pending_staking_callback_amount: BigUint::zero(),
+            pending_unstaking_callback_amount: BigUint::zero(),
+            eligible: true,
+        }
+    }
+}
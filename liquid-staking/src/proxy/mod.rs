@@ -1,4 +1,4 @@
 pub mod proxy_accumulator;
 pub mod proxy_delegation;
 pub mod proxy_delegation_manager;
-pub mod proxy_liquid_staking;
\ No newline at end of file
+pub mod proxy_liquid_staking;
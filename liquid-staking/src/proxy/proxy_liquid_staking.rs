@@ -124,14 +124,99 @@ where
             .original_result()
     }
 
+    /// Delegates EGLD to the staking pool like `delegate` without a provider, but reverts 
+    /// when the minted xEGLD is lower than `min_ls_out`. Protects integrators against 
+    /// exchange rate moves (rewards claims, `addRewards`) between quote and execution. 
+    ///  
+    /// Arguments: 
+    /// - `min_ls_out`: Reverts when the minted xEGLD is lower, zero disables the check. 
+    /// - `referrer_code`: Optional. Attributes the minted xEGLD to the referrer of the code. 
+    pub fn delegate_with_min_out<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        min_ls_out: Arg0,
+        referrer_code: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("delegateWithMinOut")
+            .argument(&min_ls_out)
+            .argument(&referrer_code)
+            .original_result()
+    }
+
+    /// Delegates EGLD to the staking pool and sends the minted xEGLD to `receiver` 
+    /// instead of the caller. 
+    ///  
+    /// Arguments: 
+    /// - `receiver`: Address receiving the minted xEGLD. 
+    /// - `min_ls_out`: Optional. Reverts when the minted xEGLD is lower. 
+    /// - `referrer_code`: Optional. Attributes the xEGLD minted to `receiver` to the 
+    ///                    referrer of the code, only accepted when the caller is `receiver`. 
+    pub fn delegate_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        receiver: Arg0,
+        min_ls_out: Arg1,
+        referrer_code: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("delegateFor")
+            .argument(&receiver)
+            .argument(&min_ls_out)
+            .argument(&referrer_code)
+            .original_result()
+    }
+
     /// Initiates the un-delegation process, enabling users to withdraw their stake. 
     /// Depending on the available pending EGLD in the contract, users can receive 
     /// an instant return without fees or enter a 10-day unbonding period. 
-    pub fn un_delegate(
+    ///  
+    /// Arguments: 
+    /// - `min_egld_out`: Optional. Reverts when the EGLD value of the burned xEGLD is lower. 
+    /// - `instant_only`: Optional. When `true`, reverts instead of minting an unstake MetaESDT 
+    ///                   if the pending EGLD cannot cover the full amount instantly. 
+    pub fn un_delegate<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg1: ProxyArg<OptionalValue<bool>>,
+    >(
         self,
+        min_egld_out: Arg0,
+        instant_only: Arg1,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("unDelegate")
+            .argument(&min_egld_out)
+            .argument(&instant_only)
+            .original_result()
+    }
+
+    /// Same as `unDelegate`, but the instant EGLD and the unstake MetaESDT are sent to 
+    /// `receiver` instead of the caller. Lets smart contract vaults relay positions for end users. 
+    ///  
+    /// Arguments: 
+    /// - `receiver`: Address receiving the EGLD and the unstake MetaESDT. 
+    /// - `min_egld_out`: Optional. Reverts when the EGLD value of the burned xEGLD is lower. 
+    /// - `instant_only`: Optional. Reverts if the full amount cannot be redeemed instantly. 
+    pub fn un_delegate_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<bool>>,
+    >(
+        self,
+        receiver: Arg0,
+        min_egld_out: Arg1,
+        instant_only: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("unDelegateFor")
+            .argument(&receiver)
+            .argument(&min_egld_out)
+            .argument(&instant_only)
             .original_result()
     }
 
@@ -146,6 +231,64 @@ where
             .original_result()
     }
 
+    /// Same as `withdraw`, but the EGLD and any remaining unstake MetaESDT are sent to `receiver`. 
+    ///  
+    /// Arguments: 
+    /// - `receiver`: Address receiving the withdrawn EGLD. 
+    pub fn withdraw_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        receiver: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawFor")
+            .argument(&receiver)
+            .original_result()
+    }
+
+    /// Withdraws an explicit amount from a single unbonded position, the rest of the 
+    /// unstake MetaESDT is sent back to the caller. 
+    ///  
+    /// Arguments: 
+    /// - `amount`: EGLD amount to withdraw, at most the amount of the sent position. 
+    pub fn withdraw_amount<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawAmount")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Splits an unstake MetaESDT in two positions with the same unbond epoch: `amount` moves 
+    /// to a new nonce and the rest stays on the sent nonce, so each part can be transferred 
+    /// or withdrawn separately. 
+    pub fn split_unstake_tokens<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue2<EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("splitUnstakeTokens")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Merges unstake positions into the sent position with the latest unbond epoch. 
+    /// The merged EGLD can only be withdrawn once that latest unbond epoch is reached. 
+    pub fn merge_unstake_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("mergeUnstakeTokens")
+            .original_result()
+    }
+
     pub fn get_ls_value_for_position<
         Arg0: ProxyArg<BigUint<Env::Api>>,
     >(
@@ -181,6 +324,47 @@ where
             .original_result()
     }
 
+    /// Time-weighted average exchange rate over the last `window` seconds, scaled by 10^18. 
+    /// Inside the history covered by the epoch snapshots, the rate accumulator is linearly 
+    /// interpolated between the two snapshots surrounding the start of the window. 
+    pub fn get_twap_exchange_rate<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        window: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTwapExchangeRate")
+            .argument(&window)
+            .original_result()
+    }
+
+    /// Returns the last `count` epoch snapshots, ordered from the oldest to the newest. 
+    pub fn get_rate_snapshots<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        count: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, RateObservation<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRateSnapshots")
+            .argument(&count)
+            .original_result()
+    }
+
+    /// Growth of the exchange rate between the two most recent epoch snapshots. 
+    /// Returns the epochs of both snapshots and the ratio newer / older, scaled by 10^18. 
+    pub fn get_exchange_rate_growth(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u64, u64, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExchangeRateGrowth")
+            .original_result()
+    }
+
     pub fn get_delegation_contract_staked_amount<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
@@ -207,6 +391,94 @@ where
             .original_result()
     }
 
+    /// Returns the un-delegations sent to the provider and not yet withdrawn, oldest first. 
+    pub fn get_provider_pending_unbonds<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegation_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PendingUnbond<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProviderPendingUnbonds")
+            .argument(&delegation_address)
+            .original_result()
+    }
+
+    /// Returns the unbonding EGLD of the provider split in the amount that can be 
+    /// withdrawn in the current epoch and the amount still locked. 
+    pub fn get_provider_unbonding_amounts<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegation_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProviderUnbondingAmounts")
+            .argument(&delegation_address)
+            .original_result()
+    }
+
+    /// Returns the failed delegations of the provider and, while it is cooling down after 
+    /// one, the epoch it becomes eligible again. 
+    pub fn get_provider_delegation_failures<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegation_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DelegationFailures> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProviderDelegationFailures")
+            .argument(&delegation_address)
+            .original_result()
+    }
+
+    /// Returns the claimable and locked unbonding EGLD of every provider with un-delegations 
+    /// not yet withdrawn. 
+    pub fn get_unbonding_ledger(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedAddress<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnbondingLedger")
+            .original_result()
+    }
+
+    /// Returns the providers that `withdrawPendingBatch` would currently withdraw from. 
+    pub fn get_withdrawable_providers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWithdrawableProviders")
+            .original_result()
+    }
+
+    /// Returns the EGLD and LS token fees each fee recipient gets on the next `claimFees`. 
+    pub fn get_accrued_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedAddress<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccruedFees")
+            .original_result()
+    }
+
+    /// Recomputes the accounting relationships of the protocol from storage, iterating every 
+    /// whitelisted provider, and returns each invariant with its expected and actual amounts. 
+    /// Liquidity added through `migrate` has no staked counterpart and shows up as a 
+    /// negative `StakedEgld` delta. 
+    pub fn check_invariants(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, InvariantCheck<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("checkInvariants")
+            .original_result()
+    }
+
     pub fn register_ls_token<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -261,94 +533,103 @@ where
             .original_result()
     }
 
-    pub fn set_accumulator_contract<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    /// Pauses the given actions while the rest of the protocol keeps running, e.g. deposits 
+    /// can be stopped while users are still able to exit. Callable by the guardians. 
+    pub fn pause_actions<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, PausableAction>>,
     >(
         self,
-        accumulator_contract: Arg0,
+        actions: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setAccumulatorContract")
-            .argument(&accumulator_contract)
+            .raw_call("pauseActions")
+            .argument(&actions)
             .original_result()
     }
 
-    pub fn set_fees<
-        Arg0: ProxyArg<BigUint<Env::Api>>,
+    /// Resumes the given actions. Only the owner can unpause, a guardian key can only stop the protocol. 
+    pub fn unpause_actions<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, PausableAction>>,
     >(
         self,
-        fees: Arg0,
+        actions: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setFees")
-            .argument(&fees)
+            .raw_call("unpauseActions")
+            .argument(&actions)
             .original_result()
     }
 
-    pub fn set_max_addresses<
-        Arg0: ProxyArg<usize>,
+    pub fn is_action_paused<
+        Arg0: ProxyArg<PausableAction>,
     >(
         self,
-        number: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        action: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setMaxAddresses")
-            .argument(&number)
+            .raw_call("isActionPaused")
+            .argument(&action)
             .original_result()
     }
 
-    pub fn set_max_selected_providers<
-        Arg0: ProxyArg<BigUint<Env::Api>>,
+    pub fn set_max_addresses<
+        Arg0: ProxyArg<usize>,
     >(
         self,
         number: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setMaxSelectedProviders")
+            .raw_call("setMaxAddresses")
             .argument(&number)
             .original_result()
     }
 
-    pub fn set_unbond_period<
-        Arg0: ProxyArg<u64>,
+    pub fn grant_role<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<Role>,
     >(
         self,
-        period: Arg0,
+        address: Arg0,
+        role: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setUnbondPeriod")
-            .argument(&period)
+            .raw_call("grantRole")
+            .argument(&address)
+            .argument(&role)
             .original_result()
     }
 
-    pub fn set_managers<
-        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    pub fn revoke_role<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<Role>,
     >(
         self,
-        managers: Arg0,
+        address: Arg0,
+        role: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("addManagers")
-            .argument(&managers)
+            .raw_call("revokeRole")
+            .argument(&address)
+            .argument(&role)
             .original_result()
     }
 
-    pub fn remove_manager<
+    pub fn get_address_roles<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        manager: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Role>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("removeManager")
-            .argument(&manager)
+            .raw_call("getAddressRoles")
+            .argument(&address)
             .original_result()
     }
 
@@ -391,6 +672,22 @@ where
             .original_result()
     }
 
+    /// When enabled, providers are scored with the yield measured from the rewards claimed 
+    /// from them instead of their declared APY, see `getProviderRealizedApy`. Providers 
+    /// without any measured yield keep their declared APY. 
+    pub fn set_realized_apy_scoring<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRealizedApyScoring")
+            .argument(&enabled)
+            .original_result()
+    }
+
     /// Delegates pending EGLD from the liquid staking contract to a list of providers, 
     /// ensuring fair distribution by allocating set amounts to multiple providers in batches. 
     ///  
@@ -433,6 +730,53 @@ where
             .original_result()
     }
 
+    /// Moves stake away from the providers holding more than their target share, the share 
+    /// of the liquid staked EGLD the scoring config gives them (none for ineligible ones). 
+    /// The most over-allocated providers are un-delegated from, at most 
+    /// `MAX_REBALANCE_PROVIDERS` of them and `max_amount` in total, capped to 
+    /// `MAX_REBALANCE_SHARE` of the liquid staked EGLD. The EGLD is not paid to user 
+    /// withdrawals: once withdrawn it is delegated again by `delegateRebalancePending`. 
+    /// Returns the un-delegated amount. 
+    pub fn rebalance<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        max_amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rebalance")
+            .argument(&max_amount)
+            .original_result()
+    }
+
+    /// Delegates the evacuated stake withdrawn from the retiring and over-allocated providers 
+    /// to the providers under their target share. The most under-allocated providers are 
+    /// delegated to, at most `MAX_REBALANCE_PROVIDERS` of them, the EGLD left waits for the 
+    /// next call. 
+    /// Returns the delegated amount. 
+    pub fn delegate_rebalance_pending(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("delegateRebalancePending")
+            .original_result()
+    }
+
+    /// Returns the stake and target share of every provider, retiring ones excluded. 
+    /// Targets are the delegation scores of the eligible providers applied to the whole 
+    /// liquid staked EGLD, evacuated stake included. A provider above its target is 
+    /// un-delegated from by `rebalance`, one below it gets the evacuated stake. 
+    pub fn get_provider_allocations(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ProviderAllocation<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProviderAllocations")
+            .original_result()
+    }
+
     /// Withdraws pending funds from a specified delegation contract, an essential function 
     /// to maintain liquidity for instant withdrawals when users leave the staking pool. 
     ///  
@@ -452,10 +796,47 @@ where
             .original_result()
     }
 
+    /// Withdraws the unbonded EGLD of every provider with a matured un-delegation in one call. 
+    /// Only providers whose un-delegations tracked by `unDelegatePending` reached their unbond 
+    /// epoch are called, at most `max_providers` of them and as many as the gas left allows. 
+    ///  
+    /// Arguments: 
+    /// - `max_providers`: Optional. Caps the batch, defaults to `MAX_WITHDRAW_PENDING_BATCH`. 
+    ///  
+    /// Returns the number of providers a withdraw was issued for. 
+    pub fn withdraw_pending_batch<
+        Arg0: ProxyArg<OptionalValue<usize>>,
+    >(
+        self,
+        max_providers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawPendingBatch")
+            .argument(&max_providers)
+            .original_result()
+    }
+
+    /// Queries the active stake of the contract on each given provider and reconciles it 
+    /// with the booked stake in the callback, see `reconcile_provider_callback`. 
+    pub fn reconcile_providers<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        providers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("reconcileProviders")
+            .argument(&providers)
+            .original_result()
+    }
+
     /// Claims accumulated staking rewards from the providers, optimizing the process 
     /// by delegating these rewards directly back into the contract to generate compounding 
     /// returns for xEGLD holders. This endpoint prevents repeated withdrawals and staking, 
-    /// improving gas efficiency and yield. 
+    /// improving gas efficiency and yield. Each provider is claimed separately so the rewards 
+    /// it paid are measured against its stake, see `getProviderRealizedApy`. 
     pub fn claim_rewards(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
@@ -505,12 +886,25 @@ where
             .original_result()
     }
 
-    pub fn managers(
+    pub fn role_members<
+        Arg0: ProxyArg<Role>,
+    >(
         self,
+        role: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getManagers")
+            .raw_call("getRoleMembers")
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn paused_actions(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPausedActions")
             .original_result()
     }
 
@@ -541,60 +935,202 @@ where
             .original_result()
     }
 
-    pub fn accumulator_contract(
+    pub fn last_fees_change_epoch(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccumulatorContract")
+            .raw_call("getLastFeesChangeEpoch")
             .original_result()
     }
 
-    pub fn state(
+    pub fn accumulator_contract(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, State> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getState")
+            .raw_call("getAccumulatorContract")
             .original_result()
     }
 
-    pub fn ls_token(
+    pub fn fee_recipients(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, FeeRecipient<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLsTokenId")
+            .raw_call("getFeeRecipients")
             .original_result()
     }
 
-    pub fn ls_token_supply(
+    pub fn fee_mode(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, FeeMode> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLsSupply")
+            .raw_call("getFeeMode")
             .original_result()
     }
 
-    pub fn virtual_egld_reserve(
+    pub fn referral_fee_share(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getVirtualEgldReserve")
+            .raw_call("getReferralFeeShare")
             .original_result()
     }
 
-    pub fn fees_reserve(
+    pub fn referrer_by_code<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        code: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferrerByCode")
+            .argument(&code)
+            .original_result()
+    }
+
+    pub fn user_referrer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserReferrer")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn user_referred_ls<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserReferredLs")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn user_referral_expiry_epoch<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserReferralExpiryEpoch")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn total_referred_ls(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalReferredLs")
+            .original_result()
+    }
+
+    pub fn referral_rewards_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferralRewardsReserve")
+            .original_result()
+    }
+
+    pub fn insurance_share(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInsuranceShare")
+            .original_result()
+    }
+
+    pub fn insurance_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInsuranceReserve")
+            .original_result()
+    }
+
+    pub fn recorded_losses(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRecordedLosses")
+            .original_result()
+    }
+
+    pub fn state(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, State> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getState")
+            .original_result()
+    }
+
+    pub fn ls_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLsTokenId")
+            .original_result()
+    }
+
+    pub fn ls_token_supply(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLsSupply")
+            .original_result()
+    }
+
+    pub fn virtual_egld_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVirtualEgldReserve")
+            .original_result()
+    }
+
+    pub fn fees_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("getFeesReserve")
             .original_result()
     }
 
+    pub fn ls_fees_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLsFeesReserve")
+            .original_result()
+    }
+
     pub fn total_withdrawn_egld(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
@@ -680,6 +1216,105 @@ where
             .original_result()
     }
 
+    pub fn last_rate_observation(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RateObservation<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastRateObservation")
+            .original_result()
+    }
+
+    pub fn realized_apy_scoring(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRealizedApyScoring")
+            .original_result()
+    }
+
+    pub fn early_redemption_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EarlyRedemptionConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEarlyRedemptionConfig")
+            .original_result()
+    }
+
+    pub fn pending_egld_for_restake(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingEgldForRestake")
+            .original_result()
+    }
+
+    pub fn withdraw_tickets_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWithdrawTicketsReserve")
+            .original_result()
+    }
+
+    pub fn config_timelock_delay(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getConfigTimelockDelay")
+            .original_result()
+    }
+
+    pub fn provider_application_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProviderApplicationConfig<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProviderApplicationConfig")
+            .original_result()
+    }
+
+    pub fn retiring_providers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRetiringProviders")
+            .original_result()
+    }
+
+    pub fn evacuating_egld(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEvacuatingEgld")
+            .original_result()
+    }
+
+    pub fn rebalance_pending_egld(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRebalancePendingEgld")
+            .original_result()
+    }
+
+    pub fn cooling_down_providers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCoolingDownProviders")
+            .original_result()
+    }
+
     pub fn migrate<
         Arg0: ProxyArg<BigUint<Env::Api>>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
@@ -712,25 +1347,27 @@ where
             .original_result()
     }
 
-    pub fn add_migration_sc_address<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-    >(
+    pub fn migration_sc_address(
         self,
-        address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setMigrationScAddress")
-            .argument(&address)
+            .raw_call("getMigrationScAddress")
             .original_result()
     }
 
-    pub fn migration_sc_address(
+    /// Returns the data read from the delegation contract of the provider for its service fee, 
+    /// uptime and age scores. 
+    pub fn get_provider_scoring_data<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProviderScoringData> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMigrationScAddress")
+            .raw_call("getProviderScoringData")
+            .argument(&address)
             .original_result()
     }
 
@@ -804,46 +1441,544 @@ where
             .argument(&is_eligible)
             .original_result()
     }
-}
 
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
-pub struct ScoringConfig {
-    pub min_nodes: u64,
-    pub max_nodes: u64,
-    pub min_apy: u64,
-    pub max_apy: u64,
-    pub stake_weight: u64,
-    pub apy_weight: u64,
-    pub nodes_weight: u64,
-    pub max_score_per_category: u64,
-    pub exponential_base: u64,
-    pub apy_growth_multiplier: u64,
-}
+    /// Starts removing a provider. It stops receiving delegations, its whole stake is 
+    /// un-delegated by the next `unDelegatePending` calls and staked again on the remaining 
+    /// providers once withdrawn. The provider is deleted when nothing is left on it. 
+    pub fn retire_provider<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("retireProvider")
+            .argument(&contract_address)
+            .original_result()
+    }
 
-#[type_abi]
-#[derive(TopEncode)]
-pub struct ChangeLiquidityEvent<Api>
-where
-    Api: ManagedTypeApi,
-{
-    pub caller: ManagedAddress<Api>,
-    pub ls_token_id: TokenIdentifier<Api>,
-    pub ls_token_supply: BigUint<Api>,
-    pub virtual_egld_reserve: BigUint<Api>,
-    pub fees_reserve: BigUint<Api>,
-    pub total_withdrawn_egld: BigUint<Api>,
-    pub pending_egld: BigUint<Api>,
-    pub pending_egld_for_unstake: BigUint<Api>,
-    pub pending_egld_for_unbond: BigUint<Api>,
-    pub block: u64,
-    pub epoch: u64,
-    pub timestamp: u64,
-}
+    /// Refreshes the total stake, the cap and the number of staked nodes of a provider from 
+    /// its delegation contract. Callable by anyone, the values are read on-chain. The APY is 
+    /// not exposed by the delegation contracts and stays set by `changeDelegationContractParams`. 
+    /// Also refreshes the checks of a pending provider application. 
+    pub fn sync_provider<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("syncProvider")
+            .argument(&contract_address)
+            .original_result()
+    }
 
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
-pub struct DelegationContractData<Api>
+    /// Realized APR of xEGLD in BPS, annualized from the exchange rate growth between 
+    /// the latest epoch snapshot and the newest one taken at least `epochs` epochs before. 
+    pub fn get_realized_apr<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        epochs: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRealizedApr")
+            .argument(&epochs)
+            .original_result()
+    }
+
+    /// Realized yield of a provider in BPS, annualized from the rewards claimed from it 
+    /// against the stake of the contract on it. The last window of at least 
+    /// `REALIZED_APR_EPOCHS` epochs is used, or the current one until a window is complete. 
+    pub fn get_provider_realized_apy<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegation_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProviderRealizedApy")
+            .argument(&delegation_address)
+            .original_result()
+    }
+
+    /// Returns the last `count` epoch snapshots, ordered from the oldest to the newest. 
+    pub fn get_epoch_snapshots<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        count: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EpochSnapshot<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEpochSnapshots")
+            .argument(&count)
+            .original_result()
+    }
+
+    /// Configures the early redemption discount, in BPS. The fee decays linearly from 
+    /// `max_fee`, right after un-delegation, to `min_fee`, right before the unbond epoch. 
+    ///  
+    /// Arguments: 
+    /// - `fee_to_holders`: When `true` the fee accrues to xEGLD holders through the 
+    ///                     virtual EGLD reserve, otherwise it is added to the fees reserve. 
+    pub fn set_early_redemption_config<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<bool>,
+    >(
+        self,
+        max_fee: Arg0,
+        min_fee: Arg1,
+        fee_to_holders: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEarlyRedemptionConfig")
+            .argument(&max_fee)
+            .argument(&min_fee)
+            .argument(&fee_to_holders)
+            .original_result()
+    }
+
+    pub fn disable_early_redemption(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("disableEarlyRedemption")
+            .original_result()
+    }
+
+    /// Redeems an unbonding position before its unbond epoch against the pending EGLD, 
+    /// paying the current early redemption fee. The EGLD of the position is either netted 
+    /// against the pending un-delegations or re-staked once it is withdrawn from the providers. 
+    ///  
+    /// Arguments: 
+    /// - `min_egld_out`: Optional. Reverts when the EGLD received after the fee is lower. 
+    pub fn redeem_early<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        min_egld_out: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("redeemEarly")
+            .argument(&min_egld_out)
+            .original_result()
+    }
+
+    /// Returns the EGLD received and the fee paid for an early redemption of `amount` 
+    /// unstake tokens of the given nonce, in the current epoch. 
+    pub fn get_early_redemption_quote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_nonce: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEarlyRedemptionQuote")
+            .argument(&token_nonce)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Burns unbonded unstake positions and registers a withdraw ticket for their EGLD. 
+    /// Tickets are filled in registration order as the unbonded EGLD arrives from the 
+    /// providers, instead of paying whoever calls `withdraw` first. 
+    ///  
+    /// Returns the id of the registered ticket. 
+    pub fn request_withdraw(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("requestWithdraw")
+            .original_result()
+    }
+
+    /// Pays the filled part of the given tickets to their owner. Fully paid tickets are removed. 
+    pub fn claim_withdraw<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        ticket_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimWithdraw")
+            .argument(&ticket_ids)
+            .original_result()
+    }
+
+    /// Allocates unbonded EGLD left over after a fill stopped at `MAX_WITHDRAW_TICKETS_FILLED`. 
+    pub fn fill_withdraw_queue(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("fillWithdrawQueue")
+            .original_result()
+    }
+
+    pub fn get_withdraw_ticket<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        ticket_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, WithdrawTicket<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWithdrawTicket")
+            .argument(&ticket_id)
+            .original_result()
+    }
+
+    pub fn get_user_withdraw_tickets<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, WithdrawTicket<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserWithdrawTickets")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Returns the number of tickets waiting for unbonded EGLD. 
+    pub fn get_withdraw_queue_length(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWithdrawQueueLength")
+            .original_result()
+    }
+
+    /// Proposes a configuration change. It can be executed once the current timelock delay 
+    /// has passed, which gives users time to react before fees or the unbond period change. 
+    ///  
+    /// Returns the id of the proposal. 
+    pub fn propose_config_change<
+        Arg0: ProxyArg<ConfigChange<Env::Api>>,
+    >(
+        self,
+        change: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeConfigChange")
+            .argument(&change)
+            .original_result()
+    }
+
+    /// Applies a proposed configuration change once its activation epoch is reached. 
+    /// Callable by anyone, the change was public for the whole timelock delay. 
+    pub fn execute_config_change<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeConfigChange")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn cancel_config_change<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelConfigChange")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_config_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ConfigProposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getConfigProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Returns the proposals neither executed nor cancelled, in proposal order. 
+    pub fn get_pending_config_proposals(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, ConfigProposal<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingConfigProposals")
+            .original_result()
+    }
+
+    /// Registers the caller as a referrer under `code`. Users passing the code to 
+    /// `delegateWithMinOut` or `delegateFor` attribute their minted xEGLD to the caller, 
+    /// which earns a share of the protocol fees. 
+    ///  
+    /// The share follows the referred xEGLD minted, not the xEGLD held: it stops when the 
+    /// referred user un-delegates it, but xEGLD transferred to other addresses keeps earning 
+    /// until the attribution expires. 
+    pub fn register_referrer<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        code: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerReferrer")
+            .argument(&code)
+            .original_result()
+    }
+
+    /// Sends the referral rewards accrued by the caller. 
+    pub fn claim_referral_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimReferralRewards")
+            .original_result()
+    }
+
+    /// Ends the attribution of the given users once `REFERRAL_ATTRIBUTION_EPOCHS` passed since 
+    /// their last referred deposit. Callable by anyone, users not expired yet are skipped. 
+    pub fn release_expired_referrals<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        users: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseExpiredReferrals")
+            .argument(&users)
+            .original_result()
+    }
+
+    /// Returns the referrer data, its rewards including the ones accrued since the last 
+    /// settlement. 
+    pub fn get_referrer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Referrer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferrer")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Records EGLD lost by a provider, e.g. after a slashing or a stuck delegation. 
+    /// The provider stake is reduced right away, the loss stays recorded until it is 
+    /// socialized through `socializeLosses`. 
+    pub fn record_provider_loss<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recordProviderLoss")
+            .argument(&contract_address)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Covers the recorded losses with the insurance reserve first. The covered EGLD is 
+    /// staked again, only the uncovered remainder lowers the virtual EGLD reserve. 
+    ///  
+    /// Returns the covered and the uncovered amounts. 
+    pub fn socialize_losses(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("socializeLosses")
+            .original_result()
+    }
+
+    /// Sets the checks a provider application must pass. Applications are disabled until 
+    /// a configuration is set. 
+    pub fn set_provider_application_config<
+        Arg0: ProxyArg<ProviderApplicationConfig<Env::Api>>,
+    >(
+        self,
+        config: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProviderApplicationConfig")
+            .argument(&config)
+            .original_result()
+    }
+
+    /// Submits a system delegation contract to join the providers, the caller becomes its 
+    /// admin and must own it. The paid seed is delegated once the application is approved. 
+    /// The owner, service fee, cap, stake and nodes of the delegation contract are read 
+    /// right away for the checks, an application of another address than the owner is 
+    /// dropped and its seed sent back. An expired application of the same contract is 
+    /// replaced. 
+    pub fn apply_as_provider<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        contract_address: Arg0,
+        apy: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("applyAsProvider")
+            .argument(&contract_address)
+            .argument(&apy)
+            .original_result()
+    }
+
+    /// Promotes an application passing the checks into the providers. A provider curator 
+    /// can approve it right away, anyone else once `auto_approval_epochs` have passed. 
+    pub fn approve_provider_application<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveProviderApplication")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    /// Drops an application and sends its seed back to the admin. Callable by the admin 
+    /// of the application or a provider curator, and by anyone once the application is 
+    /// `PROVIDER_APPLICATION_EXPIRY_EPOCHS` old. 
+    pub fn cancel_provider_application<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelProviderApplication")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn get_provider_application<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProviderApplication<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProviderApplication")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    /// Returns the pending applications, in submission order. 
+    pub fn get_pending_provider_applications(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, ProviderApplication<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingProviderApplications")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ScoringConfig {
+    pub min_nodes: u64,
+    pub max_nodes: u64,
+    pub min_apy: u64,
+    pub max_apy: u64,
+    pub min_service_fee: u64,
+    pub max_service_fee: u64,
+    pub min_uptime: u64,
+    pub max_uptime: u64,
+    pub min_age: u64,
+    pub max_age: u64,
+    pub min_top_up: u64,
+    pub max_top_up: u64,
+    pub stake_weight: u64,
+    pub apy_weight: u64,
+    pub nodes_weight: u64,
+    pub service_fee_weight: u64,
+    pub uptime_weight: u64,
+    pub age_weight: u64,
+    pub top_up_weight: u64,
+    pub stake_curve: ScoringCurve,
+    pub apy_curve: ScoringCurve,
+    pub nodes_curve: ScoringCurve,
+    pub service_fee_curve: ScoringCurve,
+    pub uptime_curve: ScoringCurve,
+    pub age_curve: ScoringCurve,
+    pub top_up_curve: ScoringCurve,
+    pub max_score_per_category: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScoringCurve {
+    Linear,
+    Power(u64),
+    Logistic(u64),
+    Step(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode)]
+pub struct ChangeLiquidityEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub caller: ManagedAddress<Api>,
+    pub ls_token_id: TokenIdentifier<Api>,
+    pub ls_token_supply: BigUint<Api>,
+    pub virtual_egld_reserve: BigUint<Api>,
+    pub fees_reserve: BigUint<Api>,
+    pub total_withdrawn_egld: BigUint<Api>,
+    pub pending_egld: BigUint<Api>,
+    pub pending_egld_for_unstake: BigUint<Api>,
+    pub pending_egld_for_unbond: BigUint<Api>,
+    pub block: u64,
+    pub epoch: u64,
+    pub timestamp: u64,
+    pub receiver: ManagedAddress<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct DelegationContractData<Api>
 where
     Api: ManagedTypeApi,
 {
@@ -865,3 +2000,235 @@ pub enum State {
     Inactive,
     Active,
 }
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct RateObservation<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub epoch: u64,
+    pub timestamp: u64,
+    pub exchange_rate: BigUint<Api>,
+    pub rate_cumulative: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct EpochSnapshot<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub epoch: u64,
+    pub virtual_egld_reserve: BigUint<Api>,
+    pub ls_token_supply: BigUint<Api>,
+    pub rewards: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct EarlyRedemptionConfig {
+    pub max_fee: u64,
+    pub min_fee: u64,
+    pub fee_to_holders: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct WithdrawTicket<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub owner: ManagedAddress<Api>,
+    pub amount: BigUint<Api>,
+    pub filled: BigUint<Api>,
+    pub created_epoch: u64,
+}
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct PendingUnbond<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub unbond_epoch: u64,
+    pub amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct InvariantCheck<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub invariant: Invariant,
+    pub expected: BigUint<Api>,
+    pub actual: BigUint<Api>,
+    pub delta: BigInt<Api>,
+    pub holds: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Invariant {
+    EgldBalance,
+    StakedEgld,
+    UnbondingEgld,
+    UnbondingLedger,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Copy, Clone, Debug)]
+pub enum PausableAction {
+    Delegate,
+    UnDelegate,
+    Withdraw,
+    DelegatePending,
+    UnDelegatePending,
+    ClaimRewards,
+    InstantDelegation,
+    WithdrawPending,
+    EarlyRedemption,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub enum ConfigChange<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Fees(BigUint<Api>),
+    UnbondPeriod(u64),
+    AccumulatorContract(ManagedAddress<Api>),
+    MaxSelectedProviders(BigUint<Api>),
+    MigrationScAddress(ManagedAddress<Api>),
+    TimelockDelay(u64),
+    FeeRecipients(ManagedVec<Api, FeeRecipient<Api>>),
+    FeeMode(FeeMode),
+    ReferralFeeShare(u64),
+    InsuranceShare(u64),
+}
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct FeeRecipient<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub address: ManagedAddress<Api>,
+    pub share: u64,
+    pub endpoint: Option<ManagedBuffer<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ConfigProposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub change: ConfigChange<Api>,
+    pub proposed_epoch: u64,
+    pub activation_epoch: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Role {
+    ProviderCurator,
+    ScoringAdmin,
+    Keeper,
+    Guardian,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Copy, Clone, Debug)]
+pub enum FeeMode {
+    Egld,
+    MintedLs,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Referrer<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub code: ManagedBuffer<Api>,
+    pub total_volume: BigUint<Api>,
+    pub attributed_ls: BigUint<Api>,
+    pub reward_per_ls_paid: BigUint<Api>,
+    pub rewards: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProviderApplicationConfig<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub min_service_fee: u64,
+    pub max_service_fee: u64,
+    pub min_nodes: u64,
+    pub min_cap_headroom: BigUint<Api>,
+    pub auto_approval_epochs: u64,
+    pub max_auto_approval_apy: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProviderApplication<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub admin_address: ManagedAddress<Api>,
+    pub seed: BigUint<Api>,
+    pub apy: u64,
+    pub submitted_epoch: u64,
+    pub service_fee: Option<u64>,
+    pub total_staked: Option<BigUint<Api>>,
+    pub delegation_contract_cap: Option<BigUint<Api>>,
+    pub nr_nodes: Option<u64>,
+    pub owner: Option<ManagedAddress<Api>>,
+}
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct ProviderAllocation<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub address: ManagedAddress<Api>,
+    pub staked: BigUint<Api>,
+    pub target: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug, Default,
+)]
+pub struct DelegationFailures {
+    pub total_failures: u64,
+    pub consecutive_failures: u64,
+    pub last_failure_epoch: u64,
+    pub readmission_epoch: u64,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug, Default,
+)]
+pub struct ProviderScoringData {
+    pub service_fee: Option<u64>,
+    pub nr_jailed_nodes: Option<u64>,
+    pub added_epoch: Option<u64>,
+}
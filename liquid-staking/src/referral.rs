@@ -0,0 +1,229 @@
+multiversx_sc::imports!();
+use crate::{
+    constants::{
+        BPS, MAX_REFERRAL_CODE_LENGTH, REFERRAL_ATTRIBUTION_EPOCHS, REFERRAL_REWARD_PRECISION,
+    },
+    errors::{
+        ERROR_INVALID_REFERRAL_CODE, ERROR_NOTHING_TO_CLAIM, ERROR_NOT_A_REFERRER,
+        ERROR_REFERRAL_CODE_NOT_FOUND, ERROR_REFERRAL_CODE_TAKEN, ERROR_REFERRAL_FOR_OTHER_ADDRESS,
+        ERROR_REFERRER_ALREADY_REGISTERED, ERROR_SELF_REFERRAL,
+    },
+    structs::Referrer,
+};
+
+#[multiversx_sc::module]
+pub trait ReferralModule:
+    crate::config::ConfigModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    /// Registers the caller as a referrer under `code`. Users passing the code to
+    /// `delegateWithMinOut` or `delegateFor` attribute their minted xEGLD to the caller,
+    /// which earns a share of the protocol fees.
+    ///
+    /// The share follows the referred xEGLD minted, not the xEGLD held: it stops when the
+    /// referred user un-delegates it, but xEGLD transferred to other addresses keeps earning
+    /// until the attribution expires.
+    #[endpoint(registerReferrer)]
+    fn register_referrer(&self, code: ManagedBuffer) {
+        require!(
+            !code.is_empty() && code.len() <= MAX_REFERRAL_CODE_LENGTH,
+            ERROR_INVALID_REFERRAL_CODE
+        );
+
+        let caller = self.blockchain().get_caller();
+        let referrer_mapper = self.referrer(&caller);
+        require!(
+            referrer_mapper.is_empty(),
+            ERROR_REFERRER_ALREADY_REGISTERED
+        );
+
+        let code_mapper = self.referrer_by_code(&code);
+        require!(code_mapper.is_empty(), ERROR_REFERRAL_CODE_TAKEN);
+
+        code_mapper.set(&caller);
+        referrer_mapper.set(Referrer {
+            code: code.clone(),
+            total_volume: BigUint::zero(),
+            attributed_ls: BigUint::zero(),
+            reward_per_ls_paid: self.referral_reward_per_ls().get(),
+            rewards: BigUint::zero(),
+        });
+
+        self.referrer_registered_event(&caller, &code);
+    }
+
+    /// Sends the referral rewards accrued by the caller.
+    #[endpoint(claimReferralRewards)]
+    fn claim_referral_rewards(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let referrer_mapper = self.referrer(&caller);
+        require!(!referrer_mapper.is_empty(), ERROR_NOT_A_REFERRER);
+
+        let mut referrer = referrer_mapper.get();
+        self.settle_referrer_rewards(&mut referrer);
+
+        let rewards = core::mem::replace(&mut referrer.rewards, BigUint::zero());
+        require!(rewards > BigUint::zero(), ERROR_NOTHING_TO_CLAIM);
+
+        referrer_mapper.set(&referrer);
+        self.referral_rewards_reserve()
+            .update(|reserve| *reserve -= &rewards);
+        self.tx().to(&caller).egld(&rewards).transfer();
+
+        self.referral_rewards_claimed_event(&caller, &rewards);
+
+        rewards
+    }
+
+    /// Ends the attribution of the given users once `REFERRAL_ATTRIBUTION_EPOCHS` passed since
+    /// their last referred deposit. Callable by anyone, users not expired yet are skipped.
+    #[endpoint(releaseExpiredReferrals)]
+    fn release_expired_referrals(&self, users: MultiValueEncoded<ManagedAddress>) {
+        for user in users {
+            self.release_expired_referral(&user);
+        }
+    }
+
+    /// Returns the referrer data, its rewards including the ones accrued since the last
+    /// settlement.
+    #[view(getReferrer)]
+    fn get_referrer(&self, address: ManagedAddress) -> Referrer<Self::Api> {
+        let referrer_mapper = self.referrer(&address);
+        require!(!referrer_mapper.is_empty(), ERROR_NOT_A_REFERRER);
+
+        let mut referrer = referrer_mapper.get();
+        self.settle_referrer_rewards(&mut referrer);
+
+        referrer
+    }
+
+    /// Returns the referrer of `referrer_code`, when one is given. Only `user` can pick its
+    /// referrer, a deposit made for it by another address could otherwise take over the
+    /// attribution of its later referred deposits.
+    fn resolve_referrer(
+        &self,
+        user: &ManagedAddress,
+        referrer_code: OptionalValue<ManagedBuffer>,
+    ) -> Option<ManagedAddress> {
+        let code = referrer_code.into_option()?;
+        require!(
+            &self.blockchain().get_caller() == user,
+            ERROR_REFERRAL_FOR_OTHER_ADDRESS
+        );
+
+        let code_mapper = self.referrer_by_code(&code);
+        require!(!code_mapper.is_empty(), ERROR_REFERRAL_CODE_NOT_FOUND);
+
+        let referrer = code_mapper.get();
+        require!(&referrer != user, ERROR_SELF_REFERRAL);
+
+        Some(referrer)
+    }
+
+    /// Attributes xEGLD minted to `user` with a referrer code. The first referrer of a user
+    /// keeps the attribution of its later referred deposits until it expires, each referred
+    /// deposit extends it by `REFERRAL_ATTRIBUTION_EPOCHS`.
+    fn attribute_referral(
+        &self,
+        user: &ManagedAddress,
+        referrer_address: &ManagedAddress,
+        ls_amount: &BigUint,
+    ) {
+        self.release_expired_referral(user);
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.user_referral_expiry_epoch(user)
+            .set(current_epoch + REFERRAL_ATTRIBUTION_EPOCHS);
+
+        let user_referrer_mapper = self.user_referrer(user);
+        if user_referrer_mapper.is_empty() {
+            user_referrer_mapper.set(referrer_address);
+        }
+
+        let referrer_address = user_referrer_mapper.get();
+        self.referrer(&referrer_address).update(|referrer| {
+            self.settle_referrer_rewards(referrer);
+            referrer.total_volume += ls_amount;
+            referrer.attributed_ls += ls_amount;
+        });
+
+        self.user_referred_ls(user)
+            .update(|referred_ls| *referred_ls += ls_amount);
+        self.total_referred_ls().update(|total| *total += ls_amount);
+    }
+
+    /// Releases the whole attribution of `user` once expired, the user is then free to be
+    /// referred again, by any referrer.
+    fn release_expired_referral(&self, user: &ManagedAddress) {
+        let expiry_epoch_mapper = self.user_referral_expiry_epoch(user);
+        if expiry_epoch_mapper.is_empty()
+            || self.blockchain().get_block_epoch() < expiry_epoch_mapper.get()
+        {
+            return;
+        }
+
+        self.release_referral(user, &self.user_referred_ls(user).get());
+        self.user_referrer(user).clear();
+        expiry_epoch_mapper.clear();
+    }
+
+    /// Stops attributing xEGLD burned by `user`. Transfers of xEGLD cannot be followed, so
+    /// the attribution ends when the referred user un-delegates or, for xEGLD moved to other
+    /// addresses, when it expires `REFERRAL_ATTRIBUTION_EPOCHS` after the last referred deposit.
+    fn release_referral(&self, user: &ManagedAddress, ls_amount: &BigUint) {
+        let user_referrer_mapper = self.user_referrer(user);
+        if user_referrer_mapper.is_empty() {
+            return;
+        }
+
+        let user_referred_ls_mapper = self.user_referred_ls(user);
+        let released = user_referred_ls_mapper.get().min(ls_amount.clone());
+        if released == BigUint::zero() {
+            return;
+        }
+
+        self.referrer(&user_referrer_mapper.get())
+            .update(|referrer| {
+                self.settle_referrer_rewards(referrer);
+                referrer.attributed_ls -= &released;
+            });
+
+        user_referred_ls_mapper.update(|referred_ls| *referred_ls -= &released);
+        self.total_referred_ls().update(|total| *total -= &released);
+    }
+
+    /// Accrues the referral share of the protocol fees to the referrers, proportionally to
+    /// their attributed xEGLD. Returns the part of the fees taken.
+    fn accrue_referral_fees(&self, fees: &BigUint) -> BigUint {
+        let total_referred_ls = self.total_referred_ls().get();
+        let referral_fee_share = self.referral_fee_share().get();
+        if total_referred_ls == BigUint::zero() || referral_fee_share == 0 {
+            return BigUint::zero();
+        }
+
+        let referral_fees = fees * &BigUint::from(referral_fee_share) / BPS;
+        if referral_fees == BigUint::zero() {
+            return referral_fees;
+        }
+
+        let reward_per_ls_increase =
+            referral_fees.clone() * REFERRAL_REWARD_PRECISION / &total_referred_ls;
+        self.referral_reward_per_ls()
+            .update(|reward_per_ls| *reward_per_ls += &reward_per_ls_increase);
+        self.referral_rewards_reserve()
+            .update(|reserve| *reserve += &referral_fees);
+
+        referral_fees
+    }
+
+    fn settle_referrer_rewards(&self, referrer: &mut Referrer<Self::Api>) {
+        let reward_per_ls = self.referral_reward_per_ls().get();
+
+        let reward_per_ls_delta = &reward_per_ls - &referrer.reward_per_ls_paid;
+        referrer.rewards +=
+            &referrer.attributed_ls * &reward_per_ls_delta / REFERRAL_REWARD_PRECISION;
+        referrer.reward_per_ls_paid = reward_per_ls;
+    }
+}
@@ -1,6 +1,6 @@
 multiversx_sc::imports!();
 use crate::{
-    structs::{DelegationContractSelectionInfo, ScoringConfig},
+    structs::{DelegationContractSelectionInfo, ScoringConfig, ScoringCurve},
     BPS,
 };
 
@@ -22,7 +22,45 @@ pub trait ScoreModule {
             config,
         );
 
-        let final_score = self.combine_scores(node_score, apy_score, stake_score, config);
+        // Lower service fees and top-ups are favored, like the stake
+        let service_fee_score = self.calculate_dimension_score(
+            info.service_fee,
+            config.min_service_fee,
+            config.max_service_fee,
+            is_delegate,
+            &config.service_fee_curve,
+            config,
+        );
+        let uptime_score = self.calculate_dimension_score(
+            info.uptime,
+            config.min_uptime,
+            config.max_uptime,
+            !is_delegate,
+            &config.uptime_curve,
+            config,
+        );
+        let age_score = self.calculate_dimension_score(
+            info.age,
+            config.min_age,
+            config.max_age,
+            !is_delegate,
+            &config.age_curve,
+            config,
+        );
+        let top_up_score = self.calculate_dimension_score(
+            Some(info.top_up),
+            config.min_top_up,
+            config.max_top_up,
+            is_delegate,
+            &config.top_up_curve,
+            config,
+        );
+
+        let final_score = self.combine_scores(node_score, apy_score, stake_score, config)
+            + service_fee_score.mul(config.service_fee_weight)
+            + uptime_score.mul(config.uptime_weight)
+            + age_score.mul(config.age_weight)
+            + top_up_score.mul(config.top_up_weight);
         info.score = final_score.clone();
         final_score
     }
@@ -33,33 +71,26 @@ pub trait ScoreModule {
         is_delegate: bool,
         config: &ScoringConfig,
     ) -> BigUint {
-        let nodes_biguint = BigUint::from(nr_nodes);
-        let min = BigUint::from(config.min_nodes);
-        let max = BigUint::from(config.max_nodes);
-
-        // Get base score
-        let base_score = self.norm_linear_clamp(&nodes_biguint, &min, &max, is_delegate);
-
-        // Apply aggressive exponential scaling
-        let scaled_score = base_score.pow(config.exponential_base as u32);
-
-        // Multiply by max_score_per_category to get final range
-        scaled_score * BigUint::from(config.max_score_per_category) / BigUint::from(BPS)
+        self.calculate_dimension_score(
+            Some(nr_nodes),
+            config.min_nodes,
+            config.max_nodes,
+            is_delegate,
+            &config.nodes_curve,
+            config,
+        )
     }
 
     fn calculate_apy_score(&self, apy: u64, is_delegate: bool, config: &ScoringConfig) -> BigUint {
-        let apy_biguint = BigUint::from(apy);
-        let min = BigUint::from(config.min_apy);
-        let max = BigUint::from(config.max_apy);
-
-        // Get base score - note the !is_delegate for inverse scoring
-        let base_score = self.norm_linear_clamp(&apy_biguint, &min, &max, !is_delegate);
-
-        // Apply more aggressive exponential scaling for APY
-        let scaled_score = base_score.pow(config.apy_growth_multiplier as u32);
-
-        // Multiply by max_score_per_category to get final range
-        scaled_score * BigUint::from(config.max_score_per_category) / BigUint::from(BPS)
+        // Note the !is_delegate for inverse scoring
+        self.calculate_dimension_score(
+            Some(apy),
+            config.min_apy,
+            config.max_apy,
+            !is_delegate,
+            &config.apy_curve,
+            config,
+        )
     }
 
     fn calculate_stake_score(
@@ -74,22 +105,49 @@ pub trait ScoreModule {
             let base_score =
                 self.norm_linear_clamp(staked, &BigUint::zero(), total_stake, is_delegate);
 
-            // Apply exponential scaling
-            let scaled_score = base_score.pow(config.exponential_base as u32);
+            // Apply the stake curve
+            let scaled_score = self.apply_scoring_curve(base_score, &config.stake_curve);
 
             // Multiply by max_score_per_category to get final range
             scaled_score * BigUint::from(config.max_score_per_category) / BigUint::from(BPS)
         } else {
             // It can happen only when all selected addresses have 0 staked, very rare to happen almost impossible, due to WL requirements of 1 EGLD
-            // Apply exponential scaling
-            let scaled_score =
-                BigUint::from(config.max_score_per_category).pow(config.exponential_base as u32);
+            // Apply the stake curve
+            let scaled_score = self.apply_scoring_curve(BigUint::from(BPS), &config.stake_curve);
 
             // Multiply by max_score_per_category to get final range
             scaled_score * BigUint::from(config.max_score_per_category) / BigUint::from(BPS)
         };
     }
 
+    /// Scores a value within the `min`..`max` range of its dimension. An unknown value
+    /// scores as the middle of the range, whatever the direction.
+    fn calculate_dimension_score(
+        &self,
+        value: Option<u64>,
+        min: u64,
+        max: u64,
+        down: bool,
+        curve: &ScoringCurve,
+        config: &ScoringConfig,
+    ) -> BigUint {
+        // Get base score
+        let base_score = match value {
+            Some(value) => self.norm_linear_clamp(
+                &BigUint::from(value),
+                &BigUint::from(min),
+                &BigUint::from(max),
+                down,
+            ),
+            None => BigUint::from(BPS / 2),
+        };
+
+        let scaled_score = self.apply_scoring_curve(base_score, curve);
+
+        // Multiply by max_score_per_category to get final range
+        scaled_score * BigUint::from(config.max_score_per_category) / BigUint::from(BPS)
+    }
+
     fn combine_scores(
         &self,
         node_score: BigUint,
@@ -98,7 +156,6 @@ pub trait ScoreModule {
         config: &ScoringConfig,
     ) -> BigUint {
         // Apply weights
-        
 
         node_score
             .mul(config.nodes_weight)
@@ -147,4 +204,44 @@ pub trait ScoreModule {
             normalized
         }
     }
+
+    /// Shapes a normalized score, in BPS, keeping it within 0..BPS whatever the curve. The
+    /// power curve is scaled back as `x^n / BPS^(n - 1)`. The logistic curve uses the algebraic
+    /// sigmoid `x / (1 + |x|)`, with `x` the distance to the middle of the range times the
+    /// steepness.
+    fn apply_scoring_curve(&self, normalized: BigUint, curve: &ScoringCurve) -> BigUint {
+        match *curve {
+            ScoringCurve::Linear => normalized,
+            ScoringCurve::Power(0) => BigUint::from(BPS),
+            ScoringCurve::Power(exponent) => {
+                let exponent = exponent.min(u32::MAX as u64) as u32;
+                normalized.pow(exponent) / BigUint::from(BPS).pow(exponent - 1)
+            }
+            ScoringCurve::Logistic(steepness) => {
+                let bps = BigUint::from(BPS);
+                let half = BigUint::from(BPS / 2);
+                let above_middle = normalized >= half;
+                let distance = if above_middle {
+                    &normalized - &half
+                } else {
+                    &half - &normalized
+                };
+
+                let x = distance * steepness;
+                let offset = &half * &x / (&bps + &x);
+                if above_middle {
+                    half + offset
+                } else {
+                    half - offset
+                }
+            }
+            ScoringCurve::Step(threshold) => {
+                if normalized >= threshold {
+                    BigUint::from(BPS)
+                } else {
+                    BigUint::zero()
+                }
+            }
+        }
+    }
 }
@@ -1,15 +1,19 @@
 multiversx_sc::imports!();
 use crate::{
     structs::{
-        DelegationContractData, DelegationContractSelectionInfo, DelegatorSelection, ScoringConfig,
+        DelegationContractData, DelegationContractSelectionInfo, DelegatorSelection,
+        ProviderScoringData, ScoringConfig,
     },
     StorageCache, DECIMALS, ERROR_BAD_DELEGATION_ADDRESS, ERROR_NO_DELEGATION_CONTRACTS,
-    ERROR_SCORING_CONFIG_NOT_SET, MIN_EGLD_TO_DELEGATE,
+    ERROR_SCORING_CONFIG_NOT_SET, MIN_EGLD_TO_DELEGATE, NODE_STAKE_EGLD, ONE_EGLD,
 };
 
 #[multiversx_sc::module]
 pub trait SelectionModule:
-    crate::storage::StorageModule + crate::config::ConfigModule + crate::score::ScoreModule
+    crate::storage::StorageModule
+    + crate::config::ConfigModule
+    + crate::score::ScoreModule
+    + crate::apr::AprModule
 {
     #[inline]
     fn get_scoring_config(&self) -> ScoringConfig {
@@ -84,13 +88,18 @@ pub trait SelectionModule:
 
         let mut selected_addresses = ManagedVec::new();
         let mut total_stake = BigUint::zero();
+        let realized_apy_scoring = self.realized_apy_scoring().get();
 
         for address in map_list.iter() {
             let contract_data = self.delegation_contract_data(&address).get();
 
             if self.is_delegation_provider_eligible(&contract_data, min_egld) {
                 total_stake += &contract_data.get_total_amount_with_pending_callbacks();
-                selected_addresses.push(self.create_selection_info(&address, &contract_data));
+                selected_addresses.push(self.create_selection_info(
+                    &address,
+                    &contract_data,
+                    realized_apy_scoring,
+                ));
             }
 
             if selected_addresses.len() == max_providers {
@@ -149,6 +158,7 @@ pub trait SelectionModule:
             map_list.len()
         };
         let max_providers = self.calculate_max_providers(amount, min_egld, providers_len);
+        let realized_apy_scoring = self.realized_apy_scoring().get();
 
         let amount_per_all_providers = amount / &hard_max_providers;
 
@@ -181,7 +191,11 @@ pub trait SelectionModule:
 
             if amount_to_take > BigUint::zero() {
                 total_stake += staked;
-                selected_providers.push(self.create_selection_info(&address, &contract_data));
+                selected_providers.push(self.create_selection_info(
+                    &address,
+                    &contract_data,
+                    realized_apy_scoring,
+                ));
 
                 if remaining > amount_to_take {
                     remaining -= amount_to_take;
@@ -424,7 +438,15 @@ pub trait SelectionModule:
         &self,
         address: &ManagedAddress,
         contract_data: &DelegationContractData<Self::Api>,
+        realized_apy_scoring: bool,
     ) -> DelegationContractSelectionInfo<Self::Api> {
+        let scoring_data = self.get_provider_scoring_data(address);
+        let nr_known_nodes = contract_data.nr_nodes + scoring_data.nr_jailed_nodes.unwrap_or(0);
+        let total_staked_egld = (&contract_data.total_staked / BigUint::from(ONE_EGLD))
+            .to_u64()
+            .unwrap_or(u64::MAX);
+        let nodes_stake_egld = contract_data.nr_nodes.saturating_mul(NODE_STAKE_EGLD);
+
         DelegationContractSelectionInfo {
             address: address.clone(),
             space_left: if contract_data.delegation_contract_cap == BigUint::zero() {
@@ -433,13 +455,41 @@ pub trait SelectionModule:
                 Some(&contract_data.delegation_contract_cap - &contract_data.total_staked)
             },
             total_staked: contract_data.total_staked.clone(),
-            apy: contract_data.apy,
+            apy: if realized_apy_scoring {
+                self.compute_provider_realized_apy(address)
+                    .unwrap_or(contract_data.apy)
+            } else {
+                contract_data.apy
+            },
             score: BigUint::zero(),
             nr_nodes: contract_data.nr_nodes,
             total_staked_from_ls_contract: contract_data.get_total_amount_with_pending_callbacks(),
+            service_fee: scoring_data.service_fee,
+            uptime: match scoring_data.nr_jailed_nodes {
+                Some(_) if nr_known_nodes > 0 => {
+                    Some(contract_data.nr_nodes * BPS / nr_known_nodes)
+                }
+                _ => None,
+            },
+            age: scoring_data
+                .added_epoch
+                .map(|added_epoch| self.blockchain().get_block_epoch() - added_epoch),
+            top_up: total_staked_egld.saturating_sub(nodes_stake_egld),
         }
     }
 
+    /// Returns the data read from the delegation contract of the provider for its service fee,
+    /// uptime and age scores.
+    #[view(getProviderScoringData)]
+    fn get_provider_scoring_data(&self, address: &ManagedAddress) -> ProviderScoringData {
+        let scoring_data_mapper = self.provider_scoring_data(address);
+        if scoring_data_mapper.is_empty() {
+            return ProviderScoringData::default();
+        }
+
+        scoring_data_mapper.get()
+    }
+
     fn calculate_max_providers(
         &self,
         amount_to_delegate: &BigUint<Self::Api>,
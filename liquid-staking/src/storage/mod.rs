@@ -1,4 +1,9 @@
-use crate::structs::{DelegationContractData, ScoringConfig, State};
+use crate::structs::{
+    ConfigProposal, DelegationContractData, DelegationFailures, EarlyRedemptionConfig,
+    EpochSnapshot, FeeMode, FeeRecipient, LegacyScoringConfig, PendingUnbond, ProviderApplication,
+    ProviderApplicationConfig, ProviderLosses, ProviderScoringData, ProviderYield, RateObservation,
+    Referrer, Role, ScoringConfig, State, WithdrawTicket,
+};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -20,10 +25,23 @@ pub trait StorageModule {
         contract_address: &ManagedAddress,
     ) -> SingleValueMapper<DelegationContractData<Self::Api>>;
 
-    #[view(getManagers)]
+    // Legacy flat managers set, moved to roles on upgrade
     #[storage_mapper("managers")]
     fn managers(&self) -> UnorderedSetMapper<ManagedAddress>;
 
+    // Bitmap of the roles granted to an address, see `Role::flag`
+    #[storage_mapper("addressRoles")]
+    fn address_roles(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("roleMembers")]
+    fn role_members(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Bitmap of the paused actions, see `PausableAction::flag`
+    #[view(getPausedActions)]
+    #[storage_mapper("pausedActions")]
+    fn paused_actions(&self) -> SingleValueMapper<u64>;
+
     #[view(getLiquidityProviders)]
     #[storage_mapper("liquidityProviders")]
     fn liquidity_providers(&self) -> UnorderedSetMapper<ManagedAddress>;
@@ -32,14 +50,91 @@ pub trait StorageModule {
     #[storage_mapper("scoringConfig")]
     fn scoring_config(&self) -> SingleValueMapper<ScoringConfig>;
 
+    // Same key as `scoring_config`, to read a config stored before the curves on upgrade
+    #[storage_mapper("scoringConfig")]
+    fn legacy_scoring_config(&self) -> SingleValueMapper<LegacyScoringConfig>;
+
     #[view(fees)]
     #[storage_mapper("fees")]
     fn fees(&self) -> SingleValueMapper<BigUint>;
 
+    #[view(getLastFeesChangeEpoch)]
+    #[storage_mapper("lastFeesChangeEpoch")]
+    fn last_fees_change_epoch(&self) -> SingleValueMapper<u64>;
+
     #[view(getAccumulatorContract)]
     #[storage_mapper("accumulatorContract")]
     fn accumulator_contract(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[view(getFeeRecipients)]
+    #[storage_mapper("feeRecipients")]
+    fn fee_recipients(&self) -> SingleValueMapper<ManagedVec<FeeRecipient<Self::Api>>>;
+
+    #[view(getFeeMode)]
+    #[storage_mapper("feeMode")]
+    fn fee_mode(&self) -> SingleValueMapper<FeeMode>;
+
+    #[view(getReferralFeeShare)]
+    #[storage_mapper("referralFeeShare")]
+    fn referral_fee_share(&self) -> SingleValueMapper<u64>;
+
+    #[view(getReferrerByCode)]
+    #[storage_mapper("referrerByCode")]
+    fn referrer_by_code(&self, code: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("referrer")]
+    fn referrer(&self, address: &ManagedAddress) -> SingleValueMapper<Referrer<Self::Api>>;
+
+    #[view(getUserReferrer)]
+    #[storage_mapper("userReferrer")]
+    fn user_referrer(&self, user: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getUserReferredLs)]
+    #[storage_mapper("userReferredLs")]
+    fn user_referred_ls(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Epoch from which the attribution of the user can be released by anyone
+    #[view(getUserReferralExpiryEpoch)]
+    #[storage_mapper("userReferralExpiryEpoch")]
+    fn user_referral_expiry_epoch(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[view(getTotalReferredLs)]
+    #[storage_mapper("totalReferredLs")]
+    fn total_referred_ls(&self) -> SingleValueMapper<BigUint>;
+
+    // Referral rewards per referred xEGLD since deployment, scaled by 10^18
+    #[storage_mapper("referralRewardPerLs")]
+    fn referral_reward_per_ls(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getReferralRewardsReserve)]
+    #[storage_mapper("referralRewardsReserve")]
+    fn referral_rewards_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    // BPS of the claimed rewards moved to the insurance reserve
+    #[view(getInsuranceShare)]
+    #[storage_mapper("insuranceShare")]
+    fn insurance_share(&self) -> SingleValueMapper<u64>;
+
+    #[view(getInsuranceReserve)]
+    #[storage_mapper("insuranceReserve")]
+    fn insurance_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    // Provider losses already removed from their stake and not yet socialized
+    #[view(getRecordedLosses)]
+    #[storage_mapper("recordedLosses")]
+    fn recorded_losses(&self) -> SingleValueMapper<BigUint>;
+
+    // Losses booked on each provider, reversed if its stake shows up again
+    #[storage_mapper("providerLosses")]
+    fn provider_losses(
+        &self,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<ProviderLosses<Self::Api>>;
+
+    // Providers with a part of `recorded_losses`
+    #[storage_mapper("unsocializedLossProviders")]
+    fn unsocialized_loss_providers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
     #[view(getState)]
     #[storage_mapper("state")]
     fn state(&self) -> SingleValueMapper<State>;
@@ -60,6 +155,10 @@ pub trait StorageModule {
     #[storage_mapper("feesReserve")]
     fn fees_reserve(&self) -> SingleValueMapper<BigUint>;
 
+    #[view(getLsFeesReserve)]
+    #[storage_mapper("lsFeesReserve")]
+    fn ls_fees_reserve(&self) -> SingleValueMapper<BigUint>;
+
     #[view(getTotalWithdrawnEgld)]
     #[storage_mapper("totalWithdrawnEgld")]
     fn total_withdrawn_egld(&self) -> SingleValueMapper<BigUint>;
@@ -84,6 +183,10 @@ pub trait StorageModule {
     #[storage_mapper("unstakeTokenNonce")]
     fn unstake_token_nonce(&self, epoch: u64) -> SingleValueMapper<u64>;
 
+    // Nonce of the latest unbond epoch position, split nonces are never stored here
+    #[storage_mapper("lastUnbondEpochNonce")]
+    fn last_unbond_epoch_nonce(&self) -> SingleValueMapper<u64>;
+
     #[view(maxDelegationAddresses)]
     #[storage_mapper("maxDelegationAddresses")]
     fn max_delegation_addresses(&self) -> SingleValueMapper<usize>;
@@ -95,4 +198,176 @@ pub trait StorageModule {
     #[view(unbondPeriod)]
     #[storage_mapper("unbondPeriod")]
     fn unbond_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLastRateObservation)]
+    #[storage_mapper("lastRateObservation")]
+    fn last_rate_observation(&self) -> SingleValueMapper<RateObservation<Self::Api>>;
+
+    #[storage_mapper("rateSnapshots")]
+    fn rate_snapshots(&self) -> VecMapper<RateObservation<Self::Api>>;
+
+    // Index of the oldest snapshot once the ring buffer is full
+    #[storage_mapper("rateSnapshotsCursor")]
+    fn rate_snapshots_cursor(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("epochSnapshots")]
+    fn epoch_snapshots(&self) -> VecMapper<EpochSnapshot<Self::Api>>;
+
+    // Index of the oldest epoch snapshot once the ring buffer is full
+    #[storage_mapper("epochSnapshotsCursor")]
+    fn epoch_snapshots_cursor(&self) -> SingleValueMapper<usize>;
+
+    // Rewards claimed from each provider, measured against its stake
+    #[storage_mapper("providerYield")]
+    fn provider_yield(
+        &self,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<ProviderYield<Self::Api>>;
+
+    #[view(isRealizedApyScoring)]
+    #[storage_mapper("realizedApyScoring")]
+    fn realized_apy_scoring(&self) -> SingleValueMapper<bool>;
+
+    #[view(getEarlyRedemptionConfig)]
+    #[storage_mapper("earlyRedemptionConfig")]
+    fn early_redemption_config(&self) -> SingleValueMapper<EarlyRedemptionConfig>;
+
+    // Unbonding EGLD of early redeemed positions, returned to the pool once withdrawn
+    #[view(getPendingEgldForRestake)]
+    #[storage_mapper("pendingEgldForRestake")]
+    fn pending_egld_for_restake(&self) -> SingleValueMapper<BigUint>;
+
+    // The same EGLD by unbond epoch of the redeemed positions, oldest first
+    #[storage_mapper("pendingRestakeUnbonds")]
+    fn pending_restake_unbonds(&self) -> SingleValueMapper<ManagedVec<PendingUnbond<Self::Api>>>;
+
+    #[storage_mapper("withdrawTicket")]
+    fn withdraw_ticket(&self, ticket_id: u64) -> SingleValueMapper<WithdrawTicket<Self::Api>>;
+
+    #[storage_mapper("lastWithdrawTicketId")]
+    fn last_withdraw_ticket_id(&self) -> SingleValueMapper<u64>;
+
+    // Tickets not yet fully filled, in registration order
+    #[storage_mapper("withdrawQueue")]
+    fn withdraw_queue(&self) -> QueueMapper<u64>;
+
+    #[storage_mapper("userWithdrawTickets")]
+    fn user_withdraw_tickets(&self, user: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    // EGLD still owed to the queued tickets, not yet allocated to them
+    #[storage_mapper("withdrawQueueOutstanding")]
+    fn withdraw_queue_outstanding(&self) -> SingleValueMapper<BigUint>;
+
+    // Unbonded EGLD allocated to tickets and not yet claimed
+    #[view(getWithdrawTicketsReserve)]
+    #[storage_mapper("withdrawTicketsReserve")]
+    fn withdraw_tickets_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("configProposal")]
+    fn config_proposal(&self, proposal_id: u64) -> SingleValueMapper<ConfigProposal<Self::Api>>;
+
+    #[storage_mapper("lastConfigProposalId")]
+    fn last_config_proposal_id(&self) -> SingleValueMapper<u64>;
+
+    // Proposals neither executed nor cancelled, in proposal order
+    #[storage_mapper("pendingConfigProposals")]
+    fn pending_config_proposals(&self) -> SetMapper<u64>;
+
+    // Epochs between the proposal of a configuration change and its execution
+    #[view(getConfigTimelockDelay)]
+    #[storage_mapper("configTimelockDelay")]
+    fn config_timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    // Provider values only used for the scoring, see `ScoringConfig`
+    #[storage_mapper("providerScoringData")]
+    fn provider_scoring_data(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> SingleValueMapper<ProviderScoringData>;
+
+    // EGLD delegated when whitelisting a provider, staked until the provider is retired and
+    // never booked as liquid staked EGLD
+    #[storage_mapper("providerSeedStake")]
+    fn provider_seed_stake(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    // Paid the whitelisting seed, which goes back to it once the provider is retired
+    #[storage_mapper("providerSeedOwner")]
+    fn provider_seed_owner(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    // Set while the seed of a retired provider is un-delegated, the last stake to withdraw
+    #[storage_mapper("providerSeedUnbondEpoch")]
+    fn provider_seed_unbond_epoch(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+
+    #[view(getProviderApplicationConfig)]
+    #[storage_mapper("providerApplicationConfig")]
+    fn provider_application_config(
+        &self,
+    ) -> SingleValueMapper<ProviderApplicationConfig<Self::Api>>;
+
+    #[storage_mapper("providerApplication")]
+    fn provider_application(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> SingleValueMapper<ProviderApplication<Self::Api>>;
+
+    // Delegation contracts with a pending application, in submission order
+    #[storage_mapper("providerApplications")]
+    fn provider_applications(&self) -> SetMapper<ManagedAddress>;
+
+    // Seeds of the pending applications, held until they are approved or cancelled
+    #[storage_mapper("providerApplicationsReserve")]
+    fn provider_applications_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    // Providers being removed, their stake is evacuated by `unDelegatePending`
+    #[view(getRetiringProviders)]
+    #[storage_mapper("retiringProviders")]
+    fn retiring_providers(&self) -> SetMapper<ManagedAddress>;
+
+    // Stake un-delegated to be staked again on other providers, by a retirement or a
+    // rebalance, until it is withdrawn
+    #[view(getEvacuatingEgld)]
+    #[storage_mapper("evacuatingEgld")]
+    fn evacuating_egld(&self) -> SingleValueMapper<BigUint>;
+
+    // Evacuated stake once withdrawn, delegated again only to the providers under their
+    // target share by `delegateRebalancePending`. User withdrawals never pay from it.
+    #[view(getRebalancePendingEgld)]
+    #[storage_mapper("rebalancePendingEgld")]
+    fn rebalance_pending_egld(&self) -> SingleValueMapper<BigUint>;
+
+    // Evacuated stake of a provider still unbonding, part of its unbonding ledger
+    #[storage_mapper("providerEvacuationUnbonds")]
+    fn provider_evacuation_unbonds(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedVec<PendingUnbond<Self::Api>>>;
+
+    // Failed delegations of a provider, kept until the provider is removed
+    #[storage_mapper("delegationFailures")]
+    fn delegation_failures(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> SingleValueMapper<DelegationFailures>;
+
+    // Providers made ineligible by a failed delegation, re-admitted by `delegatePending`
+    // once their cooldown is over
+    #[view(getCoolingDownProviders)]
+    #[storage_mapper("coolingDownProviders")]
+    fn cooling_down_providers(&self) -> SetMapper<ManagedAddress>;
+
+    // Unbonding ledger of a provider: un-delegations not yet withdrawn, oldest first
+    #[storage_mapper("providerPendingUnbonds")]
+    fn provider_pending_unbonds(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedVec<PendingUnbond<Self::Api>>>;
 }
@@ -1,6 +1,8 @@
 multiversx_sc::derive_imports!();
 multiversx_sc::imports!();
 
+use crate::constants::{BPS, EPOCHS_PER_YEAR, INITIAL_EXCHANGE_RATE};
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
 pub struct DelegationContractData<M: ManagedTypeApi> {
@@ -79,6 +81,114 @@ pub struct DelegationContractSelectionInfo<M: ManagedTypeApi> {
     pub nr_nodes: u64,
     pub total_staked_from_ls_contract: BigUint<M>,
     pub space_left: Option<BigUint<M>>, // None means unlimited
+    // None when not synced yet
+    pub service_fee: Option<u64>,
+    pub uptime: Option<u64>, // BPS of the nodes not jailed
+    pub age: Option<u64>,    // Epochs since the provider was added
+    pub top_up: u64,         // EGLD staked above the node stakes
+}
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct RateObservation<M: ManagedTypeApi> {
+    pub epoch: u64,
+    pub timestamp: u64,
+    pub exchange_rate: BigUint<M>,
+    // Sum of exchange_rate * seconds, accumulated since the first observation
+    pub rate_cumulative: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> RateObservation<M> {
+    /// The rate did not change since `self.timestamp`, so the accumulator grows linearly.
+    pub fn cumulative_at(&self, timestamp: u64) -> BigUint<M> {
+        let elapsed = BigUint::from(timestamp - self.timestamp);
+        &self.rate_cumulative + &(&self.exchange_rate * &elapsed)
+    }
+}
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct EpochSnapshot<M: ManagedTypeApi> {
+    pub epoch: u64,
+    pub virtual_egld_reserve: BigUint<M>,
+    pub ls_token_supply: BigUint<M>,
+    // Post-fee rewards booked during the epoch
+    pub rewards: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> EpochSnapshot<M> {
+    pub fn exchange_rate(&self) -> BigUint<M> {
+        if self.ls_token_supply == BigUint::zero() {
+            return BigUint::from(INITIAL_EXCHANGE_RATE);
+        }
+
+        &self.virtual_egld_reserve * &BigUint::from(INITIAL_EXCHANGE_RATE) / &self.ls_token_supply
+    }
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProviderYield<M: ManagedTypeApi> {
+    // The stake accrues rewards from the last claim on
+    pub last_claim_epoch: u64,
+    // Rewards claimed and stake times epochs accrued since the window started
+    pub window_start_epoch: u64,
+    pub window_rewards: BigUint<M>,
+    pub window_stake_epochs: BigUint<M>,
+    // Yield of the last full window, in BPS, empty until a window is complete
+    pub realized_apy: Option<u64>,
+}
+
+impl<M: ManagedTypeApi> ProviderYield<M> {
+    pub fn new(epoch: u64) -> Self {
+        ProviderYield {
+            last_claim_epoch: epoch,
+            window_start_epoch: epoch,
+            window_rewards: BigUint::zero(),
+            window_stake_epochs: BigUint::zero(),
+            realized_apy: None,
+        }
+    }
+
+    /// Annualized yield of the current window in BPS, empty without any stake accrued.
+    pub fn window_apy(&self) -> Option<u64> {
+        if self.window_stake_epochs == BigUint::zero() {
+            return None;
+        }
+
+        let window_apy =
+            self.window_rewards.clone() * BPS * EPOCHS_PER_YEAR / &self.window_stake_epochs;
+        Some(window_apy.to_u64().unwrap_or(u64::MAX))
+    }
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProviderLosses<M: ManagedTypeApi> {
+    // Booked on the provider stake and not yet socialized
+    pub unsocialized: BigUint<M>,
+    // Socialized, paid by the insurance reserve and by the xEGLD holders
+    pub covered: BigUint<M>,
+    pub uncovered: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> ProviderLosses<M> {
+    pub fn is_empty(&self) -> bool {
+        self.unsocialized == BigUint::zero()
+            && self.covered == BigUint::zero()
+            && self.uncovered == BigUint::zero()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct EarlyRedemptionConfig {
+    // Fees in BPS, decaying linearly from max_fee to min_fee until the unbond epoch
+    pub max_fee: u64,
+    pub min_fee: u64,
+    pub fee_to_holders: bool,
 }
 
 #[type_abi]
@@ -88,6 +198,54 @@ pub enum State {
     Active,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Copy, Clone, Debug)]
+pub enum PausableAction {
+    Delegate,
+    UnDelegate,
+    Withdraw,
+    DelegatePending,
+    UnDelegatePending,
+    ClaimRewards,
+    InstantDelegation,
+    WithdrawPending,
+    EarlyRedemption,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Role {
+    // Whitelists providers and changes their parameters
+    ProviderCurator,
+    // Changes the scoring configuration
+    ScoringAdmin,
+    // Targets providers in `unDelegatePending`
+    Keeper,
+    // Pauses actions
+    Guardian,
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [
+        Role::ProviderCurator,
+        Role::ScoringAdmin,
+        Role::Keeper,
+        Role::Guardian,
+    ];
+
+    // Bit of the role in the roles bitmap of an address
+    pub fn flag(self) -> u64 {
+        1u64 << (self as u64)
+    }
+}
+
+impl PausableAction {
+    // Bit of the action in the paused actions bitmap
+    pub fn flag(self) -> u64 {
+        1u64 << (self as u64)
+    }
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
 pub struct ScoringConfig {
@@ -99,15 +257,42 @@ pub struct ScoringConfig {
     pub min_apy: u64,
     pub max_apy: u64,
 
+    // Service fee limits, in BPS
+    pub min_service_fee: u64,
+    pub max_service_fee: u64,
+
+    // Uptime limits, in BPS of the nodes not jailed
+    pub min_uptime: u64,
+    pub max_uptime: u64,
+
+    // Provider age limits, in epochs
+    pub min_age: u64,
+    pub max_age: u64,
+
+    // Top-up limits, in EGLD
+    pub min_top_up: u64,
+    pub max_top_up: u64,
+
     // Scoring weights
     pub stake_weight: u64,
     pub apy_weight: u64,
     pub nodes_weight: u64,
+    pub service_fee_weight: u64,
+    pub uptime_weight: u64,
+    pub age_weight: u64,
+    pub top_up_weight: u64,
+
+    // Curves applied to the normalized dimensions
+    pub stake_curve: ScoringCurve,
+    pub apy_curve: ScoringCurve,
+    pub nodes_curve: ScoringCurve,
+    pub service_fee_curve: ScoringCurve,
+    pub uptime_curve: ScoringCurve,
+    pub age_curve: ScoringCurve,
+    pub top_up_curve: ScoringCurve,
 
     // Scoring constants
     pub max_score_per_category: u64,
-    pub exponential_base: u64,
-    pub apy_growth_multiplier: u64,
 }
 
 impl Default for ScoringConfig {
@@ -117,12 +302,245 @@ impl Default for ScoringConfig {
             max_nodes: 100,
             min_apy: 500,
             max_apy: 1000,
+            min_service_fee: 0,
+            max_service_fee: 2_000,
+            min_uptime: 5_000,
+            max_uptime: 10_000,
+            min_age: 0,
+            max_age: 365,
+            min_top_up: 0,
+            max_top_up: 10_000,
             stake_weight: 40,
             apy_weight: 50,
             nodes_weight: 10,
+            service_fee_weight: 0,
+            uptime_weight: 0,
+            age_weight: 0,
+            top_up_weight: 0,
+            stake_curve: ScoringCurve::Power(3),
+            apy_curve: ScoringCurve::Power(4),
+            nodes_curve: ScoringCurve::Power(3),
+            service_fee_curve: ScoringCurve::Linear,
+            uptime_curve: ScoringCurve::Linear,
+            age_curve: ScoringCurve::Linear,
+            top_up_curve: ScoringCurve::Linear,
             max_score_per_category: 1000,
-            exponential_base: 3,
-            apy_growth_multiplier: 4,
         }
     }
 }
+
+// Scoring config stored before the curves and the extra dimensions, converted on upgrade
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct LegacyScoringConfig {
+    pub min_nodes: u64,
+    pub max_nodes: u64,
+    pub min_apy: u64,
+    pub max_apy: u64,
+    pub stake_weight: u64,
+    pub apy_weight: u64,
+    pub nodes_weight: u64,
+    pub max_score_per_category: u64,
+    pub exponential_base: u64,
+    pub apy_growth_multiplier: u64,
+}
+
+impl From<LegacyScoringConfig> for ScoringConfig {
+    fn from(legacy: LegacyScoringConfig) -> Self {
+        ScoringConfig {
+            min_nodes: legacy.min_nodes,
+            max_nodes: legacy.max_nodes,
+            min_apy: legacy.min_apy,
+            max_apy: legacy.max_apy,
+            stake_weight: legacy.stake_weight,
+            apy_weight: legacy.apy_weight,
+            nodes_weight: legacy.nodes_weight,
+            stake_curve: ScoringCurve::Power(legacy.exponential_base),
+            apy_curve: ScoringCurve::Power(legacy.apy_growth_multiplier),
+            nodes_curve: ScoringCurve::Power(legacy.exponential_base),
+            max_score_per_category: legacy.max_score_per_category,
+            ..Default::default()
+        }
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScoringCurve {
+    // The normalized value as is
+    Linear,
+    // The normalized value raised to the exponent, scaled back to BPS
+    Power(u64),
+    // S-curve around the middle of the range, steeper with a higher steepness
+    Logistic(u64),
+    // Full score from the threshold on, in BPS of the range, none below it
+    Step(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct WithdrawTicket<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    // EGLD still owed to the owner, the filled part included until it is claimed
+    pub amount: BigUint<M>,
+    pub filled: BigUint<M>,
+    pub created_epoch: u64,
+}
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct PendingUnbond<M: ManagedTypeApi> {
+    pub unbond_epoch: u64,
+    pub amount: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Invariant {
+    // Contract balance covers the pending, withdrawn, fees, referral, insurance, provider
+    // application seeds and withdraw tickets EGLD
+    EgldBalance,
+    // Staked, in-flight and recorded lost EGLD adds up to the virtual reserve
+    StakedEgld,
+    // Unstaked EGLD of the providers adds up to the pending EGLD for unbond
+    UnbondingEgld,
+    // Unbonding ledger of the providers adds up to their unstaked EGLD
+    UnbondingLedger,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct InvariantCheck<M: ManagedTypeApi> {
+    pub invariant: Invariant,
+    pub expected: BigUint<M>,
+    pub actual: BigUint<M>,
+    // actual - expected
+    pub delta: BigInt<M>,
+    pub holds: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub enum ConfigChange<M: ManagedTypeApi> {
+    Fees(BigUint<M>),
+    UnbondPeriod(u64),
+    AccumulatorContract(ManagedAddress<M>),
+    MaxSelectedProviders(BigUint<M>),
+    MigrationScAddress(ManagedAddress<M>),
+    TimelockDelay(u64),
+    // An empty list sends all the fees to the accumulator again
+    FeeRecipients(ManagedVec<M, FeeRecipient<M>>),
+    FeeMode(FeeMode),
+    // BPS of the protocol fees accrued to the referrers
+    ReferralFeeShare(u64),
+    // BPS of the claimed rewards moved to the insurance reserve
+    InsuranceShare(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ConfigProposal<M: ManagedTypeApi> {
+    pub change: ConfigChange<M>,
+    pub proposed_epoch: u64,
+    pub activation_epoch: u64,
+}
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct FeeRecipient<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub share: u64,                         // BPS of the claimed fees
+    pub endpoint: Option<ManagedBuffer<M>>, // None means a plain transfer
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FeeMode {
+    // Fees are taken out of the rewards as EGLD
+    Egld,
+    // Fees stay staked and are taken as freshly minted LS tokens
+    MintedLs,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Referrer<M: ManagedTypeApi> {
+    pub code: ManagedBuffer<M>,
+    // xEGLD minted with the referrer code since registration
+    pub total_volume: BigUint<M>,
+    // Referred xEGLD not un-delegated yet, the referrer earns fees on it
+    pub attributed_ls: BigUint<M>,
+    // Referral rewards per xEGLD at the last settlement, scaled by 10^18
+    pub reward_per_ls_paid: BigUint<M>,
+    pub rewards: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProviderApplicationConfig<M: ManagedTypeApi> {
+    // Service fee bounds of the delegation contract, in BPS
+    pub min_service_fee: u64,
+    pub max_service_fee: u64,
+    pub min_nodes: u64,
+    // Free room a capped delegation contract must have above its total stake
+    pub min_cap_headroom: BigUint<M>,
+    // Epochs after which anyone can approve an application passing the checks, 0 disables it
+    pub auto_approval_epochs: u64,
+    // Highest APY an application approved without a curator starts with
+    pub max_auto_approval_apy: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProviderApplication<M: ManagedTypeApi> {
+    pub admin_address: ManagedAddress<M>,
+    pub seed: BigUint<M>,
+    pub apy: u64,
+    pub submitted_epoch: u64,
+    // Read from the delegation contract, empty until the checks arrive
+    pub service_fee: Option<u64>,
+    pub total_staked: Option<BigUint<M>>,
+    pub delegation_contract_cap: Option<BigUint<M>>,
+    pub nr_nodes: Option<u64>,
+    pub owner: Option<ManagedAddress<M>>,
+}
+
+#[type_abi]
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct ProviderAllocation<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    // Liquid staked EGLD on the provider, pending callbacks included
+    pub staked: BigUint<M>,
+    // Share of the liquid staked EGLD the scoring config gives the provider
+    pub target: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug, Default,
+)]
+pub struct DelegationFailures {
+    pub total_failures: u64,
+    // Failures since the last successful delegation, they drive the cooldown length
+    pub consecutive_failures: u64,
+    pub last_failure_epoch: u64,
+    // Epoch from which the provider is eligible again, while it is cooling down
+    pub readmission_epoch: u64,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug, Default,
+)]
+pub struct ProviderScoringData {
+    // Read by `syncProvider`, empty until the provider is synced
+    pub service_fee: Option<u64>,
+    pub nr_jailed_nodes: Option<u64>,
+    // Empty for the providers added before it was tracked
+    pub added_epoch: Option<u64>,
+}
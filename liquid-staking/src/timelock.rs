@@ -0,0 +1,207 @@
+multiversx_sc::imports!();
+use crate::{
+    constants::{
+        BPS, DEFAULT_CONFIG_TIMELOCK_DELAY, MAX_FEES, MAX_FEES_CHANGE, MAX_FEE_RECIPIENTS,
+        MAX_INSURANCE_SHARE, MAX_UNBOND_PERIOD, MIN_EPOCHS_BETWEEN_FEES_CHANGES, MIN_UNBOND_PERIOD,
+    },
+    errors::{
+        ERROR_CONFIG_PROPOSAL_NOT_FOUND, ERROR_FEES_CHANGE_COOLDOWN, ERROR_FEES_CHANGE_TOO_LARGE,
+        ERROR_FEES_TOO_HIGH, ERROR_INSURANCE_SHARE_TOO_HIGH, ERROR_INVALID_FEE_SPLIT,
+        ERROR_INVALID_REFERRAL_FEE_SHARE, ERROR_INVALID_UNBOND_PERIOD,
+        ERROR_MAX_CHANGED_DELEGATION_ADDRESSES, ERROR_MIGRATION_NOT_ALLOWED,
+        ERROR_TIMELOCK_DELAY_TOO_SHORT, ERROR_TIMELOCK_NOT_EXPIRED, ERROR_TOO_MANY_FEE_RECIPIENTS,
+    },
+    structs::{ConfigChange, ConfigProposal},
+};
+
+#[multiversx_sc::module]
+pub trait TimelockModule:
+    crate::config::ConfigModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::migrate::MigrateModule
+    + crate::utils::generic::UtilsModule
+    + crate::score::ScoreModule
+    + crate::selection::SelectionModule
+    + crate::liquidity_pool::LiquidityPoolModule
+    + crate::apr::AprModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    /// Proposes a configuration change. It can be executed once the current timelock delay
+    /// has passed, which gives users time to react before fees or the unbond period change.
+    ///
+    /// Returns the id of the proposal.
+    #[only_owner]
+    #[endpoint(proposeConfigChange)]
+    fn propose_config_change(&self, change: ConfigChange<Self::Api>) -> u64 {
+        self.validate_config_change(&change);
+
+        let proposed_epoch = self.blockchain().get_block_epoch();
+        let proposal = ConfigProposal {
+            change,
+            proposed_epoch,
+            activation_epoch: proposed_epoch + self.config_timelock_delay().get(),
+        };
+
+        let proposal_id = self.last_config_proposal_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        self.config_proposal(proposal_id).set(&proposal);
+        self.pending_config_proposals().insert(proposal_id);
+
+        self.config_change_proposed_event(proposal_id, &proposal);
+
+        proposal_id
+    }
+
+    /// Applies a proposed configuration change once its activation epoch is reached.
+    /// Callable by anyone, the change was public for the whole timelock delay.
+    #[endpoint(executeConfigChange)]
+    fn execute_config_change(&self, proposal_id: u64) {
+        let proposal = self.take_config_proposal(proposal_id);
+        require!(
+            self.blockchain().get_block_epoch() >= proposal.activation_epoch,
+            ERROR_TIMELOCK_NOT_EXPIRED
+        );
+
+        // The conditions might have changed since the proposal
+        self.validate_config_change(&proposal.change);
+        self.apply_config_change(&proposal.change);
+
+        self.config_change_executed_event(proposal_id, &proposal.change);
+    }
+
+    #[only_owner]
+    #[endpoint(cancelConfigChange)]
+    fn cancel_config_change(&self, proposal_id: u64) {
+        self.take_config_proposal(proposal_id);
+
+        self.config_change_cancelled_event(proposal_id);
+    }
+
+    #[view(getConfigProposal)]
+    fn get_config_proposal(&self, proposal_id: u64) -> ConfigProposal<Self::Api> {
+        let proposal_mapper = self.config_proposal(proposal_id);
+        require!(!proposal_mapper.is_empty(), ERROR_CONFIG_PROPOSAL_NOT_FOUND);
+
+        proposal_mapper.get()
+    }
+
+    /// Returns the proposals neither executed nor cancelled, in proposal order.
+    #[view(getPendingConfigProposals)]
+    fn get_pending_config_proposals(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<u64, ConfigProposal<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for proposal_id in self.pending_config_proposals().iter() {
+            result.push((proposal_id, self.config_proposal(proposal_id).get()).into());
+        }
+
+        result
+    }
+
+    fn take_config_proposal(&self, proposal_id: u64) -> ConfigProposal<Self::Api> {
+        let proposal_mapper = self.config_proposal(proposal_id);
+        require!(!proposal_mapper.is_empty(), ERROR_CONFIG_PROPOSAL_NOT_FOUND);
+
+        let proposal = proposal_mapper.take();
+        self.pending_config_proposals().remove(&proposal_id);
+
+        proposal
+    }
+
+    fn validate_config_change(&self, change: &ConfigChange<Self::Api>) {
+        match change {
+            ConfigChange::Fees(fees) => {
+                require!(*fees <= MAX_FEES, ERROR_FEES_TOO_HIGH);
+
+                let current_fees = self.fees().get();
+                let fees_delta = if *fees > current_fees {
+                    fees - &current_fees
+                } else {
+                    &current_fees - fees
+                };
+                require!(fees_delta <= MAX_FEES_CHANGE, ERROR_FEES_CHANGE_TOO_LARGE);
+            }
+            ConfigChange::UnbondPeriod(period) => {
+                // Positions must not mature before the providers release the EGLD
+                require!(
+                    (MIN_UNBOND_PERIOD..=MAX_UNBOND_PERIOD).contains(period),
+                    ERROR_INVALID_UNBOND_PERIOD
+                );
+            }
+            ConfigChange::MaxSelectedProviders(number) => {
+                require!(*number >= 1u64, ERROR_MAX_CHANGED_DELEGATION_ADDRESSES);
+            }
+            ConfigChange::TimelockDelay(delay) => {
+                // A shorter delay would let the next proposals skip the notice period
+                require!(
+                    *delay >= DEFAULT_CONFIG_TIMELOCK_DELAY,
+                    ERROR_TIMELOCK_DELAY_TOO_SHORT
+                );
+            }
+            ConfigChange::MigrationScAddress(address) => {
+                // Double check that the address is a smart contract
+                require!(
+                    self.blockchain().is_smart_contract(address),
+                    ERROR_MIGRATION_NOT_ALLOWED
+                );
+            }
+            ConfigChange::FeeRecipients(recipients) => {
+                require!(
+                    recipients.len() <= MAX_FEE_RECIPIENTS,
+                    ERROR_TOO_MANY_FEE_RECIPIENTS
+                );
+
+                let mut total_share = 0u64;
+                for recipient in recipients.iter() {
+                    require!(recipient.share > 0, ERROR_INVALID_FEE_SPLIT);
+                    total_share += recipient.share;
+                }
+                require!(
+                    recipients.is_empty() || total_share == BPS,
+                    ERROR_INVALID_FEE_SPLIT
+                );
+            }
+            ConfigChange::ReferralFeeShare(share) => {
+                require!(*share <= BPS, ERROR_INVALID_REFERRAL_FEE_SHARE);
+            }
+            ConfigChange::InsuranceShare(share) => {
+                require!(
+                    *share <= MAX_INSURANCE_SHARE,
+                    ERROR_INSURANCE_SHARE_TOO_HIGH
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn apply_config_change(&self, change: &ConfigChange<Self::Api>) {
+        match change {
+            ConfigChange::Fees(fees) => self.apply_fees_change(fees),
+            ConfigChange::UnbondPeriod(period) => self.unbond_period().set(period),
+            ConfigChange::AccumulatorContract(address) => self.accumulator_contract().set(address),
+            ConfigChange::MaxSelectedProviders(number) => self.max_selected_providers().set(number),
+            ConfigChange::MigrationScAddress(address) => self.migration_sc_address().set(address),
+            ConfigChange::TimelockDelay(delay) => self.config_timelock_delay().set(delay),
+            ConfigChange::FeeRecipients(recipients) => self.fee_recipients().set(recipients),
+            ConfigChange::FeeMode(mode) => self.fee_mode().set(mode),
+            ConfigChange::ReferralFeeShare(share) => self.referral_fee_share().set(share),
+            ConfigChange::InsuranceShare(share) => self.insurance_share().set(share),
+        }
+    }
+
+    fn apply_fees_change(&self, fees: &BigUint) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let last_change_mapper = self.last_fees_change_epoch();
+        require!(
+            last_change_mapper.is_empty()
+                || current_epoch >= last_change_mapper.get() + MIN_EPOCHS_BETWEEN_FEES_CHANGES,
+            ERROR_FEES_CHANGE_COOLDOWN
+        );
+
+        self.fees().set(fees);
+        last_change_mapper.set(current_epoch);
+    }
+}
@@ -1,7 +1,7 @@
 multiversx_sc::imports!();
 use crate::{
-    StorageCache, ERROR_BAD_PAYMENT_AMOUNT, ERROR_INSUFFICIENT_UNSTAKE_PENDING_EGLD,
-    MIN_EGLD_TO_DELEGATE,
+    structs::PausableAction, StorageCache, ERROR_BAD_PAYMENT_AMOUNT, ERROR_INSUFFICIENT_LS_OUT,
+    ERROR_INSUFFICIENT_UNSTAKE_PENDING_EGLD, MIN_EGLD_TO_DELEGATE,
 };
 
 #[multiversx_sc::module]
@@ -13,14 +13,46 @@ pub trait DelegateUtilsModule:
     + crate::score::ScoreModule
     + crate::selection::SelectionModule
     + crate::liquidity_pool::LiquidityPoolModule
+    + crate::utils::un_delegation::UnDelegateUtilsModule
+    + crate::withdraw_queue::WithdrawQueueModule
+    + crate::referral::ReferralModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
+    /// Delegates the paid EGLD to the pool for `receiver` and attributes the minted xEGLD
+    /// to the referrer of `referrer_code`, when one is given.
+    fn delegate_to_pool(
+        &self,
+        receiver: &ManagedAddress,
+        min_ls_out: &BigUint,
+        referrer_code: OptionalValue<ManagedBuffer>,
+    ) -> EsdtTokenPayment {
+        let mut storage_cache = StorageCache::new(self);
+
+        let payment = self.call_value().egld().clone_value();
+
+        self.validate_delegate_conditions(&mut storage_cache, &payment);
+
+        let referrer = self.resolve_referrer(receiver, referrer_code);
+
+        let (pending, extra) =
+            self.get_action_amount(&storage_cache.pending_egld_for_unstake, &payment);
+
+        let user_payment =
+            self.process_delegation(&mut storage_cache, &pending, &extra, receiver, min_ls_out);
+        if let Some(referrer) = referrer {
+            self.attribute_referral(receiver, &referrer, &user_payment.amount);
+        }
+
+        user_payment
+    }
+
     fn process_delegation(
         &self,
         storage_cache: &mut StorageCache<Self>,
         egld_from_pending_used: &BigUint,
         egld_to_add_liquidity: &BigUint,
-        caller: &ManagedAddress,
+        receiver: &ManagedAddress,
+        min_ls_out: &BigUint,
     ) -> EsdtTokenPayment {
         let mut final_amount_to_mint = BigUint::zero();
 
@@ -31,6 +63,7 @@ pub trait DelegateUtilsModule:
                 egld_from_pending_used,
                 &mut final_amount_to_mint,
             );
+            self.fill_withdraw_tickets(storage_cache);
         }
 
         // Increase the pending EGLD by the amount left to be staked if any
@@ -48,15 +81,20 @@ pub trait DelegateUtilsModule:
 
         // Add the liquidity to the pool and mint the corresponding xEGLD
         let ls_amount = self.pool_add_liquidity(&final_amount_to_mint, storage_cache);
+
+        // Slippage protection, zero means no minimum was requested
+        require!(&ls_amount >= min_ls_out, ERROR_INSUFFICIENT_LS_OUT);
+
         let user_payment = self.mint_ls_token(ls_amount);
 
-        // Send the final amount to the user, including the xEGLD from pending redemption if any and the fresh minted xEGLD if any
-        self.tx().to(caller).esdt(user_payment.clone()).transfer();
+        // Send the final amount to the receiver, including the xEGLD from pending redemption if any and the fresh minted xEGLD if any
+        self.tx().to(receiver).esdt(user_payment.clone()).transfer();
         // Emit the add liquidity event
         self.emit_add_liquidity_event(
             storage_cache,
             &(egld_to_add_liquidity + egld_from_pending_used),
-            Some(caller.clone()),
+            None,
+            Some(receiver.clone()),
         );
 
         user_payment
@@ -102,6 +140,7 @@ pub trait DelegateUtilsModule:
         amount: &BigUint,
     ) {
         self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::Delegate);
 
         require!(amount > &BigUint::zero(), ERROR_BAD_PAYMENT_AMOUNT);
     }
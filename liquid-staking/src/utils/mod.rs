@@ -1,3 +1,3 @@
 pub mod delegate;
+pub mod generic;
 pub mod un_delegation;
-pub mod generic;
\ No newline at end of file
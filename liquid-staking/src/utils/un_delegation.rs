@@ -1,8 +1,11 @@
 multiversx_sc::imports!();
 use crate::{
-    structs::UnstakeTokenAttributes, StorageCache, ERROR_BAD_PAYMENT_AMOUNT,
-    ERROR_BAD_PAYMENT_TOKEN, ERROR_INSUFFICIENT_PENDING_EGLD,
-    ERROR_INSUFFICIENT_UNSTAKE_PENDING_EGLD, ERROR_LS_TOKEN_NOT_ISSUED, MIN_EGLD_TO_DELEGATE,
+    structs::{PausableAction, UnstakeTokenAttributes},
+    StorageCache, ERROR_BAD_PAYMENT_AMOUNT, ERROR_BAD_PAYMENT_TOKEN,
+    ERROR_INSTANT_UNDELEGATE_NOT_POSSIBLE, ERROR_INSUFFICIENT_EGLD_OUT,
+    ERROR_INSUFFICIENT_PENDING_EGLD, ERROR_INSUFFICIENT_UNBONDED_AMOUNT,
+    ERROR_INSUFFICIENT_UNSTAKE_PENDING_EGLD, ERROR_INVALID_RECEIVER, ERROR_LS_TOKEN_NOT_ISSUED,
+    ERROR_UNSTAKE_PERIOD_NOT_PASSED, ERROR_WITHDRAW_QUEUE_NOT_EMPTY, MIN_EGLD_TO_DELEGATE,
 };
 
 #[multiversx_sc::module]
@@ -14,30 +17,120 @@ pub trait UnDelegateUtilsModule:
     + crate::score::ScoreModule
     + crate::selection::SelectionModule
     + crate::liquidity_pool::LiquidityPoolModule
+    + crate::referral::ReferralModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
+    fn un_delegate_to(
+        &self,
+        storage_cache: &mut StorageCache<Self>,
+        receiver: &ManagedAddress,
+        min_egld_out: OptionalValue<BigUint>,
+        instant_only: OptionalValue<bool>,
+    ) {
+        let payment = self.call_value().single_esdt();
+
+        self.validate_undelegate_conditions(storage_cache, &payment);
+
+        let unstaked_egld = self.pool_remove_liquidity(&payment.amount, storage_cache);
+        self.burn_ls_token(&payment.amount);
+        self.release_referral(&self.blockchain().get_caller(), &payment.amount);
+
+        let (instant, undelegate) =
+            self.get_action_amount(&storage_cache.pending_egld, &unstaked_egld);
+
+        self.validate_undelegate_limits(&unstaked_egld, &undelegate, min_egld_out, instant_only);
+
+        self.process_un_delegation(storage_cache, &instant, &undelegate, receiver);
+    }
+
+    fn withdraw_to(&self, storage_cache: &mut StorageCache<Self>, receiver: &ManagedAddress) {
+        self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::Withdraw);
+        let mut available = self.get_direct_withdraw_available(storage_cache);
+
+        let payments = self.call_value().all_esdt_transfers();
+        let unstake_token_id = self.unstake_token().get_token_id();
+        let current_epoch = self.blockchain().get_block_epoch();
+
+        let mut to_send = BigUint::zero();
+
+        for payment in payments.clone().into_iter() {
+            require!(
+                payment.token_identifier == unstake_token_id,
+                ERROR_BAD_PAYMENT_TOKEN
+            );
+
+            require!(payment.amount > BigUint::zero(), ERROR_BAD_PAYMENT_AMOUNT);
+
+            let unstake_token_attributes: UnstakeTokenAttributes = self
+                .unstake_token()
+                .get_token_attributes(payment.token_nonce);
+
+            require!(
+                current_epoch >= unstake_token_attributes.unbond_epoch,
+                ERROR_UNSTAKE_PERIOD_NOT_PASSED
+            );
+
+            if available >= payment.amount {
+                self.burn_unstake_tokens(payment.token_nonce, &payment.amount);
+
+                available -= &payment.amount;
+                to_send += payment.amount;
+            } else if available > BigUint::zero() {
+                // In this case the required amount of the MetaESDT is higher than the available amount
+                // This case can happen only when the amount from the providers didn't arrive yet in the protocol
+                // In this case we partially give to the receiver the available amount and return the remaining MetaESDT to the receiver
+                self.burn_unstake_tokens(payment.token_nonce, &available);
+
+                let remaining_amount = payment.amount - &available;
+
+                // Send the remaining amount to the receiver
+                self.tx()
+                    .to(receiver)
+                    .single_esdt(
+                        &payment.token_identifier,
+                        payment.token_nonce,
+                        &remaining_amount,
+                    )
+                    .transfer();
+
+                // Send the amount to the receiver
+                to_send += &available;
+
+                // Reset the available amount to 0
+                available = BigUint::zero();
+            } else {
+                sc_panic!(ERROR_INSUFFICIENT_UNBONDED_AMOUNT);
+            }
+        }
+
+        storage_cache.total_withdrawn_egld -= &to_send;
+        self.tx().to(receiver).egld(&to_send).transfer();
+        self.emit_general_liquidity_event_for(storage_cache, receiver);
+    }
+
     fn process_un_delegation(
         &self,
         storage_cache: &mut StorageCache<Self>,
         egld_from_pending_used: &BigUint,
         egld_to_remove_liquidity: &BigUint,
+        receiver: &ManagedAddress,
     ) {
-        let caller = self.blockchain().get_caller();
+        self.process_instant_redemption(storage_cache, receiver, egld_from_pending_used);
 
-        self.process_instant_redemption(storage_cache, &caller, egld_from_pending_used);
-
-        self.undelegate_amount(storage_cache, egld_to_remove_liquidity, &caller);
+        self.undelegate_amount(storage_cache, egld_to_remove_liquidity, receiver);
 
         self.emit_remove_liquidity_event(
             storage_cache,
             &(egld_to_remove_liquidity + egld_from_pending_used),
+            receiver,
         );
     }
 
     fn process_instant_redemption(
         &self,
         storage_cache: &mut StorageCache<Self>,
-        caller: &ManagedAddress,
+        receiver: &ManagedAddress,
         instant_amount: &BigUint,
     ) {
         if *instant_amount > BigUint::zero() {
@@ -49,7 +142,7 @@ pub trait UnDelegateUtilsModule:
                 ERROR_INSUFFICIENT_PENDING_EGLD
             );
 
-            self.tx().to(caller).egld(instant_amount).transfer();
+            self.tx().to(receiver).egld(instant_amount).transfer();
         }
     }
 
@@ -59,6 +152,7 @@ pub trait UnDelegateUtilsModule:
         payment: &EsdtTokenPayment<Self::Api>,
     ) {
         self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::UnDelegate);
 
         require!(
             storage_cache.ls_token_id.is_valid_esdt_identifier(),
@@ -73,11 +167,61 @@ pub trait UnDelegateUtilsModule:
         require!(payment.amount > BigUint::zero(), ERROR_BAD_PAYMENT_AMOUNT);
     }
 
+    // Queued tickets have priority over the unbonded EGLD, only what is left over after
+    // their outstanding amount can be withdrawn directly
+    fn get_direct_withdraw_available(&self, storage_cache: &StorageCache<Self>) -> BigUint {
+        let outstanding = self.withdraw_queue_outstanding().get();
+        if storage_cache.total_withdrawn_egld <= outstanding {
+            require!(outstanding == 0, ERROR_WITHDRAW_QUEUE_NOT_EMPTY);
+            return BigUint::zero();
+        }
+
+        &storage_cache.total_withdrawn_egld - &outstanding
+    }
+
+    fn validate_receiver(&self, receiver: &ManagedAddress) {
+        require!(!receiver.is_zero(), ERROR_INVALID_RECEIVER);
+    }
+
+    fn get_unstake_token_attributes(
+        &self,
+        payment: &EsdtTokenPayment<Self::Api>,
+    ) -> UnstakeTokenAttributes {
+        require!(
+            payment.token_identifier == self.unstake_token().get_token_id(),
+            ERROR_BAD_PAYMENT_TOKEN
+        );
+        require!(payment.amount > BigUint::zero(), ERROR_BAD_PAYMENT_AMOUNT);
+
+        self.unstake_token()
+            .get_token_attributes(payment.token_nonce)
+    }
+
+    fn validate_undelegate_limits(
+        &self,
+        unstaked_egld: &BigUint,
+        egld_to_unstake: &BigUint,
+        min_egld_out: OptionalValue<BigUint>,
+        instant_only: OptionalValue<bool>,
+    ) {
+        if let Some(min_egld_out) = min_egld_out.into_option() {
+            require!(unstaked_egld >= &min_egld_out, ERROR_INSUFFICIENT_EGLD_OUT);
+        }
+
+        // Instant only requests must not end up with an unstake MetaESDT
+        if instant_only.into_option().unwrap_or(false) {
+            require!(
+                egld_to_unstake == &BigUint::zero(),
+                ERROR_INSTANT_UNDELEGATE_NOT_POSSIBLE
+            );
+        }
+    }
+
     fn undelegate_amount(
         &self,
         storage_cache: &mut StorageCache<Self>,
         egld_to_unstake: &BigUint,
-        caller: &ManagedAddress,
+        receiver: &ManagedAddress,
     ) {
         if *egld_to_unstake > BigUint::zero() {
             storage_cache.pending_egld_for_unstake += egld_to_unstake;
@@ -104,7 +248,7 @@ pub trait UnDelegateUtilsModule:
             );
 
             self.tx()
-                .to(caller)
+                .to(receiver)
                 .single_esdt(
                     &user_payment.token_identifier,
                     user_payment.token_nonce,
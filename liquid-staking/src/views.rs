@@ -1,5 +1,15 @@
 multiversx_sc::imports!();
-use crate::StorageCache;
+use crate::{
+    constants::{ACCUMULATOR_DEPOSIT_ENDPOINT, BPS, INITIAL_EXCHANGE_RATE},
+    errors::{
+        ERROR_INVALID_TWAP_WINDOW, ERROR_NOT_ENOUGH_RATE_SNAPSHOTS, ERROR_NO_RATE_OBSERVATIONS,
+        ERROR_TWAP_WINDOW_TOO_LONG,
+    },
+    structs::{
+        DelegationFailures, FeeRecipient, Invariant, InvariantCheck, PendingUnbond, RateObservation,
+    },
+    StorageCache,
+};
 
 #[multiversx_sc::module]
 
@@ -24,8 +34,6 @@ pub trait ViewsModule:
     fn get_exchange_rate(&self) -> BigUint {
         let ls_token_supply = self.ls_token_supply().get();
         let virtual_egld_reserve = self.virtual_egld_reserve().get();
-        // 1 EGLD = 10^18 atomic units
-        const INITIAL_EXCHANGE_RATE: u64 = 1_000_000_000_000_000_000;
 
         // When no liquidity, 1 LS token = 1 EGLD
         if ls_token_supply == BigUint::zero() {
@@ -38,6 +46,62 @@ pub trait ViewsModule:
         &virtual_egld_reserve * &BigUint::from(INITIAL_EXCHANGE_RATE) / &ls_token_supply
     }
 
+    /// Time-weighted average exchange rate over the last `window` seconds, scaled by 10^18.
+    /// Inside the history covered by the epoch snapshots, the rate accumulator is linearly
+    /// interpolated between the two snapshots surrounding the start of the window.
+    #[view(getTwapExchangeRate)]
+    fn get_twap_exchange_rate(&self, window: u64) -> BigUint {
+        require!(window > 0, ERROR_INVALID_TWAP_WINDOW);
+        require!(
+            !self.last_rate_observation().is_empty(),
+            ERROR_NO_RATE_OBSERVATIONS
+        );
+
+        let now = self.blockchain().get_block_timestamp();
+        require!(window <= now, ERROR_TWAP_WINDOW_TOO_LONG);
+
+        let last_observation = self.last_rate_observation().get();
+        let window_start = now - window;
+
+        let cumulative_start = if window_start >= last_observation.timestamp {
+            last_observation.cumulative_at(window_start)
+        } else {
+            self.interpolate_rate_cumulative(window_start, &last_observation)
+        };
+
+        (last_observation.cumulative_at(now) - cumulative_start) / window
+    }
+
+    /// Returns the last `count` epoch snapshots, ordered from the oldest to the newest.
+    #[view(getRateSnapshots)]
+    fn get_rate_snapshots(&self, count: usize) -> MultiValueEncoded<RateObservation<Self::Api>> {
+        let snapshots = self.get_ordered_rate_snapshots();
+        let len = snapshots.len();
+        let skip = len.saturating_sub(count);
+
+        let mut result = MultiValueEncoded::new();
+        for snapshot in snapshots.iter().skip(skip) {
+            result.push(snapshot.clone());
+        }
+        result
+    }
+
+    /// Growth of the exchange rate between the two most recent epoch snapshots.
+    /// Returns the epochs of both snapshots and the ratio newer / older, scaled by 10^18.
+    #[view(getExchangeRateGrowth)]
+    fn get_exchange_rate_growth(&self) -> MultiValue3<u64, u64, BigUint> {
+        let snapshots = self.get_ordered_rate_snapshots();
+        let len = snapshots.len();
+        require!(len >= 2, ERROR_NOT_ENOUGH_RATE_SNAPSHOTS);
+
+        let from = snapshots.get(len - 2).clone();
+        let to = snapshots.get(len - 1).clone();
+        let growth =
+            &to.exchange_rate * &BigUint::from(INITIAL_EXCHANGE_RATE) / &from.exchange_rate;
+
+        (from.epoch, to.epoch, growth).into()
+    }
+
     #[view(getDelegationContractStakedAmount)]
     fn get_delegation_contract_staked_amount(
         &self,
@@ -55,4 +119,335 @@ pub trait ViewsModule:
         let delegation_contract_data = self.delegation_contract_data(delegation_address).get();
         delegation_contract_data.total_unstaked_from_ls_contract
     }
+
+    /// Returns the un-delegations sent to the provider and not yet withdrawn, oldest first.
+    #[view(getProviderPendingUnbonds)]
+    fn get_provider_pending_unbonds(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> MultiValueEncoded<PendingUnbond<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for pending_unbond in self
+            .provider_pending_unbonds(delegation_address)
+            .get()
+            .iter()
+        {
+            result.push(pending_unbond.clone());
+        }
+
+        result
+    }
+
+    /// Returns the unbonding EGLD of the provider split in the amount that can be
+    /// withdrawn in the current epoch and the amount still locked.
+    #[view(getProviderUnbondingAmounts)]
+    fn get_provider_unbonding_amounts(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> MultiValue2<BigUint, BigUint> {
+        let current_epoch = self.blockchain().get_block_epoch();
+
+        self.split_unbonding_amounts(delegation_address, current_epoch)
+            .into()
+    }
+
+    /// Returns the failed delegations of the provider and, while it is cooling down after
+    /// one, the epoch it becomes eligible again.
+    #[view(getProviderDelegationFailures)]
+    fn get_provider_delegation_failures(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> DelegationFailures {
+        let failures_mapper = self.delegation_failures(delegation_address);
+        if failures_mapper.is_empty() {
+            return DelegationFailures::default();
+        }
+
+        failures_mapper.get()
+    }
+
+    /// Returns the claimable and locked unbonding EGLD of every provider with un-delegations
+    /// not yet withdrawn.
+    #[view(getUnbondingLedger)]
+    fn get_unbonding_ledger(
+        &self,
+    ) -> MultiValueEncoded<MultiValue3<ManagedAddress, BigUint, BigUint>> {
+        let current_epoch = self.blockchain().get_block_epoch();
+
+        let mut result = MultiValueEncoded::new();
+        for provider in self.un_delegation_addresses_list().iter() {
+            if self.provider_pending_unbonds(&provider).is_empty() {
+                continue;
+            }
+
+            let (claimable, locked) = self.split_unbonding_amounts(&provider, current_epoch);
+            result.push((provider, claimable, locked).into());
+        }
+
+        result
+    }
+
+    /// Returns the providers that `withdrawPendingBatch` would currently withdraw from.
+    #[view(getWithdrawableProviders)]
+    fn get_withdrawable_providers(&self) -> MultiValueEncoded<ManagedAddress> {
+        let current_epoch = self.blockchain().get_block_epoch();
+
+        let mut result = MultiValueEncoded::new();
+        for provider in self.un_delegation_addresses_list().iter() {
+            if self.is_provider_withdrawable(&provider, current_epoch) {
+                result.push(provider);
+            }
+        }
+
+        result
+    }
+
+    /// Returns the EGLD and LS token fees each fee recipient gets on the next `claimFees`.
+    #[view(getAccruedFees)]
+    fn get_accrued_fees(&self) -> MultiValueEncoded<MultiValue3<ManagedAddress, BigUint, BigUint>> {
+        let (recipients, egld_amounts) = self.split_fees(&self.fees_reserve().get());
+        let (_, ls_amounts) = self.split_fees(&self.ls_fees_reserve().get());
+
+        let mut result = MultiValueEncoded::new();
+        for (index, recipient) in recipients.iter().enumerate() {
+            result.push(
+                (
+                    recipient.address.clone(),
+                    egld_amounts.get(index).clone_value(),
+                    ls_amounts.get(index).clone_value(),
+                )
+                    .into(),
+            );
+        }
+
+        result
+    }
+
+    /// Recomputes the accounting relationships of the protocol from storage, iterating every
+    /// whitelisted provider, and returns each invariant with its expected and actual amounts.
+    /// Liquidity added through `migrate` has no staked counterpart and shows up as a
+    /// negative `StakedEgld` delta.
+    #[view(checkInvariants)]
+    fn check_invariants(&self) -> MultiValueEncoded<InvariantCheck<Self::Api>> {
+        let storage_cache = StorageCache::new(self);
+
+        let mut total_staked = BigUint::zero();
+        let mut total_unstaked = BigUint::zero();
+        let mut pending_staking = BigUint::zero();
+        let mut pending_unstaking = BigUint::zero();
+        let mut total_ledger = BigUint::zero();
+
+        let delegation_addresses = self.delegation_addresses_list();
+        let un_delegation_only = self
+            .un_delegation_addresses_list()
+            .iter()
+            .filter(|address| !delegation_addresses.contains(address));
+        for provider in delegation_addresses.iter().chain(un_delegation_only) {
+            let contract_data_mapper = self.delegation_contract_data(&provider);
+            if contract_data_mapper.is_empty() {
+                continue;
+            }
+
+            let contract_data = contract_data_mapper.get();
+            total_staked += &contract_data.total_staked_from_ls_contract;
+            total_unstaked += &contract_data.total_unstaked_from_ls_contract;
+            pending_staking += &contract_data.pending_staking_callback_amount;
+            pending_unstaking += &contract_data.pending_unstaking_callback_amount;
+
+            for pending_unbond in self.provider_pending_unbonds(&provider).get().iter() {
+                total_ledger += &pending_unbond.amount;
+            }
+        }
+
+        let mut result = MultiValueEncoded::new();
+
+        let egld_balance = self
+            .blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        let egld_owed = &storage_cache.pending_egld
+            + &storage_cache.total_withdrawn_egld
+            + &storage_cache.fees_reserve
+            + &self.referral_rewards_reserve().get()
+            + &self.insurance_reserve().get()
+            + &self.provider_applications_reserve().get()
+            + &self.withdraw_tickets_reserve().get()
+            + &self.rebalance_pending_egld().get();
+        // Donations are allowed, only a shortfall breaks the invariant
+        let holds = egld_balance >= egld_owed;
+        result.push(self.build_invariant_check(
+            Invariant::EgldBalance,
+            egld_owed,
+            egld_balance,
+            holds,
+        ));
+
+        let staked_expected = &storage_cache.virtual_egld_reserve
+            + &storage_cache.pending_egld_for_unstake
+            + &pending_unstaking;
+        let staked_actual = total_staked
+            + &pending_staking
+            + &storage_cache.pending_egld
+            + &self.pending_egld_for_restake().get()
+            + &self.evacuating_egld().get()
+            + &self.rebalance_pending_egld().get()
+            + &self.recorded_losses().get();
+        let holds = staked_actual == staked_expected;
+        result.push(self.build_invariant_check(
+            Invariant::StakedEgld,
+            staked_expected,
+            staked_actual,
+            holds,
+        ));
+
+        let holds = total_unstaked == storage_cache.pending_egld_for_unbond;
+        result.push(self.build_invariant_check(
+            Invariant::UnbondingEgld,
+            storage_cache.pending_egld_for_unbond.clone(),
+            total_unstaked.clone(),
+            holds,
+        ));
+
+        let holds = total_ledger == total_unstaked;
+        result.push(self.build_invariant_check(
+            Invariant::UnbondingLedger,
+            total_unstaked,
+            total_ledger,
+            holds,
+        ));
+
+        result
+    }
+
+    fn build_invariant_check(
+        &self,
+        invariant: Invariant,
+        expected: BigUint,
+        actual: BigUint,
+        holds: bool,
+    ) -> InvariantCheck<Self::Api> {
+        let delta = BigInt::from(actual.clone()) - BigInt::from(expected.clone());
+
+        InvariantCheck {
+            invariant,
+            expected,
+            actual,
+            delta,
+            holds,
+        }
+    }
+
+    // Only tracked un-delegations are considered, calling withdraw for anything else would fail
+    fn is_provider_withdrawable(&self, provider: &ManagedAddress, current_epoch: u64) -> bool {
+        let contract_data_mapper = self.delegation_contract_data(provider);
+        if contract_data_mapper.is_empty() {
+            return false;
+        }
+
+        // The seed of a retired provider is the last un-delegation left on it
+        let seed_unbond_epoch_mapper = self.provider_seed_unbond_epoch(provider);
+        if !seed_unbond_epoch_mapper.is_empty() {
+            return seed_unbond_epoch_mapper.get() <= current_epoch;
+        }
+
+        if contract_data_mapper.get().total_unstaked_from_ls_contract == BigUint::zero() {
+            return false;
+        }
+
+        let (claimable, _) = self.split_unbonding_amounts(provider, current_epoch);
+        claimable > BigUint::zero()
+    }
+
+    fn split_unbonding_amounts(
+        &self,
+        provider: &ManagedAddress,
+        current_epoch: u64,
+    ) -> (BigUint, BigUint) {
+        let mut claimable = BigUint::zero();
+        let mut locked = BigUint::zero();
+        for pending_unbond in self.provider_pending_unbonds(provider).get().iter() {
+            if pending_unbond.unbond_epoch <= current_epoch {
+                claimable += &pending_unbond.amount;
+            } else {
+                locked += &pending_unbond.amount;
+            }
+        }
+
+        (claimable, locked)
+    }
+
+    fn get_ordered_rate_snapshots(&self) -> ManagedVec<RateObservation<Self::Api>> {
+        let snapshots = self.rate_snapshots();
+        let len = snapshots.len();
+        // The cursor stays at 0 until the ring buffer is full
+        let oldest = self.rate_snapshots_cursor().get();
+
+        let mut ordered = ManagedVec::new();
+        for offset in 0..len {
+            ordered.push(snapshots.get((oldest + offset) % len + 1));
+        }
+        ordered
+    }
+
+    /// Splits the fees between the fee recipients by their share, the last one getting the
+    /// rounding dust. Without a configured split, the accumulator gets all the fees.
+    fn split_fees(
+        &self,
+        total_fees: &BigUint,
+    ) -> (ManagedVec<FeeRecipient<Self::Api>>, ManagedVec<BigUint>) {
+        let mut recipients = self.fee_recipients().get();
+        if recipients.is_empty() {
+            recipients.push(FeeRecipient {
+                address: self.accumulator_contract().get(),
+                share: BPS,
+                endpoint: Some(ManagedBuffer::from(ACCUMULATOR_DEPOSIT_ENDPOINT)),
+            });
+        }
+
+        let mut amounts = ManagedVec::new();
+        let mut remaining = total_fees.clone();
+        let last_index = recipients.len() - 1;
+        for (index, recipient) in recipients.iter().enumerate() {
+            let amount = if index == last_index {
+                remaining.clone()
+            } else {
+                total_fees * &BigUint::from(recipient.share) / BPS
+            };
+            remaining -= &amount;
+            amounts.push(amount);
+        }
+
+        (recipients, amounts)
+    }
+
+    fn interpolate_rate_cumulative(
+        &self,
+        timestamp: u64,
+        last_observation: &RateObservation<Self::Api>,
+    ) -> BigUint {
+        let snapshots = self.get_ordered_rate_snapshots();
+        let len = snapshots.len();
+
+        // Walk back to the newest snapshot taken at or before the timestamp
+        let mut index = len;
+        while index > 0 && snapshots.get(index - 1).timestamp > timestamp {
+            index -= 1;
+        }
+        require!(index > 0, ERROR_TWAP_WINDOW_TOO_LONG);
+
+        let before = snapshots.get(index - 1).clone();
+        let after = if index < len {
+            snapshots.get(index).clone()
+        } else {
+            last_observation.clone()
+        };
+
+        if after.timestamp == before.timestamp {
+            return before.rate_cumulative;
+        }
+
+        let cumulative_delta = &after.rate_cumulative - &before.rate_cumulative;
+        &before.rate_cumulative
+            + &(cumulative_delta * (timestamp - before.timestamp)
+                / (after.timestamp - before.timestamp))
+    }
 }
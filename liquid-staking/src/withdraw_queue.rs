@@ -0,0 +1,201 @@
+multiversx_sc::imports!();
+use crate::{
+    constants::{MAX_WITHDRAW_TICKETS_FILLED, MIN_EGLD_TO_DELEGATE},
+    errors::{
+        ERROR_BAD_PAYMENT_AMOUNT, ERROR_NOTHING_TO_CLAIM, ERROR_NOT_WITHDRAW_TICKET_OWNER,
+        ERROR_UNSTAKE_PERIOD_NOT_PASSED, ERROR_WITHDRAW_TICKET_NOT_FOUND,
+        ERROR_WITHDRAW_TICKET_TOO_SMALL,
+    },
+    structs::{PausableAction, WithdrawTicket},
+    StorageCache,
+};
+
+#[multiversx_sc::module]
+pub trait WithdrawQueueModule:
+    crate::config::ConfigModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::score::ScoreModule
+    + crate::selection::SelectionModule
+    + crate::utils::generic::UtilsModule
+    + crate::liquidity_pool::LiquidityPoolModule
+    + crate::utils::un_delegation::UnDelegateUtilsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    /// Burns unbonded unstake positions and registers a withdraw ticket for their EGLD.
+    /// Tickets are filled in registration order as the unbonded EGLD arrives from the
+    /// providers, instead of paying whoever calls `withdraw` first.
+    ///
+    /// Returns the id of the registered ticket.
+    #[payable("*")]
+    #[endpoint(requestWithdraw)]
+    fn request_withdraw(&self) -> u64 {
+        let mut storage_cache = StorageCache::new(self);
+        self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::Withdraw);
+
+        let payments = self.call_value().all_esdt_transfers();
+        require!(!payments.is_empty(), ERROR_BAD_PAYMENT_AMOUNT);
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut amount = BigUint::zero();
+        for payment in payments.clone().into_iter() {
+            let unstake_token_attributes = self.get_unstake_token_attributes(&payment);
+            require!(
+                current_epoch >= unstake_token_attributes.unbond_epoch,
+                ERROR_UNSTAKE_PERIOD_NOT_PASSED
+            );
+
+            self.burn_unstake_tokens(payment.token_nonce, &payment.amount);
+            amount += &payment.amount;
+        }
+        // Dust tickets would block `withdraw` for everyone at no cost
+        require!(
+            amount >= MIN_EGLD_TO_DELEGATE,
+            ERROR_WITHDRAW_TICKET_TOO_SMALL
+        );
+
+        let caller = self.blockchain().get_caller();
+        let ticket_id = self.last_withdraw_ticket_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        let ticket = WithdrawTicket {
+            owner: caller.clone(),
+            amount,
+            filled: BigUint::zero(),
+            created_epoch: current_epoch,
+        };
+
+        self.withdraw_queue_outstanding()
+            .update(|outstanding| *outstanding += &ticket.amount);
+        self.withdraw_ticket(ticket_id).set(&ticket);
+        self.withdraw_queue().push_back(ticket_id);
+        self.user_withdraw_tickets(&caller).insert(ticket_id);
+        self.emit_withdraw_ticket_event(ticket_id, &ticket);
+
+        // Older tickets are filled first, a fill stopped at `MAX_WITHDRAW_TICKETS_FILLED`
+        // leaves the new ticket to `fillWithdrawQueue` or the next arrival
+        self.fill_withdraw_tickets(&mut storage_cache);
+        self.emit_general_liquidity_event(&storage_cache);
+
+        ticket_id
+    }
+
+    /// Pays the filled part of the given tickets to their owner. Fully paid tickets are removed.
+    #[endpoint(claimWithdraw)]
+    fn claim_withdraw(&self, ticket_ids: MultiValueEncoded<u64>) {
+        let storage_cache = StorageCache::new(self);
+        self.is_state_active(storage_cache.contract_state);
+        self.require_action_not_paused(PausableAction::Withdraw);
+
+        let caller = self.blockchain().get_caller();
+        let mut to_send = BigUint::zero();
+        for ticket_id in ticket_ids {
+            let ticket_mapper = self.withdraw_ticket(ticket_id);
+            require!(!ticket_mapper.is_empty(), ERROR_WITHDRAW_TICKET_NOT_FOUND);
+
+            let mut ticket = ticket_mapper.get();
+            require!(ticket.owner == caller, ERROR_NOT_WITHDRAW_TICKET_OWNER);
+
+            if ticket.filled == BigUint::zero() {
+                continue;
+            }
+
+            to_send += &ticket.filled;
+            ticket.amount -= &ticket.filled;
+            ticket.filled = BigUint::zero();
+
+            if ticket.amount == BigUint::zero() {
+                ticket_mapper.clear();
+                self.user_withdraw_tickets(&caller).swap_remove(&ticket_id);
+            } else {
+                ticket_mapper.set(&ticket);
+            }
+        }
+
+        require!(to_send > BigUint::zero(), ERROR_NOTHING_TO_CLAIM);
+
+        self.withdraw_tickets_reserve()
+            .update(|reserve| *reserve -= &to_send);
+        self.tx().to(&caller).egld(&to_send).transfer();
+        self.emit_general_liquidity_event(&storage_cache);
+    }
+
+    /// Allocates unbonded EGLD left over after a fill stopped at `MAX_WITHDRAW_TICKETS_FILLED`.
+    #[endpoint(fillWithdrawQueue)]
+    fn fill_withdraw_queue(&self) {
+        let mut storage_cache = StorageCache::new(self);
+        self.is_state_active(storage_cache.contract_state);
+
+        self.fill_withdraw_tickets(&mut storage_cache);
+        self.emit_general_liquidity_event(&storage_cache);
+    }
+
+    #[view(getWithdrawTicket)]
+    fn get_withdraw_ticket(&self, ticket_id: u64) -> WithdrawTicket<Self::Api> {
+        let ticket_mapper = self.withdraw_ticket(ticket_id);
+        require!(!ticket_mapper.is_empty(), ERROR_WITHDRAW_TICKET_NOT_FOUND);
+
+        ticket_mapper.get()
+    }
+
+    #[view(getUserWithdrawTickets)]
+    fn get_user_withdraw_tickets(
+        &self,
+        user: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<u64, WithdrawTicket<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for ticket_id in self.user_withdraw_tickets(&user).iter() {
+            result.push((ticket_id, self.withdraw_ticket(ticket_id).get()).into());
+        }
+
+        result
+    }
+
+    /// Returns the number of tickets waiting for unbonded EGLD.
+    #[view(getWithdrawQueueLength)]
+    fn get_withdraw_queue_length(&self) -> usize {
+        self.withdraw_queue().len()
+    }
+
+    /// Allocates the available unbonded EGLD to the queued tickets, oldest first.
+    /// At most `MAX_WITHDRAW_TICKETS_FILLED` tickets are touched per call, the rest
+    /// is picked up by the next arrival.
+    fn fill_withdraw_tickets(&self, storage_cache: &mut StorageCache<Self>) {
+        let mut queue = self.withdraw_queue();
+        let mut allocated = BigUint::zero();
+        let mut touched_tickets = 0;
+
+        while storage_cache.total_withdrawn_egld > BigUint::zero()
+            && touched_tickets < MAX_WITHDRAW_TICKETS_FILLED
+        {
+            let ticket_id = match queue.front() {
+                Some(ticket_id) => ticket_id,
+                None => break,
+            };
+
+            let ticket_mapper = self.withdraw_ticket(ticket_id);
+            let mut ticket = ticket_mapper.get();
+
+            let fill_amount =
+                (&ticket.amount - &ticket.filled).min(storage_cache.total_withdrawn_egld.clone());
+            ticket.filled += &fill_amount;
+            storage_cache.total_withdrawn_egld -= &fill_amount;
+            allocated += &fill_amount;
+
+            if ticket.filled == ticket.amount {
+                queue.pop_front();
+            }
+            ticket_mapper.set(&ticket);
+            touched_tickets += 1;
+        }
+
+        if allocated > BigUint::zero() {
+            self.withdraw_queue_outstanding()
+                .update(|outstanding| *outstanding -= &allocated);
+            self.withdraw_tickets_reserve()
+                .update(|reserve| *reserve += &allocated);
+        }
+    }
+}
@@ -1,13 +1,18 @@
 use crate::contract_setup::LiquidStakingContractSetup;
-use crate::{utils::*, DELEGATION_DEPLOY_CODE, OWNER_ADDRESS};
+use crate::{utils::*, DELEGATION_DEPLOY_CODE, OWNER_ADDRESS, UNSTAKE_TOKEN_ID};
 use delegation_manager_mock::proxy_delegation::{self, DelegationMockProxy};
-use liquid_staking::proxy::proxy_liquid_staking;
+use liquid_staking::proxy::proxy_liquid_staking::{
+    self, ConfigChange, DelegationContractData, DelegationFailures, FeeRecipient, PausableAction,
+    ProviderAllocation, ProviderApplication, ProviderApplicationConfig, ProviderScoringData,
+    Referrer, Role, ScoringConfig, ScoringCurve, WithdrawTicket,
+};
 use liquid_staking::structs::UnstakeTokenAttributes;
 use multiversx_sc::types::{
-    BigUint, ManagedVec, ReturnsNewManagedAddress, ReturnsResult, TestAddress, TestTokenIdentifier,
+    BigUint, EsdtTokenPayment, ManagedBuffer, ManagedVec, MultiValueEncoded,
+    ReturnsNewManagedAddress, ReturnsResult, TestAddress, TestTokenIdentifier,
 };
 use multiversx_sc::{
-    imports::OptionalValue,
+    imports::{MultiValue2, OptionalValue},
     types::{Address, ManagedAddress},
 };
 use multiversx_sc_scenario::api::StaticApi;
@@ -33,12 +38,39 @@ impl LiquidStakingContractSetup {
         apy: u64,
     ) -> Address {
         let rust_one_egld = exp18(1);
-        let egld_balance_biguint = &exp18(egld_balance);
         let total_staked_biguint = exp18(total_staked);
         let delegation_contract_cap_biguint = exp18(delegation_contract_cap);
 
+        let delegation_contract = self.deploy_delegation_contract(owner_address, egld_balance);
+        self.b_mock.set_egld_balance(owner_address, &rust_one_egld);
+
+        self.b_mock
+            .tx()
+            .from(owner_address)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .whitelist_delegation_contract(
+                &delegation_contract,
+                owner_address,
+                total_staked_biguint,
+                delegation_contract_cap_biguint,
+                nr_nodes,
+                apy,
+            )
+            .egld(rust_one_egld)
+            .run();
+
+        delegation_contract
+    }
+
+    pub fn deploy_delegation_contract(
+        &mut self,
+        owner_address: &Address,
+        egld_balance: u64,
+    ) -> Address {
+        let egld_balance_biguint = exp18(egld_balance);
         self.b_mock
-            .set_egld_balance(owner_address, &(egld_balance_biguint + &rust_one_egld));
+            .set_egld_balance(owner_address, &egld_balance_biguint);
 
         let delegation_contract = self
             .b_mock
@@ -59,20 +91,43 @@ impl LiquidStakingContractSetup {
             .egld(egld_balance_biguint)
             .run();
 
+        delegation_contract.to_address()
+    }
+
+    // Deploys the delegation contract in the system SC range, as the delegation manager does
+    pub fn deploy_system_delegation_contract(
+        &mut self,
+        owner_address: &Address,
+        egld_balance: u64,
+        id: u8,
+    ) -> Address {
+        let egld_balance_biguint = exp18(egld_balance);
+        self.b_mock
+            .set_egld_balance(owner_address, &egld_balance_biguint);
+
+        let mut address = [0u8; 32];
+        address[9] = 1;
+        address[29] = id;
+        address[30] = 0xff;
+        address[31] = 0xff;
+        let delegation_contract = self
+            .b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(DelegationMockProxy)
+            .init()
+            .code(DELEGATION_DEPLOY_CODE)
+            .new_address(ManagedAddress::<StaticApi>::new_from_bytes(&address))
+            .returns(ReturnsNewManagedAddress)
+            .run();
+
         self.b_mock
             .tx()
             .from(owner_address)
-            .to(&self.sc_wrapper)
-            .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .whitelist_delegation_contract(
-                &delegation_contract,
-                owner_address,
-                total_staked_biguint,
-                delegation_contract_cap_biguint,
-                nr_nodes,
-                apy,
-            )
-            .egld(rust_one_egld)
+            .to(&delegation_contract)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .deposit_egld()
+            .egld(egld_balance_biguint)
             .run();
 
         delegation_contract.to_address()
@@ -117,243 +172,1951 @@ impl LiquidStakingContractSetup {
             .run()
     }
 
-    pub fn add_liquidity(
+    pub fn grant_role(&mut self, address: &Address, role: Role) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .grant_role(address, role)
+            .run();
+    }
+
+    pub fn revoke_role(&mut self, address: &Address, role: Role) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .revoke_role(address, role)
+            .run();
+    }
+
+    pub fn check_address_roles(&mut self, address: &Address, roles: &[Role]) {
+        let address_roles: Vec<Role> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_address_roles(address)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(address_roles, roles);
+    }
+
+    pub fn check_role_members(&mut self, role: Role, members: &[&Address]) {
+        let role_members: Vec<Address> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .role_members(role)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|member| member.to_address())
+            .collect();
+
+        let expected_members: Vec<Address> =
+            members.iter().map(|member| (*member).clone()).collect();
+        assert_eq!(role_members, expected_members);
+    }
+
+    pub fn set_realized_apy_scoring(&mut self, caller: &Address, enabled: bool) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .set_realized_apy_scoring(enabled)
+            .run();
+    }
+
+    pub fn set_realized_apy_scoring_error(
         &mut self,
         caller: &Address,
-        payment_amount: BigUint<StaticApi>,
-        to: OptionalValue<ManagedAddress<StaticApi>>,
+        enabled: bool,
+        error: &[u8],
     ) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .delegate(to)
-            .egld(payment_amount)
+            .set_realized_apy_scoring(enabled)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
             .run();
     }
 
-    pub fn add_liquidity_error(
+    pub fn pause_actions(&mut self, caller: &Address, actions: &[PausableAction]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .pause_actions(pausable_actions_arg(actions))
+            .run();
+    }
+
+    pub fn pause_actions_error(
         &mut self,
         caller: &Address,
-        payment_amount: BigUint<StaticApi>,
+        actions: &[PausableAction],
         error: &[u8],
-        to: OptionalValue<ManagedAddress<StaticApi>>,
     ) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .delegate(to)
-            .egld(&payment_amount)
+            .pause_actions(pausable_actions_arg(actions))
             .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
             .run();
     }
 
-    pub fn add_liquidity_provider(&mut self, providers: Address) {
+    pub fn unpause_actions(&mut self, caller: &Address, actions: &[PausableAction]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .unpause_actions(pausable_actions_arg(actions))
+            .run();
+    }
+
+    pub fn unpause_actions_error(
+        &mut self,
+        caller: &Address,
+        actions: &[PausableAction],
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .unpause_actions(pausable_actions_arg(actions))
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn check_action_paused(&mut self, action: PausableAction, paused: bool) {
+        let is_paused = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .is_action_paused(action)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(is_paused, paused);
+    }
+
+    pub fn propose_config_change(&mut self, change: ConfigChange<StaticApi>) -> u64 {
         self.b_mock
             .tx()
             .from(OWNER_ADDRESS)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .add_liquidity_provider(providers)
+            .propose_config_change(change)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn propose_config_change_error(
+        &mut self,
+        caller: &Address,
+        change: ConfigChange<StaticApi>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .propose_config_change(change)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
             .run();
     }
 
-    pub fn remove_liquidity_provider(&mut self, provider: Address) {
+    pub fn execute_config_change(&mut self, caller: &Address, proposal_id: u64) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .execute_config_change(proposal_id)
+            .run();
+    }
+
+    pub fn execute_config_change_error(
+        &mut self,
+        caller: &Address,
+        proposal_id: u64,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .execute_config_change(proposal_id)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn cancel_config_change(&mut self, proposal_id: u64) {
         self.b_mock
             .tx()
             .from(OWNER_ADDRESS)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .remove_liquidity_provider(provider)
+            .cancel_config_change(proposal_id)
             .run();
     }
 
-    pub fn remove_liquidity(
+    pub fn check_pending_config_proposals(&mut self, proposal_ids: &[u64]) {
+        let pending_proposal_ids: Vec<u64> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_pending_config_proposals()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|entry| entry.into_tuple().0)
+            .collect();
+
+        assert_eq!(pending_proposal_ids, proposal_ids);
+    }
+
+    pub fn check_fees(&mut self, fees: u64) {
+        let current_fees = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .fees()
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(current_fees, BigUint::from(fees));
+    }
+
+    pub fn check_last_fees_change_epoch(&mut self, epoch: u64) {
+        let last_fees_change_epoch = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .last_fees_change_epoch()
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(last_fees_change_epoch, epoch);
+    }
+
+    pub fn add_liquidity(
         &mut self,
         caller: &Address,
-        payment_token: TestTokenIdentifier,
         payment_amount: BigUint<StaticApi>,
+        to: OptionalValue<ManagedAddress<StaticApi>>,
     ) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .un_delegate()
-            .single_esdt(&payment_token.to_token_identifier(), 0, &payment_amount)
+            .delegate(to)
+            .egld(payment_amount)
             .run();
     }
 
-    pub fn remove_liquidity_error(
+    pub fn add_liquidity_error(
         &mut self,
         caller: &Address,
-        payment_token: TestTokenIdentifier,
         payment_amount: BigUint<StaticApi>,
         error: &[u8],
+        to: OptionalValue<ManagedAddress<StaticApi>>,
     ) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .un_delegate()
-            .single_esdt(&payment_token.to_token_identifier(), 0, &payment_amount)
+            .delegate(to)
+            .egld(&payment_amount)
             .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
             .run();
     }
 
-    pub fn claim_rewards(&mut self, caller: &Address) {
+    pub fn add_liquidity_with_referral(
+        &mut self,
+        caller: &Address,
+        payment_amount: BigUint<StaticApi>,
+        referrer_code: &[u8],
+    ) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .claim_rewards()
+            .delegate_with_min_out(
+                BigUint::zero(),
+                OptionalValue::Some(ManagedBuffer::from(referrer_code)),
+            )
+            .egld(payment_amount)
             .run();
     }
 
-    pub fn claim_rewards_error(&mut self, caller: &Address, error: &[u8]) {
+    pub fn add_liquidity_with_referral_error(
+        &mut self,
+        caller: &Address,
+        payment_amount: BigUint<StaticApi>,
+        referrer_code: &[u8],
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_with_min_out(
+                BigUint::zero(),
+                OptionalValue::Some(ManagedBuffer::from(referrer_code)),
+            )
+            .egld(payment_amount)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn add_liquidity_for_with_referral(
+        &mut self,
+        caller: &Address,
+        receiver: &Address,
+        payment_amount: BigUint<StaticApi>,
+        referrer_code: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_for(
+                receiver,
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::Some(ManagedBuffer::from(referrer_code)),
+            )
+            .egld(payment_amount)
+            .run();
+    }
+
+    pub fn add_liquidity_for_with_referral_error(
+        &mut self,
+        caller: &Address,
+        receiver: &Address,
+        payment_amount: BigUint<StaticApi>,
+        referrer_code: &[u8],
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_for(
+                receiver,
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::Some(ManagedBuffer::from(referrer_code)),
+            )
+            .egld(payment_amount)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn register_referrer(&mut self, caller: &Address, code: &[u8]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .register_referrer(ManagedBuffer::<StaticApi>::from(code))
+            .run();
+    }
+
+    pub fn register_referrer_error(&mut self, caller: &Address, code: &[u8], error: &[u8]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .register_referrer(ManagedBuffer::<StaticApi>::from(code))
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn claim_referral_rewards(&mut self, caller: &Address) -> BigUint<StaticApi> {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .claim_referral_rewards()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn claim_referral_rewards_error(&mut self, caller: &Address, error: &[u8]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .claim_referral_rewards()
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn release_expired_referrals(&mut self, caller: &Address, users: &[&Address]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .release_expired_referrals(providers_arg(users))
+            .run();
+    }
+
+    pub fn get_referrer(&mut self, address: &Address) -> Referrer<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_referrer(address)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn get_referral_rewards_reserve(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .referral_rewards_reserve()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn check_user_referred_ls(&mut self, user: &Address, referred_ls: BigUint<StaticApi>) {
+        let user_referred_ls = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .user_referred_ls(user)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(user_referred_ls, referred_ls);
+    }
+
+    pub fn record_provider_loss(
+        &mut self,
+        caller: &Address,
+        contract_address: &Address,
+        amount: BigUint<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .record_provider_loss(contract_address, amount)
+            .run();
+    }
+
+    pub fn record_provider_loss_error(
+        &mut self,
+        caller: &Address,
+        contract_address: &Address,
+        amount: BigUint<StaticApi>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .record_provider_loss(contract_address, amount)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn socialize_losses(
+        &mut self,
+        caller: &Address,
+    ) -> (BigUint<StaticApi>, BigUint<StaticApi>) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .socialize_losses()
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
+    pub fn socialize_losses_error(&mut self, caller: &Address, error: &[u8]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .socialize_losses()
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn get_insurance_reserve(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .insurance_reserve()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn get_recorded_losses(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .recorded_losses()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn reconcile_providers(&mut self, caller: &Address, providers: &[&Address]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .reconcile_providers(providers_arg(providers))
+            .run();
+    }
+
+    pub fn reconcile_providers_error(
+        &mut self,
+        caller: &Address,
+        providers: &[&Address],
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .reconcile_providers(providers_arg(providers))
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn slash_provider(&mut self, delegation_contract: &Address, amount: BigUint<StaticApi>) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(delegation_contract)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .slash(amount)
+            .run();
+    }
+
+    pub fn delegate_to_provider(
+        &mut self,
+        caller: &Address,
+        delegation_contract: &Address,
+        amount: BigUint<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(delegation_contract)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .delegate()
+            .egld(amount)
+            .run();
+    }
+
+    pub fn set_provider_config(
+        &mut self,
+        delegation_contract: &Address,
+        max_delegation_cap: BigUint<StaticApi>,
+    ) {
+        self.set_provider_service_fee(delegation_contract, 1_000, max_delegation_cap);
+    }
+
+    pub fn set_provider_service_fee(
+        &mut self,
+        delegation_contract: &Address,
+        service_fee: u64,
+        max_delegation_cap: BigUint<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(delegation_contract)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .set_contract_config(BigUint::from(service_fee), max_delegation_cap)
+            .run();
+    }
+
+    pub fn set_provider_owner(&mut self, delegation_contract: &Address, owner: &Address) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(delegation_contract)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .set_contract_owner(owner)
+            .run();
+    }
+
+    pub fn set_provider_node_states(
+        &mut self,
+        delegation_contract: &Address,
+        node_states: &[(&[u8], &[u8])],
+    ) {
+        let mut node_states_arg = MultiValueEncoded::new();
+        for (bls_key, state) in node_states {
+            node_states_arg.push(MultiValue2::from((
+                ManagedBuffer::<StaticApi>::from(*bls_key),
+                ManagedBuffer::from(*state),
+            )));
+        }
+
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(delegation_contract)
+            .typed(proxy_delegation::DelegationMockProxy)
+            .set_node_states(node_states_arg)
+            .run();
+    }
+
+    pub fn sync_provider(&mut self, caller: &Address, delegation_contract: &Address) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .sync_provider(delegation_contract)
+            .run();
+    }
+
+    pub fn sync_provider_error(
+        &mut self,
+        caller: &Address,
+        delegation_contract: &Address,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .sync_provider(delegation_contract)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn get_provider_scoring_data(
+        &mut self,
+        delegation_contract: &Address,
+    ) -> ProviderScoringData {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_provider_scoring_data(delegation_contract)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn set_scoring_config(&mut self, caller: &Address, config: ScoringConfig) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .set_scoring_config(config)
+            .run();
+    }
+
+    pub fn set_scoring_config_error(
+        &mut self,
+        caller: &Address,
+        config: ScoringConfig,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .set_scoring_config(config)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn set_provider_application_config(
+        &mut self,
+        min_service_fee: u64,
+        max_service_fee: u64,
+        min_nodes: u64,
+        min_cap_headroom: u64,
+        auto_approval_epochs: u64,
+        max_auto_approval_apy: u64,
+    ) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .set_provider_application_config(ProviderApplicationConfig {
+                min_service_fee,
+                max_service_fee,
+                min_nodes,
+                min_cap_headroom: exp18(min_cap_headroom),
+                auto_approval_epochs,
+                max_auto_approval_apy,
+            })
+            .run();
+    }
+
+    pub fn apply_as_provider(
+        &mut self,
+        caller: &Address,
+        delegation_contract: &Address,
+        apy: u64,
+        seed: u64,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .apply_as_provider(delegation_contract, apy)
+            .egld(exp18(seed))
+            .run();
+    }
+
+    pub fn apply_as_provider_error(
+        &mut self,
+        caller: &Address,
+        delegation_contract: &Address,
+        seed: u64,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .apply_as_provider(delegation_contract, 5_000u64)
+            .egld(exp18(seed))
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn approve_provider_application(
+        &mut self,
+        caller: &Address,
+        delegation_contract: &Address,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .approve_provider_application(delegation_contract)
+            .run();
+    }
+
+    pub fn approve_provider_application_error(
+        &mut self,
+        caller: &Address,
+        delegation_contract: &Address,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .approve_provider_application(delegation_contract)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn cancel_provider_application(&mut self, caller: &Address, delegation_contract: &Address) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .cancel_provider_application(delegation_contract)
+            .run();
+    }
+
+    pub fn cancel_provider_application_error(
+        &mut self,
+        caller: &Address,
+        delegation_contract: &Address,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .cancel_provider_application(delegation_contract)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn get_provider_application(
+        &mut self,
+        delegation_contract: &Address,
+    ) -> ProviderApplication<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_provider_application(delegation_contract)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn check_pending_provider_applications(&mut self, delegation_contracts: &[&Address]) {
+        let pending_applications: Vec<Address> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_pending_provider_applications()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|pending_application| pending_application.into_tuple().0.to_address())
+            .collect();
+
+        let expected_applications: Vec<Address> = delegation_contracts
+            .iter()
+            .map(|delegation_contract| (*delegation_contract).clone())
+            .collect();
+        assert_eq!(pending_applications, expected_applications);
+    }
+
+    pub fn get_delegation_contract_data(
+        &mut self,
+        delegation_contract: &Address,
+    ) -> DelegationContractData<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegation_contract_data(delegation_contract)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn is_provider_eligible(&mut self, delegation_contract: &Address) -> bool {
+        self.get_delegation_contract_data(delegation_contract)
+            .eligible
+    }
+
+    pub fn retire_provider(&mut self, caller: &Address, delegation_contract: &Address) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .retire_provider(delegation_contract)
+            .run();
+    }
+
+    pub fn retire_provider_error(
+        &mut self,
+        caller: &Address,
+        delegation_contract: &Address,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .retire_provider(delegation_contract)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn check_retiring_providers(&mut self, delegation_contracts: &[&Address]) {
+        let retiring_providers: Vec<Address> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .retiring_providers()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|provider| provider.to_address())
+            .collect();
+
+        let expected_providers: Vec<Address> = delegation_contracts
+            .iter()
+            .map(|delegation_contract| (*delegation_contract).clone())
+            .collect();
+        assert_eq!(retiring_providers, expected_providers);
+    }
+
+    pub fn check_delegation_addresses(&mut self, delegation_contracts: &[&Address]) {
+        let delegation_addresses: Vec<Address> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegation_addresses_list()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|provider| provider.to_address())
+            .collect();
+
+        let expected_addresses: Vec<Address> = delegation_contracts
+            .iter()
+            .map(|delegation_contract| (*delegation_contract).clone())
+            .collect();
+        assert_eq!(delegation_addresses, expected_addresses);
+    }
+
+    pub fn rebalance(
+        &mut self,
+        caller: &Address,
+        max_amount: BigUint<StaticApi>,
+    ) -> BigUint<StaticApi> {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .rebalance(max_amount)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn rebalance_error(
+        &mut self,
+        caller: &Address,
+        max_amount: BigUint<StaticApi>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .rebalance(max_amount)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn delegate_rebalance_pending(&mut self, caller: &Address) -> BigUint<StaticApi> {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_rebalance_pending()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn delegate_rebalance_pending_error(&mut self, caller: &Address, error: &[u8]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_rebalance_pending()
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn get_provider_allocations(&mut self) -> Vec<ProviderAllocation<StaticApi>> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_provider_allocations()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect()
+    }
+
+    pub fn get_provider_delegation_failures(
+        &mut self,
+        delegation_contract: &Address,
+    ) -> DelegationFailures {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_provider_delegation_failures(delegation_contract)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn check_cooling_down_providers(&mut self, delegation_contracts: &[&Address]) {
+        let cooling_down_providers: Vec<Address> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .cooling_down_providers()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|provider| provider.to_address())
+            .collect();
+
+        let expected_providers: Vec<Address> = delegation_contracts
+            .iter()
+            .map(|delegation_contract| (*delegation_contract).clone())
+            .collect();
+        assert_eq!(cooling_down_providers, expected_providers);
+    }
+
+    pub fn get_evacuating_egld(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .evacuating_egld()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn get_rebalance_pending_egld(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .rebalance_pending_egld()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn add_liquidity_min_out(
+        &mut self,
+        caller: &Address,
+        payment_amount: BigUint<StaticApi>,
+        min_ls_out: BigUint<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_with_min_out(min_ls_out, OptionalValue::<ManagedBuffer<StaticApi>>::None)
+            .egld(payment_amount)
+            .run();
+    }
+
+    pub fn add_liquidity_min_out_error(
+        &mut self,
+        caller: &Address,
+        payment_amount: BigUint<StaticApi>,
+        min_ls_out: BigUint<StaticApi>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_with_min_out(min_ls_out, OptionalValue::<ManagedBuffer<StaticApi>>::None)
+            .egld(&payment_amount)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn add_liquidity_for(
+        &mut self,
+        caller: &Address,
+        receiver: &Address,
+        payment_amount: BigUint<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_for(
+                receiver,
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            )
+            .egld(payment_amount)
+            .run();
+    }
+
+    pub fn add_liquidity_for_error(
+        &mut self,
+        caller: &Address,
+        receiver: &Address,
+        payment_amount: BigUint<StaticApi>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_for(
+                receiver,
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            )
+            .egld(&payment_amount)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn add_liquidity_provider(&mut self, providers: Address) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .add_liquidity_provider(providers)
+            .run();
+    }
+
+    pub fn remove_liquidity_provider(&mut self, provider: Address) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .remove_liquidity_provider(provider)
+            .run();
+    }
+
+    pub fn remove_liquidity(
+        &mut self,
+        caller: &Address,
+        payment_token: TestTokenIdentifier,
+        payment_amount: BigUint<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .un_delegate(
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<bool>::None,
+            )
+            .single_esdt(&payment_token.to_token_identifier(), 0, &payment_amount)
+            .run();
+    }
+
+    pub fn remove_liquidity_error(
+        &mut self,
+        caller: &Address,
+        payment_token: TestTokenIdentifier,
+        payment_amount: BigUint<StaticApi>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .un_delegate(
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<bool>::None,
+            )
+            .single_esdt(&payment_token.to_token_identifier(), 0, &payment_amount)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn remove_liquidity_with_limits(
+        &mut self,
+        caller: &Address,
+        payment_token: TestTokenIdentifier,
+        payment_amount: BigUint<StaticApi>,
+        min_egld_out: OptionalValue<BigUint<StaticApi>>,
+        instant_only: OptionalValue<bool>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .un_delegate(min_egld_out, instant_only)
+            .single_esdt(&payment_token.to_token_identifier(), 0, &payment_amount)
+            .run();
+    }
+
+    pub fn remove_liquidity_with_limits_error(
+        &mut self,
+        caller: &Address,
+        payment_token: TestTokenIdentifier,
+        payment_amount: BigUint<StaticApi>,
+        min_egld_out: OptionalValue<BigUint<StaticApi>>,
+        instant_only: OptionalValue<bool>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .un_delegate(min_egld_out, instant_only)
+            .single_esdt(&payment_token.to_token_identifier(), 0, &payment_amount)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn remove_liquidity_for(
+        &mut self,
+        caller: &Address,
+        receiver: &Address,
+        payment_token: TestTokenIdentifier,
+        payment_amount: BigUint<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .un_delegate_for(
+                receiver,
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<bool>::None,
+            )
+            .single_esdt(&payment_token.to_token_identifier(), 0, &payment_amount)
+            .run();
+    }
+
+    pub fn add_rewards(&mut self, caller: &Address, amount: BigUint<StaticApi>) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .add_rewards()
+            .egld(amount)
+            .run();
+    }
+
+    pub fn claim_rewards(&mut self, caller: &Address) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .claim_rewards()
+            .run();
+    }
+
+    pub fn claim_rewards_error(&mut self, caller: &Address, error: &[u8]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .claim_rewards()
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn claim_fees(&mut self, caller: &Address) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .claim_fees()
+            .run();
+    }
+
+    pub fn check_accrued_fees(&mut self, expected_fees: &[(&Address, u128, u128)]) {
+        let accrued_fees: Vec<(Address, BigUint<StaticApi>, BigUint<StaticApi>)> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_accrued_fees()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|accrued| {
+                let (recipient, egld_amount, ls_amount) = accrued.into_tuple();
+                (recipient.to_address(), egld_amount, ls_amount)
+            })
+            .collect();
+
+        let expected_fees: Vec<(Address, BigUint<StaticApi>, BigUint<StaticApi>)> = expected_fees
+            .iter()
+            .map(|(recipient, egld_amount, ls_amount)| {
+                ((*recipient).clone(), exp(*egld_amount), exp(*ls_amount))
+            })
+            .collect();
+        assert_eq!(accrued_fees, expected_fees);
+    }
+
+    pub fn delegate_pending(
+        &mut self,
+        caller: &Address,
+        amount: OptionalValue<BigUint<StaticApi>>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_pending(amount)
+            .run();
+    }
+
+    pub fn delegate_pending_error(
+        &mut self,
+        caller: &Address,
+        amount: OptionalValue<BigUint<StaticApi>>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .delegate_pending(amount)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn un_delegate_pending(
+        &mut self,
+        caller: &Address,
+        amount: OptionalValue<BigUint<StaticApi>>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .un_delegate_pending(
+                amount,
+                OptionalValue::<ManagedVec<StaticApi, ManagedAddress<StaticApi>>>::None,
+            )
+            .run();
+    }
+
+    pub fn un_delegate_pending_provider(
+        &mut self,
+        caller: &Address,
+        amount: OptionalValue<BigUint<StaticApi>>,
+        provider: ManagedAddress<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .un_delegate_pending(
+                amount,
+                OptionalValue::Some(ManagedVec::from_iter(vec![provider])),
+            )
+            .run();
+    }
+
+    pub fn un_delegate_pending_error(
+        &mut self,
+        caller: &Address,
+        amount: OptionalValue<BigUint<StaticApi>>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .un_delegate_pending(
+                amount,
+                OptionalValue::<ManagedVec<StaticApi, ManagedAddress<StaticApi>>>::None,
+            )
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn withdraw_pending(&mut self, caller: &Address, contracts: &Address) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .withdraw_pending(contracts)
+            .run();
+    }
+
+    pub fn withdraw_pending_error(&mut self, caller: &Address, contracts: &Address, error: &[u8]) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .withdraw_pending(contracts)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn withdraw_pending_batch(&mut self, caller: &Address, max_providers: usize) -> usize {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .gas(100_000_000u64)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .withdraw_pending_batch(OptionalValue::Some(max_providers))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn withdraw_pending_batch_error(
+        &mut self,
+        caller: &Address,
+        max_providers: usize,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .gas(100_000_000u64)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .withdraw_pending_batch(OptionalValue::Some(max_providers))
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn check_withdrawable_providers(&mut self, providers: &[&Address]) {
+        let withdrawable_providers: Vec<Address> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_withdrawable_providers()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|provider| provider.to_address())
+            .collect();
+
+        let expected_providers: Vec<Address> = providers
+            .iter()
+            .map(|provider| (*provider).clone())
+            .collect();
+        assert_eq!(withdrawable_providers, expected_providers);
+    }
+
+    pub fn check_provider_pending_unbonds(&mut self, provider: &Address, unbond_epochs: &[u64]) {
+        let pending_unbond_epochs: Vec<u64> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_provider_pending_unbonds(provider)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|pending_unbond| pending_unbond.unbond_epoch)
+            .collect();
+
+        assert_eq!(pending_unbond_epochs, unbond_epochs);
+    }
+
+    pub fn check_provider_unbonding_amounts(
+        &mut self,
+        provider: &Address,
+        claimable: u64,
+        locked: u64,
+    ) {
+        let unbonding_amounts = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_provider_unbonding_amounts(provider)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(
+            unbonding_amounts.into_tuple(),
+            (exp18(claimable), exp18(locked))
+        );
+    }
+
+    pub fn check_unbonding_ledger(&mut self, expected_ledger: &[(&Address, u64, u64)]) {
+        let ledger: Vec<(Address, BigUint<StaticApi>, BigUint<StaticApi>)> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_unbonding_ledger()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|entry| {
+                let (provider, claimable, locked) = entry.into_tuple();
+                (provider.to_address(), claimable, locked)
+            })
+            .collect();
+
+        let expected_ledger: Vec<(Address, BigUint<StaticApi>, BigUint<StaticApi>)> =
+            expected_ledger
+                .iter()
+                .map(|(provider, claimable, locked)| {
+                    ((*provider).clone(), exp18(*claimable), exp18(*locked))
+                })
+                .collect();
+        assert_eq!(ledger, expected_ledger);
+    }
+
+    pub fn check_invariants_hold(&mut self) {
+        let invariant_checks: Vec<_> = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .check_invariants()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(invariant_checks.len(), 4);
+        for check in invariant_checks {
+            assert!(
+                check.holds,
+                "invariant {:?} broken: expected {:?}, actual {:?}",
+                check.invariant, check.expected, check.actual
+            );
+        }
+    }
+
+    pub fn get_pending_egld(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .pending_egld()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn get_pending_egld_for_unstake(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .pending_egld_for_unstake()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn get_withdrawable_providers(&mut self) -> Vec<Address> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_withdrawable_providers()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|provider| provider.to_address())
+            .collect()
+    }
+
+    pub fn get_ls_token_supply(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .ls_token_supply()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn get_total_withdrawn_egld(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .total_withdrawn_egld()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn get_unstake_token_nonce(&mut self, unbond_epoch: u64) -> u64 {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .unstake_token_nonce(unbond_epoch)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn withdraw(
+        &mut self,
+        caller: &Address,
+        payment_token: TestTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .withdraw()
+            .single_esdt(&payment_token.to_token_identifier(), token_nonce, &amount)
+            .run();
+    }
+
+    pub fn withdraw_error(
+        &mut self,
+        caller: &Address,
+        payment_token: TestTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint<StaticApi>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .withdraw()
+            .single_esdt(&payment_token.to_token_identifier(), token_nonce, &amount)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn withdraw_for(
+        &mut self,
+        caller: &Address,
+        receiver: &Address,
+        payment_token: TestTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .withdraw_for(receiver)
+            .single_esdt(&payment_token.to_token_identifier(), token_nonce, &amount)
+            .run();
+    }
+
+    pub fn withdraw_amount(
+        &mut self,
+        caller: &Address,
+        token_nonce: u64,
+        payment_amount: BigUint<StaticApi>,
+        amount: BigUint<StaticApi>,
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .withdraw_amount(amount)
+            .single_esdt(
+                &UNSTAKE_TOKEN_ID.to_token_identifier(),
+                token_nonce,
+                &payment_amount,
+            )
+            .run();
+    }
+
+    pub fn withdraw_amount_error(
+        &mut self,
+        caller: &Address,
+        token_nonce: u64,
+        payment_amount: BigUint<StaticApi>,
+        amount: BigUint<StaticApi>,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .withdraw_amount(amount)
+            .single_esdt(
+                &UNSTAKE_TOKEN_ID.to_token_identifier(),
+                token_nonce,
+                &payment_amount,
+            )
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn split_unstake_tokens(
+        &mut self,
+        caller: &Address,
+        token_nonce: u64,
+        payment_amount: BigUint<StaticApi>,
+        amount: BigUint<StaticApi>,
+    ) -> (BigUint<StaticApi>, BigUint<StaticApi>) {
+        let (first_part, second_part) = self
+            .b_mock
+            .tx()
+            .from(caller)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .split_unstake_tokens(amount)
+            .single_esdt(
+                &UNSTAKE_TOKEN_ID.to_token_identifier(),
+                token_nonce,
+                &payment_amount,
+            )
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+
+        (first_part.amount, second_part.amount)
+    }
+
+    pub fn split_unstake_tokens_error(
+        &mut self,
+        caller: &Address,
+        token_nonce: u64,
+        payment_amount: BigUint<StaticApi>,
+        amount: BigUint<StaticApi>,
+        error: &[u8],
+    ) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .claim_rewards()
+            .split_unstake_tokens(amount)
+            .single_esdt(
+                &UNSTAKE_TOKEN_ID.to_token_identifier(),
+                token_nonce,
+                &payment_amount,
+            )
             .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
             .run();
     }
 
-    pub fn delegate_pending(
-        &mut self,
-        caller: &Address,
-        amount: OptionalValue<BigUint<StaticApi>>,
-    ) {
+    pub fn merge_unstake_tokens(&mut self, caller: &Address, positions: &[(u64, u64)]) {
+        let mut payments = ManagedVec::new();
+        for (token_nonce, amount) in positions {
+            payments.push(EsdtTokenPayment::new(
+                UNSTAKE_TOKEN_ID.to_token_identifier(),
+                *token_nonce,
+                exp18(*amount),
+            ));
+        }
+
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .delegate_pending(amount)
+            .merge_unstake_tokens()
+            .payment(payments)
             .run();
     }
 
-    pub fn delegate_pending_error(
+    pub fn request_withdraw(&mut self, caller: &Address, positions: &[(u64, u64)]) -> u64 {
+        let positions: Vec<_> = positions
+            .iter()
+            .map(|(token_nonce, amount)| (*token_nonce, exp18(*amount)))
+            .collect();
+
+        self.request_withdraw_denominated(caller, &positions)
+    }
+
+    pub fn request_withdraw_denominated(
         &mut self,
         caller: &Address,
-        amount: OptionalValue<BigUint<StaticApi>>,
-        error: &[u8],
-    ) {
+        positions: &[(u64, BigUint<StaticApi>)],
+    ) -> u64 {
+        let mut payments = ManagedVec::new();
+        for (token_nonce, amount) in positions {
+            payments.push(EsdtTokenPayment::new(
+                UNSTAKE_TOKEN_ID.to_token_identifier(),
+                *token_nonce,
+                amount.clone(),
+            ));
+        }
+
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .delegate_pending(amount)
-            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
-            .run();
+            .request_withdraw()
+            .payment(payments)
+            .returns(ReturnsResult)
+            .run()
     }
 
-    pub fn un_delegate_pending(
+    pub fn request_withdraw_error(
         &mut self,
         caller: &Address,
-        amount: OptionalValue<BigUint<StaticApi>>,
+        token_nonce: u64,
+        amount: BigUint<StaticApi>,
+        error: &[u8],
     ) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .un_delegate_pending(
-                amount,
-                OptionalValue::<ManagedVec<StaticApi, ManagedAddress<StaticApi>>>::None,
+            .request_withdraw()
+            .single_esdt(
+                &UNSTAKE_TOKEN_ID.to_token_identifier(),
+                token_nonce,
+                &amount,
             )
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
             .run();
     }
 
-    pub fn un_delegate_pending_provider(
-        &mut self,
-        caller: &Address,
-        amount: OptionalValue<BigUint<StaticApi>>,
-        provider: ManagedAddress<StaticApi>,
-    ) {
+    pub fn claim_withdraw(&mut self, caller: &Address, ticket_ids: &[u64]) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .un_delegate_pending(
-                amount,
-                OptionalValue::Some(ManagedVec::from_iter(vec![provider])),
-            )
+            .claim_withdraw(MultiValueEncoded::from_iter(ticket_ids.iter().copied()))
             .run();
     }
 
-    pub fn un_delegate_pending_error(
-        &mut self,
-        caller: &Address,
-        amount: OptionalValue<BigUint<StaticApi>>,
-        error: &[u8],
-    ) {
+    pub fn claim_withdraw_error(&mut self, caller: &Address, ticket_ids: &[u64], error: &[u8]) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .un_delegate_pending(
-                amount,
-                OptionalValue::<ManagedVec<StaticApi, ManagedAddress<StaticApi>>>::None,
-            )
+            .claim_withdraw(MultiValueEncoded::from_iter(ticket_ids.iter().copied()))
             .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
             .run();
     }
 
-    pub fn withdraw_pending(&mut self, caller: &Address, contracts: &Address) {
+    pub fn fill_withdraw_queue(&mut self, caller: &Address) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .withdraw_pending(contracts)
+            .fill_withdraw_queue()
             .run();
     }
 
-    pub fn withdraw_pending_error(&mut self, caller: &Address, contracts: &Address, error: &[u8]) {
+    pub fn get_withdraw_ticket(&mut self, ticket_id: u64) -> WithdrawTicket<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_withdraw_ticket(ticket_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn check_withdraw_ticket(&mut self, ticket_id: u64, amount: u64, filled: u64) {
+        let ticket = self.get_withdraw_ticket(ticket_id);
+
+        assert_eq!(ticket.amount, exp18(amount));
+        assert_eq!(ticket.filled, exp18(filled));
+    }
+
+    pub fn get_withdraw_queue_length(&mut self) -> usize {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_withdraw_queue_length()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn check_withdraw_queue_length(&mut self, queue_length: usize) {
+        assert_eq!(self.get_withdraw_queue_length(), queue_length);
+    }
+
+    pub fn set_early_redemption_config(
+        &mut self,
+        max_fee: u64,
+        min_fee: u64,
+        fee_to_holders: bool,
+    ) {
         self.b_mock
             .tx()
-            .from(caller)
+            .from(OWNER_ADDRESS)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .withdraw_pending(contracts)
-            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .set_early_redemption_config(max_fee, min_fee, fee_to_holders)
             .run();
     }
 
-    pub fn withdraw(
+    pub fn redeem_early(
         &mut self,
         caller: &Address,
-        payment_token: TestTokenIdentifier,
         token_nonce: u64,
         amount: BigUint<StaticApi>,
+        min_egld_out: OptionalValue<BigUint<StaticApi>>,
     ) {
         self.b_mock
             .tx()
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .withdraw()
-            .single_esdt(&payment_token.to_token_identifier(), token_nonce, &amount)
+            .redeem_early(min_egld_out)
+            .single_esdt(
+                &UNSTAKE_TOKEN_ID.to_token_identifier(),
+                token_nonce,
+                &amount,
+            )
             .run();
     }
 
-    pub fn withdraw_error(
+    pub fn redeem_early_error(
         &mut self,
         caller: &Address,
-        payment_token: TestTokenIdentifier,
         token_nonce: u64,
         amount: BigUint<StaticApi>,
+        min_egld_out: OptionalValue<BigUint<StaticApi>>,
         error: &[u8],
     ) {
         self.b_mock
@@ -361,12 +2124,56 @@ impl LiquidStakingContractSetup {
             .from(caller)
             .to(&self.sc_wrapper)
             .typed(proxy_liquid_staking::LiquidStakingProxy)
-            .withdraw()
-            .single_esdt(&payment_token.to_token_identifier(), token_nonce, &amount)
+            .redeem_early(min_egld_out)
+            .single_esdt(
+                &UNSTAKE_TOKEN_ID.to_token_identifier(),
+                token_nonce,
+                &amount,
+            )
             .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
             .run();
     }
 
+    pub fn get_early_redemption_quote(
+        &mut self,
+        token_nonce: u64,
+        amount: BigUint<StaticApi>,
+    ) -> (BigUint<StaticApi>, BigUint<StaticApi>) {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_early_redemption_quote(token_nonce, amount)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
+    pub fn check_early_redemption_quote(
+        &mut self,
+        token_nonce: u64,
+        amount: BigUint<StaticApi>,
+        egld_out: BigUint<StaticApi>,
+        fee: BigUint<StaticApi>,
+    ) {
+        let quote = self.get_early_redemption_quote(token_nonce, amount);
+
+        assert_eq!(quote, (egld_out, fee));
+    }
+
+    pub fn check_pending_egld_for_restake(&mut self, pending_egld_for_restake: u64) {
+        assert_eq!(
+            self.b_mock
+                .query()
+                .to(&self.sc_wrapper)
+                .typed(proxy_liquid_staking::LiquidStakingProxy)
+                .pending_egld_for_restake()
+                .returns(ReturnsResult)
+                .run(),
+            exp18(pending_egld_for_restake)
+        );
+    }
+
     pub fn check_user_balance(
         &mut self,
         address: &Address,
@@ -611,6 +2418,19 @@ impl LiquidStakingContractSetup {
         );
     }
 
+    pub fn get_ls_value_for_position_denominated(
+        &mut self,
+        ls_amount: BigUint<StaticApi>,
+    ) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_ls_value_for_position(ls_amount)
+            .returns(ReturnsResult)
+            .run()
+    }
+
     pub fn get_ls_value_for_position(&mut self, token_amount: u64) -> u128 {
         let ls_value_biguint = self
             .b_mock
@@ -625,6 +2445,133 @@ impl LiquidStakingContractSetup {
         u128::from(ls_value_biguint.to_u64().unwrap())
     }
 
+    pub fn check_twap_exchange_rate(&mut self, window: u64, expected: BigUint<StaticApi>) {
+        let twap = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_twap_exchange_rate(window)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(twap, expected);
+    }
+
+    pub fn check_twap_exchange_rate_error(&mut self, window: u64, error: &[u8]) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_twap_exchange_rate(window)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn check_exchange_rate_growth(
+        &mut self,
+        from_epoch: u64,
+        to_epoch: u64,
+        growth: BigUint<StaticApi>,
+    ) {
+        let result = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_exchange_rate_growth()
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(result.into_tuple(), (from_epoch, to_epoch, growth));
+    }
+
+    pub fn check_rate_snapshot_epochs(&mut self, count: usize, epochs: &[u64]) {
+        let snapshots = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_rate_snapshots(count)
+            .returns(ReturnsResult)
+            .run();
+
+        let snapshot_epochs: Vec<u64> = snapshots
+            .into_iter()
+            .map(|snapshot| snapshot.epoch)
+            .collect();
+        assert_eq!(snapshot_epochs, epochs);
+    }
+
+    pub fn check_realized_apr(&mut self, epochs: u64, expected_apr: u64) {
+        let realized_apr = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_realized_apr(epochs)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(realized_apr, expected_apr);
+    }
+
+    pub fn check_realized_apr_error(&mut self, epochs: u64, error: &[u8]) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_realized_apr(epochs)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn get_provider_realized_apy(&mut self, delegation_contract: &Address) -> u64 {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_provider_realized_apy(delegation_contract)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn check_provider_realized_apy_error(
+        &mut self,
+        delegation_contract: &Address,
+        error: &[u8],
+    ) {
+        self.b_mock
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_provider_realized_apy(delegation_contract)
+            .returns(ExpectMessage(core::str::from_utf8(error).unwrap()))
+            .run();
+    }
+
+    pub fn check_epoch_snapshot_rewards(&mut self, count: usize, rewards: &[u64]) {
+        let snapshots = self
+            .b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_epoch_snapshots(count)
+            .returns(ReturnsResult)
+            .run();
+
+        let snapshot_rewards: Vec<BigUint<StaticApi>> = snapshots
+            .into_iter()
+            .map(|snapshot| snapshot.rewards)
+            .collect();
+        let expected_rewards: Vec<BigUint<StaticApi>> =
+            rewards.iter().map(|amount| exp18(*amount)).collect();
+        assert_eq!(snapshot_rewards, expected_rewards);
+    }
+
     pub fn get_fees_reserve(&mut self) -> u128 {
         let fees_value_biguint = self
             .b_mock
@@ -638,6 +2585,36 @@ impl LiquidStakingContractSetup {
         u128::from(fees_value_biguint.to_u64().unwrap())
     }
 
+    pub fn get_ls_fees_reserve(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .ls_fees_reserve()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn get_exchange_rate(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .get_exchange_rate()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn get_virtual_egld_reserve(&mut self) -> BigUint<StaticApi> {
+        self.b_mock
+            .query()
+            .to(&self.sc_wrapper)
+            .typed(proxy_liquid_staking::LiquidStakingProxy)
+            .virtual_egld_reserve()
+            .returns(ReturnsResult)
+            .run()
+    }
+
     pub fn print_pending_egld(&mut self) {
         let pending_egld_value_biguint = self
             .b_mock
@@ -735,3 +2712,72 @@ impl LiquidStakingContractSetup {
         }
     }
 }
+
+fn pausable_actions_arg(
+    actions: &[PausableAction],
+) -> MultiValueEncoded<StaticApi, PausableAction> {
+    let mut actions_arg = MultiValueEncoded::new();
+    for action in actions {
+        actions_arg.push(*action);
+    }
+    actions_arg
+}
+
+pub fn fee_recipients_arg(
+    recipients: &[(&Address, u64)],
+) -> ManagedVec<StaticApi, FeeRecipient<StaticApi>> {
+    let mut fee_recipients = ManagedVec::new();
+    for (address, share) in recipients {
+        fee_recipients.push(FeeRecipient {
+            address: ManagedAddress::from(*address),
+            share: *share,
+            endpoint: None,
+        });
+    }
+
+    fee_recipients
+}
+
+pub fn providers_arg(
+    providers: &[&Address],
+) -> MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>> {
+    let mut providers_arg = MultiValueEncoded::new();
+    for provider in providers {
+        providers_arg.push(ManagedAddress::from(*provider));
+    }
+
+    providers_arg
+}
+
+// Mirrors the contract defaults, used before any scoring config is set
+pub fn default_scoring_config() -> ScoringConfig {
+    ScoringConfig {
+        min_nodes: 1,
+        max_nodes: 100,
+        min_apy: 500,
+        max_apy: 1000,
+        min_service_fee: 0,
+        max_service_fee: 2_000,
+        min_uptime: 5_000,
+        max_uptime: 10_000,
+        min_age: 0,
+        max_age: 365,
+        min_top_up: 0,
+        max_top_up: 10_000,
+        stake_weight: 40,
+        apy_weight: 50,
+        nodes_weight: 10,
+        service_fee_weight: 0,
+        uptime_weight: 0,
+        age_weight: 0,
+        top_up_weight: 0,
+        stake_curve: ScoringCurve::Power(3),
+        apy_curve: ScoringCurve::Power(4),
+        nodes_curve: ScoringCurve::Power(3),
+        service_fee_curve: ScoringCurve::Linear,
+        uptime_curve: ScoringCurve::Linear,
+        age_curve: ScoringCurve::Linear,
+        top_up_curve: ScoringCurve::Linear,
+        max_score_per_category: 1000,
+    }
+}
@@ -4,8 +4,9 @@ use multiversx_sc::types::{
 };
 
 use multiversx_sc_scenario::{
-    api::StaticApi, imports::{ExecutorConfig, MxscPath}, managed_biguint, rust_biguint, ScenarioTxRun,
-    ScenarioTxWhitebox, ScenarioWorld,
+    api::StaticApi,
+    imports::{ExecutorConfig, MxscPath},
+    managed_biguint, rust_biguint, ScenarioTxRun, ScenarioTxWhitebox, ScenarioWorld,
 };
 
 use liquid_staking::config::ConfigModule;
@@ -16,7 +17,7 @@ use structs::ScoringConfig;
 
 use crate::exp18;
 
-extern crate accumulator;
+extern crate accumulator_mock;
 extern crate delegation_manager_mock;
 extern crate delegation_mock;
 extern crate liquid_staking;
@@ -35,10 +36,7 @@ pub const DELEGATION_MANAGER_DEPLOY_CODE: MxscPath =
 pub const ACCUMULATION_DEPLOY_CODE: MxscPath =
     MxscPath::new("liquid-staking/tests/accumulator.mxsc.json");
 
-pub static ESDT_ROLES: &[EsdtLocalRole] = &[
-    EsdtLocalRole::Mint,
-    EsdtLocalRole::Burn,
-];
+pub static ESDT_ROLES: &[EsdtLocalRole] = &[EsdtLocalRole::Mint, EsdtLocalRole::Burn];
 
 pub static SFT_ROLES: &[EsdtLocalRole] = &[
     EsdtLocalRole::NftCreate,
@@ -137,15 +135,15 @@ impl LiquidStakingContractSetup {
     }
 }
 
-pub fn world() -> ScenarioWorld {    
+pub fn world() -> ScenarioWorld {
     let mut blockchain =
-    ScenarioWorld::new().executor_config(ExecutorConfig::compiled_tests_if_else(
-        ExecutorConfig::Experimental.then(ExecutorConfig::Experimental),
-        ExecutorConfig::Debugger,
-    ));
+        ScenarioWorld::new().executor_config(ExecutorConfig::compiled_tests_if_else(
+            ExecutorConfig::Experimental.then(ExecutorConfig::Experimental),
+            ExecutorConfig::Debugger,
+        ));
 
     blockchain.register_contract(LIQUID_STAKING_DEPLOY_CODE, liquid_staking::ContractBuilder);
-    blockchain.register_contract(ACCUMULATION_DEPLOY_CODE, accumulator::ContractBuilder);
+    blockchain.register_contract(ACCUMULATION_DEPLOY_CODE, accumulator_mock::ContractBuilder);
     blockchain.register_contract(DELEGATION_DEPLOY_CODE, delegation_mock::ContractBuilder);
     blockchain.register_contract(
         DELEGATION_MANAGER_DEPLOY_CODE,
@@ -4,13 +4,16 @@ mod utils;
 
 use contract_setup::*;
 
-use multiversx_sc::{imports::OptionalValue, types::TestAddress};
+use multiversx_sc::{
+    imports::OptionalValue,
+    types::{Address, TestAddress},
+};
 use utils::*;
 
 use liquid_staking::{
     errors::{
-        ERROR_INSUFFICIENT_PENDING_EGLD, ERROR_MIN_EGLD_TO_DELEGATE, ERROR_NOT_ACTIVE,
-        ERROR_NOT_LIQUIDITY_PROVIDER,
+        ERROR_INSUFFICIENT_LS_OUT, ERROR_INSUFFICIENT_PENDING_EGLD, ERROR_INVALID_RECEIVER,
+        ERROR_MIN_EGLD_TO_DELEGATE, ERROR_NOT_ACTIVE, ERROR_NOT_LIQUIDITY_PROVIDER,
     },
     structs::UnstakeTokenAttributes,
 };
@@ -271,7 +274,6 @@ fn liquid_staking_add_liquidity_exp17_test() {
     sc_setup.check_user_balance(&second_user, LS_TOKEN_ID, exp17(5u64));
 }
 
-
 #[test]
 fn liquid_staking_add_liquidity_inactive_contract_error_test() {
     DebugApi::dummy();
@@ -478,3 +480,75 @@ fn liquidity_staking_provider_instant_no_minim() {
         OptionalValue::Some(managed_address!(&provider_1)),
     );
 }
+
+#[test]
+fn liquid_staking_delegate_min_out_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let first_user = sc_setup.setup_new_user(TestAddress::new("first_user"), 100u64);
+
+    // The exchange rate is 1:1, the minimum is exactly met
+    sc_setup.add_liquidity_min_out(&first_user, exp18(100u64), exp18(100u64));
+
+    sc_setup.check_contract_storage(100, 100, 0, 0, 100, 0);
+    sc_setup.check_user_balance(&first_user, LS_TOKEN_ID, exp18(100u64));
+}
+
+#[test]
+fn liquid_staking_delegate_min_out_error_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let first_user = sc_setup.setup_new_user(TestAddress::new("first_user"), 100u64);
+    let second_user = sc_setup.setup_new_user(TestAddress::new("second_user"), 11u64);
+    let rewards_user = sc_setup.setup_new_user(TestAddress::new("rewards_user"), 10u64);
+
+    sc_setup.add_liquidity(&first_user, exp18(100u64), OptionalValue::None);
+
+    // Rewards move the exchange rate to 1.1 between the quote and the execution
+    sc_setup.add_rewards(&rewards_user, exp18(10u64));
+    sc_setup.check_contract_storage(100, 110, 0, 0, 110, 0);
+
+    // 11 EGLD now mints only 10 xEGLD, the quoted 11 xEGLD are not met
+    sc_setup.add_liquidity_min_out_error(
+        &second_user,
+        exp18(11u64),
+        exp18(11u64),
+        ERROR_INSUFFICIENT_LS_OUT,
+    );
+
+    sc_setup.add_liquidity_min_out(&second_user, exp18(11u64), exp18(10u64));
+    sc_setup.check_user_balance(&second_user, LS_TOKEN_ID, exp18(10u64));
+    sc_setup.check_contract_storage(110, 121, 0, 0, 121, 0);
+}
+
+#[test]
+fn liquid_staking_delegate_for_receiver_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let vault = sc_setup.setup_new_user(TestAddress::new("vault"), 101u64);
+    let end_user = sc_setup.setup_new_user(TestAddress::new("end_user"), 0u64);
+
+    // The vault pays, the end user receives the minted xEGLD
+    sc_setup.add_liquidity_for(&vault, &end_user, exp18(100u64));
+
+    sc_setup.check_contract_storage(100, 100, 0, 0, 100, 0);
+    sc_setup.check_user_balance(&end_user, LS_TOKEN_ID, exp18(100u64));
+    sc_setup.check_user_balance(&vault, LS_TOKEN_ID, exp18(0u64));
+    sc_setup.check_user_egld_balance(&vault, exp18(1u64));
+
+    sc_setup.add_liquidity_for_error(
+        &vault,
+        &Address::zero(),
+        exp18(1u64),
+        ERROR_INVALID_RECEIVER,
+    );
+}
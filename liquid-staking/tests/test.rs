@@ -2,12 +2,42 @@ mod contract_interactions;
 mod contract_setup;
 mod utils;
 
+use contract_interactions::{default_scoring_config, fee_recipients_arg};
 use contract_setup::*;
 
-use liquid_staking::{errors::ERROR_NO_DELEGATION_CONTRACTS, structs::UnstakeTokenAttributes};
-use multiversx_sc::{imports::OptionalValue, types::TestAddress};
-use multiversx_sc_scenario::DebugApi;
-use utils::{exp, exp18};
+use liquid_staking::{
+    constants::{
+        BPS, DEFAULT_CONFIG_TIMELOCK_DELAY, MAX_EPOCH_SNAPSHOTS, MAX_FEES, MAX_INSURANCE_SHARE,
+        MAX_REBALANCE_SHARE, MAX_SCORING_CURVE_EXPONENT, MAX_UNBOND_PERIOD, MIN_UNBOND_PERIOD,
+        PROVIDER_APPLICATION_EXPIRY_EPOCHS, REFERRAL_ATTRIBUTION_EPOCHS,
+    },
+    errors::{
+        ERROR_ACTION_PAUSED, ERROR_ALREADY_WHITELISTED, ERROR_AUTO_APPROVAL_NOT_REACHED,
+        ERROR_CONFIG_PROPOSAL_NOT_FOUND, ERROR_FEES_CHANGE_COOLDOWN, ERROR_FEES_CHANGE_TOO_LARGE,
+        ERROR_FEES_TOO_HIGH, ERROR_INSUFFICIENT_PENDING_EGLD, ERROR_INSURANCE_SHARE_TOO_HIGH,
+        ERROR_INVALID_BATCH_SIZE, ERROR_INVALID_FEE_SPLIT, ERROR_INVALID_REBALANCE_AMOUNT,
+        ERROR_INVALID_SCORING_CONFIG, ERROR_INVALID_UNBOND_PERIOD, ERROR_LOSS_ABOVE_PROVIDER_STAKE,
+        ERROR_MISSING_ROLE, ERROR_NOTHING_TO_CLAIM, ERROR_NOTHING_TO_REBALANCE,
+        ERROR_NOT_DELEGATION_CONTRACT, ERROR_NOT_ENOUGH_EPOCH_SNAPSHOTS, ERROR_NOT_WHITELISTED,
+        ERROR_NO_DELEGATION_CONTRACTS, ERROR_NO_PROVIDER_YIELD, ERROR_NO_RECORDED_LOSS,
+        ERROR_ONLY_DELEGATION_ADMIN, ERROR_PROVIDER_APPLICATIONS_DISABLED,
+        ERROR_PROVIDER_APPLICATION_EXISTS, ERROR_PROVIDER_APPLICATION_EXPIRED,
+        ERROR_PROVIDER_CHECKS_FAILED, ERROR_PROVIDER_RETIRING, ERROR_REFERRAL_CODE_NOT_FOUND,
+        ERROR_REFERRAL_CODE_TAKEN, ERROR_REFERRAL_FOR_OTHER_ADDRESS, ERROR_SELF_REFERRAL,
+        ERROR_TIMELOCK_DELAY_TOO_SHORT, ERROR_TIMELOCK_NOT_EXPIRED, ERROR_TWAP_WINDOW_TOO_LONG,
+        ERROR_WEIGHTS_MUST_SUM_TO_100,
+    },
+    proxy::proxy_liquid_staking::{ConfigChange, FeeMode, PausableAction, Role, ScoringCurve},
+    structs::UnstakeTokenAttributes,
+};
+use multiversx_sc::{
+    imports::OptionalValue,
+    types::{Address, BigUint, TestAddress},
+};
+use multiversx_sc_scenario::{api::StaticApi, DebugApi};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use utils::{exp, exp15, exp17, exp18};
 
 #[test]
 fn init_test() {
@@ -440,3 +470,1455 @@ fn add_liquidity_no_valid_delegation_contract_error_test() {
         ERROR_NO_DELEGATION_CONTRACTS,
     );
 }
+
+#[test]
+fn liquid_staking_twap_exchange_rate_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let first_user = sc_setup.setup_new_user(TestAddress::new("first_user"), 100u64);
+    let rewards_user = sc_setup.setup_new_user(TestAddress::new("rewards_user"), 10u64);
+
+    sc_setup
+        .b_mock
+        .current_block()
+        .block_epoch(1u64)
+        .block_timestamp(1_000u64);
+    sc_setup.add_liquidity(&first_user, exp18(100u64), OptionalValue::None);
+
+    // Rate moves from 1 to 1.1 at the start of epoch 2
+    sc_setup
+        .b_mock
+        .current_block()
+        .block_epoch(2u64)
+        .block_timestamp(2_000u64);
+    sc_setup.add_rewards(&rewards_user, exp18(10u64));
+
+    sc_setup
+        .b_mock
+        .current_block()
+        .block_epoch(3u64)
+        .block_timestamp(3_000u64);
+
+    // Half of the window at 1, half at 1.1
+    sc_setup.check_twap_exchange_rate(2_000u64, exp15(1_050u64));
+    // A fifth of the window at 1, the rest at 1.1
+    sc_setup.check_twap_exchange_rate(1_250u64, exp15(1_080u64));
+    // Fully after the last rate change
+    sc_setup.check_twap_exchange_rate(500u64, exp17(11u64));
+    // The history starts with the first snapshot, at timestamp 1000
+    sc_setup.check_twap_exchange_rate_error(2_500u64, ERROR_TWAP_WINDOW_TOO_LONG);
+
+    sc_setup.check_rate_snapshot_epochs(10, &[1, 2]);
+    sc_setup.check_rate_snapshot_epochs(1, &[2]);
+    sc_setup.check_exchange_rate_growth(1u64, 2u64, exp17(11u64));
+}
+
+#[test]
+fn liquid_staking_provider_realized_apy_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let low_declared =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 500);
+    let high_declared =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 1_000);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+    let outside_delegator = sc_setup.setup_new_user(TestAddress::new("outside"), 1000u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.check_provider_realized_apy_error(&low_declared, ERROR_NO_PROVIDER_YIELD);
+
+    // The mock pays the rewards of its whole deposit, the outside delegation makes the
+    // provider declaring the lowest APY pay the most to the contract
+    sc_setup.delegate_to_provider(&outside_delegator, &low_declared, exp18(1000u64));
+
+    sc_setup.b_mock.current_block().block_epoch(10u64);
+    sc_setup.claim_rewards(&OWNER_ADDRESS.to_address());
+
+    // The high declared provider only pays the 10% of the mock on the stake of the contract
+    let low_declared_apy = sc_setup.get_provider_realized_apy(&low_declared);
+    let high_declared_apy = sc_setup.get_provider_realized_apy(&high_declared);
+    assert!(high_declared_apy > 990 && high_declared_apy <= 1_000);
+    assert!(low_declared_apy > 2 * high_declared_apy);
+
+    let mut config = default_scoring_config();
+    config.max_apy = 2_000;
+    config.stake_weight = 0;
+    config.nodes_weight = 0;
+    config.apy_weight = 100;
+    config.apy_curve = ScoringCurve::Linear;
+    sc_setup.set_scoring_config(&OWNER_ADDRESS.to_address(), config);
+
+    // The declared APYs rank the providers one way, the measured yields the other
+    let allocations = sc_setup.get_provider_allocations();
+    assert_eq!(allocations[0].address.to_address(), low_declared);
+    assert!(allocations[0].target < allocations[1].target);
+
+    sc_setup.set_realized_apy_scoring(&OWNER_ADDRESS.to_address(), true);
+    let allocations = sc_setup.get_provider_allocations();
+    assert!(allocations[0].target > allocations[1].target);
+}
+
+#[test]
+fn liquid_staking_realized_apr_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 1000);
+
+    let first_user = sc_setup.setup_new_user(TestAddress::new("first_user"), 100u64);
+    let rewards_user = sc_setup.setup_new_user(TestAddress::new("rewards_user"), 100u64);
+
+    sc_setup.b_mock.current_block().block_epoch(1u64);
+    sc_setup.add_liquidity(&first_user, exp18(100u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    // A single snapshot is not enough to measure anything
+    sc_setup.add_rewards(&rewards_user, exp18(10u64));
+    sc_setup.check_realized_apr_error(1u64, ERROR_NOT_ENOUGH_EPOCH_SNAPSHOTS);
+
+    // The rate grows from 1.1 to 1.21 in 10 epochs, 10% * 365 / 10
+    sc_setup.b_mock.current_block().block_epoch(11u64);
+    sc_setup.add_rewards(&rewards_user, exp18(11u64));
+
+    sc_setup.check_realized_apr(10u64, 36_500u64);
+    sc_setup.check_realized_apr(5u64, 36_500u64);
+    sc_setup.check_realized_apr_error(11u64, ERROR_NOT_ENOUGH_EPOCH_SNAPSHOTS);
+
+    // Rewards added by hand are not paid by any provider
+    sc_setup.check_provider_realized_apy_error(&delegation_contract, ERROR_NO_PROVIDER_YIELD);
+
+    sc_setup.check_epoch_snapshot_rewards(10, &[10, 11]);
+    sc_setup.check_epoch_snapshot_rewards(1, &[11]);
+
+    // The oldest snapshots are overwritten once the ring buffer is full
+    for epoch in 12..12 + MAX_EPOCH_SNAPSHOTS as u64 {
+        sc_setup.b_mock.current_block().block_epoch(epoch);
+        sc_setup.add_rewards(&rewards_user, exp18(1u64));
+    }
+    let last_epoch = 11 + MAX_EPOCH_SNAPSHOTS as u64;
+    sc_setup.check_epoch_snapshot_rewards(MAX_EPOCH_SNAPSHOTS + 1, &[1; MAX_EPOCH_SNAPSHOTS]);
+    sc_setup.check_realized_apr_error(last_epoch - 11, ERROR_NOT_ENOUGH_EPOCH_SNAPSHOTS);
+}
+
+#[test]
+fn liquid_staking_invariants_fuzz_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+    const SEED: u64 = 1337; // Fixed seed for reproducible tests
+    const STEPS: usize = 300;
+    const UNBOND_PERIOD: u64 = 10;
+    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+    sc_setup.set_early_redemption_config(1000, 100, true);
+
+    let users = [
+        sc_setup.setup_new_user(TestAddress::new("first_user"), 1000u64),
+        sc_setup.setup_new_user(TestAddress::new("second_user"), 1000u64),
+        sc_setup.setup_new_user(TestAddress::new("third_user"), 1000u64),
+    ];
+    let rewards_user = sc_setup.setup_new_user(TestAddress::new("rewards_user"), 10u64);
+    // Claimed rewards move the exchange rate, so the minted LS tokens are read from the supply
+    let mut ls_balances = vec![BigUint::<StaticApi>::zero(); users.len()];
+    // (user index, nonce, amount, unbond epoch) of every unstake position held by the users
+    let mut positions: Vec<(usize, u64, BigUint<StaticApi>, u64)> = Vec::new();
+    // (user index, ticket id) of every withdraw ticket not fully claimed yet
+    let mut tickets: Vec<(usize, u64)> = Vec::new();
+
+    let mut epoch = 50u64;
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.b_mock.current_block().block_epoch(epoch);
+    sc_setup.check_invariants_hold();
+
+    for _ in 0..STEPS {
+        let user_index = rng.random_range(0..users.len());
+        let user = &users[user_index];
+        match rng.random_range(0..12) {
+            0 => {
+                let amount = rng.random_range(1..=20u64);
+                let ls_supply = sc_setup.get_ls_token_supply();
+                sc_setup.add_liquidity(user, exp18(amount), OptionalValue::None);
+                ls_balances[user_index] += sc_setup.get_ls_token_supply() - ls_supply;
+            }
+            1 => {
+                let whole_ls = (&ls_balances[user_index] / &exp18(1)).to_u64().unwrap();
+                if whole_ls > 0 {
+                    let ls_amount = exp18(rng.random_range(1..=whole_ls));
+                    let egld_value =
+                        sc_setup.get_ls_value_for_position_denominated(ls_amount.clone());
+                    let pending_egld = sc_setup.get_pending_egld();
+
+                    // Splits leaving less than the minimum on either side are rejected by design
+                    if pending_egld >= &egld_value + &exp18(1)
+                        || egld_value >= &pending_egld + &exp18(1)
+                    {
+                        let pending_for_unstake = sc_setup.get_pending_egld_for_unstake();
+                        sc_setup.remove_liquidity(user, LS_TOKEN_ID, ls_amount.clone());
+                        ls_balances[user_index] -= &ls_amount;
+
+                        let unstaked =
+                            sc_setup.get_pending_egld_for_unstake() - pending_for_unstake;
+                        if unstaked > 0u64 {
+                            let unbond_epoch = epoch + UNBOND_PERIOD;
+                            let nonce = sc_setup.get_unstake_token_nonce(unbond_epoch);
+                            positions.push((user_index, nonce, unstaked, unbond_epoch));
+                        }
+                    }
+                }
+            }
+            2 => {
+                if sc_setup.get_pending_egld() > 0u64 {
+                    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+                }
+            }
+            3 => {
+                if sc_setup.get_pending_egld_for_unstake() > 0u64 {
+                    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+                }
+            }
+            4 => {
+                for provider in sc_setup.get_withdrawable_providers() {
+                    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &provider);
+                }
+            }
+            5 => {
+                sc_setup.claim_rewards(&OWNER_ADDRESS.to_address());
+            }
+            6 => {
+                // Direct withdrawals are only open while no ticket is waiting in the queue
+                let total_withdrawn_egld = sc_setup.get_total_withdrawn_egld();
+                if total_withdrawn_egld > 0u64 && sc_setup.get_withdraw_queue_length() == 0 {
+                    let matured = positions.iter().position(|(owner, _, _, unbond_epoch)| {
+                        *owner == user_index && *unbond_epoch <= epoch
+                    });
+                    if let Some(index) = matured {
+                        let (_, nonce, amount, _) = positions[index].clone();
+                        sc_setup.withdraw(user, UNSTAKE_TOKEN_ID, nonce, amount.clone());
+
+                        // The part not covered by the unbonded EGLD is sent back
+                        if amount > total_withdrawn_egld {
+                            positions[index].2 = amount - total_withdrawn_egld;
+                        } else {
+                            positions.remove(index);
+                        }
+                    }
+                }
+            }
+            7 => {
+                let matured = positions
+                    .iter()
+                    .position(|(owner, _, amount, unbond_epoch)| {
+                        *owner == user_index && *unbond_epoch <= epoch && *amount >= exp18(1)
+                    });
+                if let Some(index) = matured {
+                    let (_, nonce, amount, _) = positions.remove(index);
+                    let ticket_id = sc_setup.request_withdraw_denominated(user, &[(nonce, amount)]);
+                    tickets.push((user_index, ticket_id));
+                }
+            }
+            8 => {
+                let mut claimable = Vec::new();
+                let mut settled = Vec::new();
+                for (_, ticket_id) in tickets.iter().filter(|(owner, _)| *owner == user_index) {
+                    let ticket = sc_setup.get_withdraw_ticket(*ticket_id);
+                    if ticket.filled > 0u64 {
+                        claimable.push(*ticket_id);
+                    }
+                    if ticket.filled == ticket.amount {
+                        settled.push(*ticket_id);
+                    }
+                }
+
+                if !claimable.is_empty() {
+                    sc_setup.claim_withdraw(user, &claimable);
+                    tickets.retain(|(_, ticket_id)| !settled.contains(ticket_id));
+                }
+            }
+            9 => {
+                let unbonding = positions.iter().position(|(owner, _, _, unbond_epoch)| {
+                    *owner == user_index && *unbond_epoch > epoch
+                });
+                if let Some(index) = unbonding {
+                    let (_, nonce, amount, _) = positions[index].clone();
+                    let (egld_out, _) = sc_setup.get_early_redemption_quote(nonce, amount.clone());
+                    let pending_egld = sc_setup.get_pending_egld();
+
+                    // The redemption must leave either nothing or the minimum in the pending EGLD
+                    if pending_egld == egld_out || pending_egld >= &egld_out + &exp18(1) {
+                        sc_setup.redeem_early(user, nonce, amount, OptionalValue::None);
+                        positions.remove(index);
+                    }
+                }
+            }
+            _ => {
+                epoch += rng.random_range(1..=5u64);
+                sc_setup.b_mock.current_block().block_epoch(epoch);
+            }
+        }
+
+        sc_setup.check_invariants_hold();
+    }
+
+    sc_setup.add_rewards(&rewards_user, exp18(10u64));
+    sc_setup.check_invariants_hold();
+}
+
+#[test]
+fn liquid_staking_pause_actions_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+    let guardian = sc_setup.setup_new_user(TestAddress::new("guardian"), 0u64);
+
+    sc_setup.add_liquidity(&user, exp18(50u64), OptionalValue::None);
+
+    sc_setup.pause_actions_error(&guardian, &[PausableAction::Delegate], ERROR_MISSING_ROLE);
+    sc_setup.grant_role(&guardian, Role::Guardian);
+    sc_setup.pause_actions(
+        &guardian,
+        &[PausableAction::Delegate, PausableAction::DelegatePending],
+    );
+    sc_setup.check_action_paused(PausableAction::Delegate, true);
+    sc_setup.check_action_paused(PausableAction::UnDelegate, false);
+
+    // Deposits are stopped, users can still exit
+    sc_setup.add_liquidity_error(
+        &user,
+        exp18(10u64),
+        ERROR_ACTION_PAUSED,
+        OptionalValue::None,
+    );
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending_error(
+        &OWNER_ADDRESS.to_address(),
+        OptionalValue::None,
+        ERROR_ACTION_PAUSED,
+    );
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(20u64));
+    sc_setup.check_contract_storage(30, 30, 0, 0, 30, 0);
+
+    // A guardian can only pause
+    sc_setup.unpause_actions_error(
+        &guardian,
+        &[PausableAction::Delegate],
+        b"Endpoint can only be called by owner",
+    );
+
+    sc_setup.unpause_actions(&OWNER_ADDRESS.to_address(), &[PausableAction::Delegate]);
+    sc_setup.check_action_paused(PausableAction::Delegate, false);
+    sc_setup.check_action_paused(PausableAction::DelegatePending, true);
+    sc_setup.add_liquidity(&user, exp18(10u64), OptionalValue::None);
+    sc_setup.check_contract_storage(40, 40, 0, 0, 40, 0);
+
+    // Provider withdrawals have their own flag, user withdrawals stay open
+    sc_setup.pause_actions(&guardian, &[PausableAction::WithdrawPending]);
+    sc_setup.check_action_paused(PausableAction::WithdrawPending, true);
+    sc_setup.check_action_paused(PausableAction::Withdraw, false);
+    sc_setup.withdraw_pending_error(
+        &OWNER_ADDRESS.to_address(),
+        &delegation_contract,
+        ERROR_ACTION_PAUSED,
+    );
+    sc_setup.withdraw_pending_batch_error(&OWNER_ADDRESS.to_address(), 1, ERROR_ACTION_PAUSED);
+}
+
+#[test]
+fn liquid_staking_config_timelock_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 1u64);
+
+    sc_setup.b_mock.current_block().block_epoch(10u64);
+    sc_setup.propose_config_change_error(
+        &user,
+        ConfigChange::Fees(BigUint::from(500u64)),
+        b"Endpoint can only be called by owner",
+    );
+
+    let fees_proposal = sc_setup.propose_config_change(ConfigChange::Fees(BigUint::from(500u64)));
+    let unbond_proposal = sc_setup.propose_config_change(ConfigChange::UnbondPeriod(12u64));
+    sc_setup.check_pending_config_proposals(&[fees_proposal, unbond_proposal]);
+
+    // The default delay is 3 epochs
+    sc_setup.b_mock.current_block().block_epoch(12u64);
+    sc_setup.execute_config_change_error(&user, fees_proposal, ERROR_TIMELOCK_NOT_EXPIRED);
+    sc_setup.check_fees(400);
+
+    sc_setup.cancel_config_change(unbond_proposal);
+    sc_setup.check_pending_config_proposals(&[fees_proposal]);
+
+    // The unbond period stays within the bounds
+    sc_setup.propose_config_change_error(
+        &OWNER_ADDRESS.to_address(),
+        ConfigChange::UnbondPeriod(MIN_UNBOND_PERIOD - 1),
+        ERROR_INVALID_UNBOND_PERIOD,
+    );
+    sc_setup.propose_config_change_error(
+        &OWNER_ADDRESS.to_address(),
+        ConfigChange::UnbondPeriod(MAX_UNBOND_PERIOD + 1),
+        ERROR_INVALID_UNBOND_PERIOD,
+    );
+
+    // Anyone can execute a matured proposal, but only once
+    sc_setup.b_mock.current_block().block_epoch(13u64);
+    sc_setup.execute_config_change(&user, fees_proposal);
+    sc_setup.check_fees(500);
+    sc_setup.check_pending_config_proposals(&[]);
+    sc_setup.execute_config_change_error(&user, fees_proposal, ERROR_CONFIG_PROPOSAL_NOT_FOUND);
+
+    // The delay can be raised but never brought under the default
+    sc_setup.propose_config_change_error(
+        &OWNER_ADDRESS.to_address(),
+        ConfigChange::TimelockDelay(DEFAULT_CONFIG_TIMELOCK_DELAY - 1),
+        ERROR_TIMELOCK_DELAY_TOO_SHORT,
+    );
+    sc_setup.propose_config_change(ConfigChange::TimelockDelay(
+        DEFAULT_CONFIG_TIMELOCK_DELAY + 2,
+    ));
+    sc_setup.execute_config_change_error(&user, unbond_proposal, ERROR_CONFIG_PROPOSAL_NOT_FOUND);
+}
+
+fn claim_rewards_with_fees(fees: u64) {
+    let mut sc_setup = LiquidStakingContractSetup::new(fees);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    let virtual_egld_reserve_before = sc_setup.get_virtual_egld_reserve();
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.claim_rewards(&OWNER_ADDRESS.to_address());
+
+    let fees_reserve = BigUint::from(sc_setup.get_fees_reserve());
+    let distributed_rewards = sc_setup.get_virtual_egld_reserve() - virtual_egld_reserve_before;
+    let total_rewards = &distributed_rewards + &fees_reserve;
+    assert!(total_rewards > 0u64);
+    assert_eq!(
+        fees_reserve,
+        total_rewards * BigUint::from(fees) / BigUint::from(10_000u64)
+    );
+}
+
+#[test]
+fn liquid_staking_fees_bounds_rewards_test() {
+    DebugApi::dummy();
+    claim_rewards_with_fees(0);
+    claim_rewards_with_fees(MAX_FEES);
+}
+
+#[test]
+fn liquid_staking_fees_change_limits_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(1_800);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 1u64);
+
+    sc_setup.propose_config_change_error(
+        &OWNER_ADDRESS.to_address(),
+        ConfigChange::Fees(BigUint::from(MAX_FEES + 1)),
+        ERROR_FEES_TOO_HIGH,
+    );
+    sc_setup.propose_config_change_error(
+        &OWNER_ADDRESS.to_address(),
+        ConfigChange::Fees(BigUint::from(1_299u64)),
+        ERROR_FEES_CHANGE_TOO_LARGE,
+    );
+
+    sc_setup.b_mock.current_block().block_epoch(10u64);
+    let first_proposal =
+        sc_setup.propose_config_change(ConfigChange::Fees(BigUint::from(1_300u64)));
+    sc_setup.b_mock.current_block().block_epoch(13u64);
+    sc_setup.execute_config_change(&user, first_proposal);
+    sc_setup.check_fees(1_300);
+    sc_setup.check_last_fees_change_epoch(13);
+
+    // Each change is bounded, even if it is proposed right away
+    let second_proposal =
+        sc_setup.propose_config_change(ConfigChange::Fees(BigUint::from(1_800u64)));
+    sc_setup.b_mock.current_block().block_epoch(16u64);
+    sc_setup.execute_config_change_error(&user, second_proposal, ERROR_FEES_CHANGE_COOLDOWN);
+    sc_setup.check_fees(1_300);
+
+    sc_setup.b_mock.current_block().block_epoch(20u64);
+    sc_setup.execute_config_change(&user, second_proposal);
+    sc_setup.check_fees(1_800);
+    sc_setup.check_last_fees_change_epoch(20);
+}
+
+#[test]
+fn liquid_staking_fee_split_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+    let treasury = sc_setup.setup_new_user(TestAddress::new("treasury"), 0u64);
+    let insurance = sc_setup.setup_new_user(TestAddress::new("insurance"), 0u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.claim_rewards(&OWNER_ADDRESS.to_address());
+
+    sc_setup.propose_config_change_error(
+        &OWNER_ADDRESS.to_address(),
+        ConfigChange::FeeRecipients(fee_recipients_arg(&[
+            (&treasury, 7_000),
+            (&insurance, 2_000),
+        ])),
+        ERROR_INVALID_FEE_SPLIT,
+    );
+    let proposal_id =
+        sc_setup.propose_config_change(ConfigChange::FeeRecipients(fee_recipients_arg(&[
+            (&treasury, 7_000),
+            (&insurance, 3_000),
+        ])));
+    sc_setup.b_mock.current_block().block_epoch(53u64);
+    sc_setup.execute_config_change(&user, proposal_id);
+
+    // The last recipient gets the rounding dust
+    let fees_reserve = sc_setup.get_fees_reserve();
+    let treasury_fees = fees_reserve * 7_000 / 10_000;
+    let insurance_fees = fees_reserve - treasury_fees;
+    assert!(treasury_fees > 0);
+    sc_setup.check_accrued_fees(&[
+        (&treasury, treasury_fees, 0),
+        (&insurance, insurance_fees, 0),
+    ]);
+
+    // Anyone can push the fees to the recipients
+    sc_setup.claim_fees(&user);
+    sc_setup.check_user_egld_balance(&treasury, exp(treasury_fees));
+    sc_setup.check_user_egld_balance(&insurance, exp(insurance_fees));
+    assert_eq!(sc_setup.get_fees_reserve(), 0);
+    sc_setup.check_accrued_fees(&[(&treasury, 0, 0), (&insurance, 0, 0)]);
+}
+
+fn claim_rewards_in_fee_mode(fee_mode: FeeMode) -> (LiquidStakingContractSetup, Address) {
+    let mut sc_setup = LiquidStakingContractSetup::new(1_000);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+    let treasury = sc_setup.setup_new_user(TestAddress::new("treasury"), 0u64);
+
+    let recipients_proposal = sc_setup.propose_config_change(ConfigChange::FeeRecipients(
+        fee_recipients_arg(&[(&treasury, 10_000)]),
+    ));
+    let mode_proposal = sc_setup.propose_config_change(ConfigChange::FeeMode(fee_mode));
+    sc_setup.b_mock.current_block().block_epoch(3u64);
+    sc_setup.execute_config_change(&user, recipients_proposal);
+    sc_setup.execute_config_change(&user, mode_proposal);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.claim_rewards(&OWNER_ADDRESS.to_address());
+
+    (sc_setup, treasury)
+}
+
+#[test]
+fn liquid_staking_minted_fees_test() {
+    DebugApi::dummy();
+    let (mut egld_setup, _) = claim_rewards_in_fee_mode(FeeMode::Egld);
+    let (mut minted_setup, treasury) = claim_rewards_in_fee_mode(FeeMode::MintedLs);
+
+    assert!(egld_setup.get_fees_reserve() > 0);
+    assert_eq!(egld_setup.get_ls_fees_reserve(), 0u64);
+    assert_eq!(minted_setup.get_fees_reserve(), 0);
+
+    // Holders get the same rate, up to the rounding of the minted amount
+    let egld_mode_rate = egld_setup.get_exchange_rate();
+    let minted_mode_rate = minted_setup.get_exchange_rate();
+    assert!(egld_mode_rate > exp18(1u64));
+    let rate_delta = if egld_mode_rate > minted_mode_rate {
+        egld_mode_rate - minted_mode_rate
+    } else {
+        minted_mode_rate - egld_mode_rate
+    };
+    assert!(rate_delta <= 1u64);
+
+    let ls_fees = minted_setup.get_ls_fees_reserve();
+    assert!(ls_fees > 0u64);
+    minted_setup.claim_fees(&OWNER_ADDRESS.to_address());
+    minted_setup.check_user_balance(&treasury, LS_TOKEN_ID, ls_fees);
+    minted_setup.check_user_egld_balance(&treasury, exp18(0u64));
+    assert_eq!(minted_setup.get_ls_fees_reserve(), 0u64);
+}
+
+#[test]
+fn liquid_staking_referral_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(1_000);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+    let partner = sc_setup.setup_new_user(TestAddress::new("partner"), 10u64);
+    let other_partner = sc_setup.setup_new_user(TestAddress::new("other_partner"), 10u64);
+    let referred_user = sc_setup.setup_new_user(TestAddress::new("referred_user"), 60u64);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 50u64);
+
+    sc_setup.register_referrer(&partner, b"partner");
+    sc_setup.register_referrer_error(&other_partner, b"partner", ERROR_REFERRAL_CODE_TAKEN);
+    sc_setup.add_liquidity_with_referral_error(
+        &partner,
+        exp18(10u64),
+        b"partner",
+        ERROR_SELF_REFERRAL,
+    );
+    sc_setup.add_liquidity_with_referral_error(
+        &user,
+        exp18(40u64),
+        b"unknown",
+        ERROR_REFERRAL_CODE_NOT_FOUND,
+    );
+
+    let proposal_id = sc_setup.propose_config_change(ConfigChange::ReferralFeeShare(2_000));
+    sc_setup.b_mock.current_block().block_epoch(3u64);
+    sc_setup.execute_config_change(&user, proposal_id);
+
+    sc_setup.add_liquidity_with_referral(&referred_user, exp18(60u64), b"partner");
+    sc_setup.add_liquidity(&user, exp18(40u64), OptionalValue::None);
+    sc_setup.check_user_referred_ls(&referred_user, exp18(60u64));
+    sc_setup.check_user_referred_ls(&user, exp18(0u64));
+
+    // A deposit made for another address cannot pick its referrer
+    sc_setup.add_liquidity_for_with_referral_error(
+        &user,
+        &other_partner,
+        exp18(10u64),
+        b"partner",
+        ERROR_REFERRAL_FOR_OTHER_ADDRESS,
+    );
+    sc_setup.add_liquidity_for_with_referral(
+        &other_partner,
+        &other_partner,
+        exp18(10u64),
+        b"partner",
+    );
+    sc_setup.check_user_referred_ls(&other_partner, exp18(10u64));
+    sc_setup.check_user_referred_ls(&user, exp18(0u64));
+
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.claim_rewards(&OWNER_ADDRESS.to_address());
+    sc_setup.check_invariants_hold();
+
+    // Referrers take their share out of the protocol fees
+    let referral_rewards = sc_setup.get_referral_rewards_reserve();
+    let total_fees = BigUint::from(sc_setup.get_fees_reserve()) + &referral_rewards;
+    assert!(referral_rewards > 0u64);
+    assert_eq!(
+        referral_rewards,
+        total_fees * BigUint::from(2_000u64) / BigUint::from(10_000u64)
+    );
+
+    // The only referrer gets all of it, up to the rounding of the rewards per xEGLD
+    let referrer = sc_setup.get_referrer(&partner);
+    assert_eq!(referrer.total_volume, exp18(70u64));
+    assert_eq!(referrer.attributed_ls, exp18(70u64));
+    assert!(referrer.rewards <= referral_rewards);
+    assert!(&referral_rewards - &referrer.rewards <= 70u64);
+
+    // Un-delegated xEGLD stops earning, the volume is kept
+    sc_setup.remove_liquidity(&referred_user, LS_TOKEN_ID, exp18(20u64));
+    sc_setup.check_user_referred_ls(&referred_user, exp18(40u64));
+    let referrer = sc_setup.get_referrer(&partner);
+    assert_eq!(referrer.total_volume, exp18(70u64));
+    assert_eq!(referrer.attributed_ls, exp18(50u64));
+
+    let claimed = sc_setup.claim_referral_rewards(&partner);
+    assert_eq!(claimed, referrer.rewards);
+    sc_setup.check_user_egld_balance(&partner, exp18(10u64) + &claimed);
+    sc_setup.claim_referral_rewards_error(&partner, ERROR_NOTHING_TO_CLAIM);
+    sc_setup.check_invariants_hold();
+
+    // Transferred xEGLD cannot be followed, the attributions expire instead
+    let expiry_epoch = 3u64 + REFERRAL_ATTRIBUTION_EPOCHS;
+    sc_setup
+        .b_mock
+        .current_block()
+        .block_epoch(expiry_epoch - 1);
+    sc_setup.release_expired_referrals(&user, &[&referred_user, &other_partner]);
+    assert_eq!(sc_setup.get_referrer(&partner).attributed_ls, exp18(50u64));
+
+    sc_setup.b_mock.current_block().block_epoch(expiry_epoch);
+    sc_setup.release_expired_referrals(&user, &[&referred_user, &other_partner]);
+    sc_setup.check_user_referred_ls(&referred_user, exp18(0u64));
+    sc_setup.check_user_referred_ls(&other_partner, exp18(0u64));
+    let referrer = sc_setup.get_referrer(&partner);
+    assert_eq!(referrer.total_volume, exp18(70u64));
+    assert_eq!(referrer.attributed_ls, exp18(0u64));
+    sc_setup.check_invariants_hold();
+}
+
+#[test]
+fn liquid_staking_referral_hijack_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(1_000);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+    let partner = sc_setup.setup_new_user(TestAddress::new("partner"), 0u64);
+    let attacker = sc_setup.setup_new_user(TestAddress::new("attacker"), 1u64);
+    let victim = sc_setup.setup_new_user(TestAddress::new("victim"), 100u64);
+
+    sc_setup.register_referrer(&partner, b"partner");
+    sc_setup.register_referrer(&attacker, b"attacker");
+
+    // A dust deposit for the victim cannot make the attacker its referrer
+    sc_setup.add_liquidity_for_with_referral_error(
+        &attacker,
+        &victim,
+        exp18(1u64),
+        b"attacker",
+        ERROR_REFERRAL_FOR_OTHER_ADDRESS,
+    );
+    sc_setup.add_liquidity_for(&attacker, &victim, exp18(1u64));
+    sc_setup.check_user_referred_ls(&victim, exp18(0u64));
+
+    // The referrer picked by the victim gets the attribution
+    sc_setup.add_liquidity_with_referral(&victim, exp18(100u64), b"partner");
+    sc_setup.check_user_referred_ls(&victim, exp18(100u64));
+    assert_eq!(sc_setup.get_referrer(&partner).attributed_ls, exp18(100u64));
+    assert_eq!(sc_setup.get_referrer(&attacker).attributed_ls, exp18(0u64));
+}
+
+#[test]
+fn liquid_staking_insurance_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(1_000);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+    let guardian = sc_setup.setup_new_user(TestAddress::new("guardian"), 0u64);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+    sc_setup.grant_role(&guardian, Role::Guardian);
+
+    sc_setup.propose_config_change_error(
+        &OWNER_ADDRESS.to_address(),
+        ConfigChange::InsuranceShare(MAX_INSURANCE_SHARE + 1),
+        ERROR_INSURANCE_SHARE_TOO_HIGH,
+    );
+    let proposal_id = sc_setup.propose_config_change(ConfigChange::InsuranceShare(500));
+    sc_setup.b_mock.current_block().block_epoch(3u64);
+    sc_setup.execute_config_change(&user, proposal_id);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.claim_rewards(&OWNER_ADDRESS.to_address());
+    sc_setup.check_invariants_hold();
+
+    // The insurance share is taken from the rewards, next to the protocol fees
+    let insurance = sc_setup.get_insurance_reserve();
+    let total_rewards = sc_setup.get_virtual_egld_reserve() - exp18(100u64)
+        + BigUint::from(sc_setup.get_fees_reserve())
+        + &insurance;
+    assert!(insurance > 0u64);
+    assert_eq!(
+        insurance,
+        total_rewards * BigUint::from(500u64) / BigUint::from(10_000u64)
+    );
+
+    sc_setup.record_provider_loss_error(
+        &user,
+        &delegation_contract,
+        exp18(1u64),
+        ERROR_MISSING_ROLE,
+    );
+    sc_setup.record_provider_loss_error(
+        &guardian,
+        &delegation_contract,
+        exp18(101u64),
+        ERROR_LOSS_ABOVE_PROVIDER_STAKE,
+    );
+    sc_setup.socialize_losses_error(&guardian, ERROR_NO_RECORDED_LOSS);
+
+    // A recorded loss leaves the exchange rate untouched until it is socialized
+    let virtual_egld_reserve = sc_setup.get_virtual_egld_reserve();
+    let pending_egld = sc_setup.get_pending_egld();
+    sc_setup.record_provider_loss(&guardian, &delegation_contract, exp18(2u64));
+    sc_setup.check_delegation_contract_values(&delegation_contract, exp18(98u64), exp18(0u64));
+    assert_eq!(sc_setup.get_recorded_losses(), exp18(2u64));
+    assert_eq!(sc_setup.get_virtual_egld_reserve(), virtual_egld_reserve);
+    sc_setup.check_invariants_hold();
+
+    // The reserve covers the loss first, only the remainder hits the holders
+    let (covered, uncovered) = sc_setup.socialize_losses(&guardian);
+    assert_eq!(covered, insurance);
+    assert_eq!(uncovered, exp18(2u64) - &insurance);
+    assert_eq!(
+        sc_setup.get_virtual_egld_reserve(),
+        virtual_egld_reserve - &uncovered
+    );
+    assert_eq!(sc_setup.get_pending_egld(), pending_egld.clone() + &covered);
+    assert_eq!(sc_setup.get_insurance_reserve(), exp18(0u64));
+    assert_eq!(sc_setup.get_recorded_losses(), exp18(0u64));
+    sc_setup.check_invariants_hold();
+
+    // The loss was recorded by mistake, the stake found again refunds the holders and the
+    // reserve instead of being booked as rewards
+    let fees_reserve = sc_setup.get_fees_reserve();
+    sc_setup.reconcile_providers(&OWNER_ADDRESS.to_address(), &[&delegation_contract]);
+    sc_setup.check_delegation_contract_values(&delegation_contract, exp18(100u64), exp18(0u64));
+    assert_eq!(sc_setup.get_virtual_egld_reserve(), virtual_egld_reserve);
+    assert_eq!(sc_setup.get_insurance_reserve(), insurance);
+    assert_eq!(sc_setup.get_pending_egld(), pending_egld);
+    assert_eq!(sc_setup.get_fees_reserve(), fees_reserve);
+    sc_setup.check_invariants_hold();
+}
+
+#[test]
+fn liquid_staking_provider_reconciliation_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(1_000);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+    let keeper = sc_setup.setup_new_user(TestAddress::new("keeper"), 0u64);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 107u64);
+    sc_setup.grant_role(&keeper, Role::Keeper);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.reconcile_providers_error(&user, &[&delegation_contract], ERROR_MISSING_ROLE);
+    sc_setup.reconcile_providers_error(&keeper, &[], ERROR_INVALID_BATCH_SIZE);
+
+    // The whitelisting seed is not mistaken for a surplus
+    sc_setup.reconcile_providers(&keeper, &[&delegation_contract]);
+    sc_setup.check_delegation_contract_values(&delegation_contract, exp18(100u64), exp18(0u64));
+    assert_eq!(sc_setup.get_virtual_egld_reserve(), exp18(100u64));
+    assert!(sc_setup.is_provider_eligible(&delegation_contract));
+
+    // A slashing is socialized and the provider stops receiving delegations
+    sc_setup.slash_provider(&delegation_contract, exp18(3u64));
+    sc_setup.reconcile_providers(&keeper, &[&delegation_contract]);
+    sc_setup.check_delegation_contract_values(&delegation_contract, exp18(97u64), exp18(0u64));
+    assert_eq!(sc_setup.get_virtual_egld_reserve(), exp18(97u64));
+    assert_eq!(sc_setup.get_recorded_losses(), exp18(0u64));
+    assert!(!sc_setup.is_provider_eligible(&delegation_contract));
+    sc_setup.check_invariants_hold();
+
+    // The mock keeps a single delegator, a direct delegation shows up as a surplus.
+    // Its fees are paid from the pending EGLD, none is pending yet.
+    sc_setup.delegate_to_provider(&user, &delegation_contract, exp18(2u64));
+    sc_setup.reconcile_providers(&keeper, &[&delegation_contract]);
+    sc_setup.check_delegation_contract_values(&delegation_contract, exp18(97u64), exp18(0u64));
+    assert_eq!(sc_setup.get_virtual_egld_reserve(), exp18(97u64));
+    sc_setup.check_invariants_hold();
+
+    // The surplus is booked like claimed rewards, 10% of it goes to the fees
+    sc_setup.add_liquidity(&user, exp18(5u64), OptionalValue::None);
+    sc_setup.reconcile_providers(&keeper, &[&delegation_contract]);
+    sc_setup.check_delegation_contract_values(&delegation_contract, exp18(99u64), exp18(0u64));
+    assert_eq!(sc_setup.get_virtual_egld_reserve(), exp17(1038u64));
+    assert_eq!(sc_setup.get_pending_egld(), exp17(48u64));
+    assert_eq!(BigUint::from(sc_setup.get_fees_reserve()), exp17(2u64));
+    sc_setup.check_invariants_hold();
+}
+
+#[test]
+fn liquid_staking_sync_provider_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 5, 8_000);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.sync_provider_error(&user, &user, ERROR_NOT_WHITELISTED);
+
+    sc_setup.set_provider_config(&delegation_contract, exp18(3000u64));
+    sc_setup.set_provider_node_states(
+        &delegation_contract,
+        &[
+            (b"bls_key_1", b"staked"),
+            (b"bls_key_4", b"notStaked"),
+            (b"bls_key_2", b"staked"),
+            (b"bls_key_3", b"jailed"),
+        ],
+    );
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    // Anyone can sync, the owner stake, the whitelisting seed and our delegation are read.
+    // Only the keys listed under the staked label count as nodes.
+    sc_setup.sync_provider(&user, &delegation_contract);
+    let contract_data = sc_setup.get_delegation_contract_data(&delegation_contract);
+    assert_eq!(contract_data.total_staked, exp18(1101u64));
+    assert_eq!(contract_data.delegation_contract_cap, exp18(3000u64));
+    assert_eq!(contract_data.nr_nodes, 2);
+    assert_eq!(contract_data.apy, 8_000);
+    assert_eq!(contract_data.total_staked_from_ls_contract, exp18(100u64));
+
+    // An uncapped delegation contract is stored with a zero cap
+    sc_setup.set_provider_config(&delegation_contract, exp18(0u64));
+    sc_setup.sync_provider(&user, &delegation_contract);
+    let contract_data = sc_setup.get_delegation_contract_data(&delegation_contract);
+    assert_eq!(contract_data.delegation_contract_cap, exp18(0u64));
+}
+
+#[test]
+fn liquid_staking_provider_application_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_system_delegation_contract(&OWNER_ADDRESS.to_address(), 1000, 1);
+    let admin = sc_setup.setup_new_user(TestAddress::new("admin"), 10u64);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.apply_as_provider_error(
+        &admin,
+        &delegation_contract,
+        1u64,
+        ERROR_PROVIDER_APPLICATIONS_DISABLED,
+    );
+
+    sc_setup.set_provider_application_config(500, 1_500, 2, 100, 10, 4_000);
+
+    // Only delegation contracts deployed by the delegation manager can apply
+    let user_contract = sc_setup.deploy_delegation_contract(&OWNER_ADDRESS.to_address(), 100);
+    sc_setup.apply_as_provider_error(&admin, &user_contract, 1u64, ERROR_NOT_DELEGATION_CONTRACT);
+
+    sc_setup.set_provider_config(&delegation_contract, exp18(1100u64));
+    sc_setup.set_provider_node_states(
+        &delegation_contract,
+        &[(b"bls_key_1", b"staked"), (b"bls_key_2", b"jailed")],
+    );
+
+    // An application of another address than the owner is dropped and its seed sent back
+    sc_setup.apply_as_provider(&user, &delegation_contract, 5_000, 1u64);
+    sc_setup.check_user_egld_balance(&user, exp18(100u64));
+    sc_setup.check_pending_provider_applications(&[]);
+    sc_setup.check_invariants_hold();
+
+    // The seed is held by the contract and the delegation contract values are read right away
+    sc_setup.set_provider_owner(&delegation_contract, &admin);
+    sc_setup.apply_as_provider(&admin, &delegation_contract, 5_000, 1u64);
+    sc_setup.check_user_egld_balance(&admin, exp18(9u64));
+    let application = sc_setup.get_provider_application(&delegation_contract);
+    assert_eq!(application.admin_address.to_address(), admin);
+    assert_eq!(application.seed, exp18(1u64));
+    assert_eq!(application.service_fee, Some(1_000));
+    assert_eq!(application.total_staked, Some(exp18(1000u64)));
+    assert_eq!(application.delegation_contract_cap, Some(exp18(1100u64)));
+    assert_eq!(application.nr_nodes, Some(1));
+    assert_eq!(
+        application.owner.map(|owner| owner.to_address()),
+        Some(admin.clone())
+    );
+    sc_setup.check_pending_provider_applications(&[&delegation_contract]);
+    sc_setup.check_invariants_hold();
+
+    sc_setup.apply_as_provider_error(
+        &user,
+        &delegation_contract,
+        1u64,
+        ERROR_PROVIDER_APPLICATION_EXISTS,
+    );
+
+    // Not enough staked nodes
+    sc_setup.approve_provider_application_error(
+        &OWNER_ADDRESS.to_address(),
+        &delegation_contract,
+        ERROR_PROVIDER_CHECKS_FAILED,
+    );
+
+    sc_setup.set_provider_node_states(
+        &delegation_contract,
+        &[(b"bls_key_1", b"staked"), (b"bls_key_2", b"staked")],
+    );
+    sc_setup.sync_provider(&user, &delegation_contract);
+    let application = sc_setup.get_provider_application(&delegation_contract);
+    assert_eq!(application.nr_nodes, Some(2));
+
+    // Anyone can approve it once the auto approval delay has passed, with a capped APY
+    sc_setup.approve_provider_application_error(
+        &user,
+        &delegation_contract,
+        ERROR_AUTO_APPROVAL_NOT_REACHED,
+    );
+    sc_setup.b_mock.current_block().block_epoch(10u64);
+    sc_setup.approve_provider_application(&user, &delegation_contract);
+
+    let contract_data = sc_setup.get_delegation_contract_data(&delegation_contract);
+    assert_eq!(contract_data.admin_address.to_address(), admin);
+    assert_eq!(contract_data.total_staked, exp18(1000u64));
+    assert_eq!(contract_data.delegation_contract_cap, exp18(1100u64));
+    assert_eq!(contract_data.nr_nodes, 2);
+    assert_eq!(contract_data.apy, 4_000);
+    assert!(contract_data.eligible);
+    sc_setup.check_pending_provider_applications(&[]);
+    sc_setup.check_invariants_hold();
+
+    sc_setup.add_liquidity(&user, exp18(50u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.check_delegation_contract_values(&delegation_contract, exp18(50u64), exp18(0u64));
+
+    sc_setup.apply_as_provider_error(
+        &admin,
+        &delegation_contract,
+        1u64,
+        ERROR_ALREADY_WHITELISTED,
+    );
+
+    // Only the admin or a curator can cancel, the seed goes back to the admin
+    let other_delegation_contract =
+        sc_setup.deploy_system_delegation_contract(&OWNER_ADDRESS.to_address(), 500, 2);
+    sc_setup.set_provider_owner(&other_delegation_contract, &admin);
+    sc_setup.apply_as_provider(&admin, &other_delegation_contract, 5_000, 2u64);
+    sc_setup.check_user_egld_balance(&admin, exp18(7u64));
+    sc_setup.cancel_provider_application_error(
+        &user,
+        &other_delegation_contract,
+        ERROR_ONLY_DELEGATION_ADMIN,
+    );
+    sc_setup.cancel_provider_application(&admin, &other_delegation_contract);
+    sc_setup.check_user_egld_balance(&admin, exp18(9u64));
+    sc_setup.check_pending_provider_applications(&[]);
+    sc_setup.check_invariants_hold();
+
+    // Anyone can drop an expired application, which can no longer be approved
+    sc_setup.apply_as_provider(&admin, &other_delegation_contract, 5_000, 2u64);
+    sc_setup.check_user_egld_balance(&admin, exp18(7u64));
+    sc_setup
+        .b_mock
+        .current_block()
+        .block_epoch(10u64 + PROVIDER_APPLICATION_EXPIRY_EPOCHS);
+    sc_setup.approve_provider_application_error(
+        &OWNER_ADDRESS.to_address(),
+        &other_delegation_contract,
+        ERROR_PROVIDER_APPLICATION_EXPIRED,
+    );
+    sc_setup.cancel_provider_application(&user, &other_delegation_contract);
+    sc_setup.check_user_egld_balance(&admin, exp18(9u64));
+    sc_setup.check_pending_provider_applications(&[]);
+    sc_setup.check_invariants_hold();
+}
+
+#[test]
+fn liquid_staking_retire_provider_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let retiring_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 10_000);
+    let other_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 10_000);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    let retiring_stake = sc_setup.get_total_staked_from_ls_contract(&retiring_contract);
+    let other_stake = sc_setup.get_total_staked_from_ls_contract(&other_contract);
+    assert!(retiring_stake > BigUint::zero());
+
+    sc_setup.retire_provider_error(&user, &retiring_contract, ERROR_MISSING_ROLE);
+    sc_setup.retire_provider(&OWNER_ADDRESS.to_address(), &retiring_contract);
+    sc_setup.retire_provider_error(
+        &OWNER_ADDRESS.to_address(),
+        &retiring_contract,
+        ERROR_PROVIDER_RETIRING,
+    );
+    sc_setup.check_retiring_providers(&[&retiring_contract]);
+    assert!(!sc_setup.is_provider_eligible(&retiring_contract));
+
+    // The evacuation needs no pending un-delegation
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.check_delegation_contract_values(
+        &retiring_contract,
+        exp18(0u64),
+        retiring_stake.clone(),
+    );
+    assert_eq!(sc_setup.get_evacuating_egld(), retiring_stake);
+    sc_setup.check_invariants_hold();
+
+    // User un-delegations skip the retiring provider
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(10u64));
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.check_delegation_contract_values(
+        &retiring_contract,
+        exp18(0u64),
+        retiring_stake.clone(),
+    );
+    sc_setup.check_delegation_contract_values(
+        &other_contract,
+        &other_stake - &exp18(10u64),
+        exp18(10u64),
+    );
+
+    // The withdrawn stake is pending again, the seed is still delegated
+    sc_setup.b_mock.current_block().block_epoch(10u64);
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &retiring_contract);
+    assert_eq!(sc_setup.get_evacuating_egld(), BigUint::zero());
+    sc_setup.check_retiring_providers(&[&retiring_contract]);
+    sc_setup.check_invariants_hold();
+
+    // The seed is un-delegated last and sent back to the curator who whitelisted the provider,
+    // then the empty provider is deleted
+    sc_setup.check_user_egld_balance(&OWNER_ADDRESS.to_address(), exp18(0u64));
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_epoch(20u64);
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &retiring_contract);
+    sc_setup.check_user_egld_balance(&OWNER_ADDRESS.to_address(), exp18(1u64));
+    sc_setup.check_retiring_providers(&[]);
+    sc_setup.check_delegation_addresses(&[&other_contract]);
+    sc_setup.withdraw_pending_error(
+        &OWNER_ADDRESS.to_address(),
+        &retiring_contract,
+        ERROR_NOT_WHITELISTED,
+    );
+    sc_setup.check_invariants_hold();
+
+    sc_setup.delegate_rebalance_pending(&OWNER_ADDRESS.to_address());
+    sc_setup.check_delegation_contract_values(&other_contract, exp18(90u64), exp18(10u64));
+    sc_setup.check_invariants_hold();
+}
+
+#[test]
+fn liquid_staking_rebalance_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let collapsed_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 10_000);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    // A single provider holds exactly its target share
+    sc_setup.rebalance_error(
+        &OWNER_ADDRESS.to_address(),
+        exp18(10u64),
+        ERROR_NOTHING_TO_REBALANCE,
+    );
+
+    let target_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 10_000);
+    sc_setup.update_staking_contract_params(
+        &OWNER_ADDRESS.to_address(),
+        &collapsed_contract,
+        1000,
+        1500,
+        3,
+        10_000,
+        false,
+    );
+    let collapsed_stake = sc_setup.get_total_staked_from_ls_contract(&collapsed_contract);
+    let target_stake = sc_setup.get_total_staked_from_ls_contract(&target_contract);
+    let total_stake = &collapsed_stake + &target_stake;
+
+    let allocations = sc_setup.get_provider_allocations();
+    assert_eq!(allocations.len(), 2);
+    assert_eq!(allocations[0].address.to_address(), collapsed_contract);
+    assert_eq!(allocations[0].staked, collapsed_stake);
+    assert_eq!(allocations[0].target, BigUint::zero());
+    assert_eq!(allocations[1].address.to_address(), target_contract);
+    assert_eq!(allocations[1].target, total_stake);
+
+    sc_setup.rebalance_error(&user, exp18(10u64), ERROR_MISSING_ROLE);
+    sc_setup.rebalance_error(
+        &OWNER_ADDRESS.to_address(),
+        exp17(5u64),
+        ERROR_INVALID_REBALANCE_AMOUNT,
+    );
+
+    // A single call moves at most 5% of the liquid staked EGLD
+    let rebalanced = sc_setup.rebalance(&OWNER_ADDRESS.to_address(), exp18(100u64));
+    assert_eq!(
+        rebalanced,
+        &total_stake * &BigUint::from(MAX_REBALANCE_SHARE) / BigUint::from(BPS)
+    );
+    sc_setup.check_delegation_contract_values(
+        &collapsed_contract,
+        &collapsed_stake - &rebalanced,
+        rebalanced.clone(),
+    );
+    assert_eq!(sc_setup.get_evacuating_egld(), rebalanced);
+    sc_setup.check_invariants_hold();
+
+    // Once withdrawn the stake is kept apart from the pending EGLD paying user withdrawals
+    sc_setup.b_mock.current_block().block_epoch(10u64);
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &collapsed_contract);
+    assert_eq!(sc_setup.get_evacuating_egld(), BigUint::zero());
+    assert_eq!(sc_setup.get_rebalance_pending_egld(), rebalanced);
+    assert_eq!(sc_setup.get_pending_egld(), BigUint::zero());
+    sc_setup.check_invariants_hold();
+
+    // Only the provider under its target share gets it
+    sc_setup.delegate_pending_error(
+        &OWNER_ADDRESS.to_address(),
+        OptionalValue::None,
+        ERROR_INSUFFICIENT_PENDING_EGLD,
+    );
+    let delegated = sc_setup.delegate_rebalance_pending(&user);
+    assert_eq!(delegated, rebalanced);
+    assert_eq!(sc_setup.get_rebalance_pending_egld(), BigUint::zero());
+    sc_setup.check_delegation_contract_values(
+        &collapsed_contract,
+        &collapsed_stake - &rebalanced,
+        exp18(0u64),
+    );
+    sc_setup.check_delegation_contract_values(
+        &target_contract,
+        &target_stake + &rebalanced,
+        exp18(0u64),
+    );
+    sc_setup.delegate_rebalance_pending_error(&user, ERROR_INSUFFICIENT_PENDING_EGLD);
+    sc_setup.check_invariants_hold();
+}
+
+#[test]
+fn liquid_staking_delegation_failure_cooldown_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let failing_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 10_000);
+    let other_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 10_000);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 200u64);
+
+    // The delegation contract is full, the booked cap is outdated
+    sc_setup.set_provider_config(&failing_contract, exp18(1001u64));
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    assert!(!sc_setup.is_provider_eligible(&failing_contract));
+    sc_setup.check_cooling_down_providers(&[&failing_contract]);
+    let failures = sc_setup.get_provider_delegation_failures(&failing_contract);
+    assert_eq!(failures.total_failures, 1);
+    assert_eq!(failures.consecutive_failures, 1);
+    assert_eq!(failures.last_failure_epoch, 0);
+    assert_eq!(failures.readmission_epoch, 2);
+    assert!(sc_setup.get_pending_egld() > BigUint::zero());
+    sc_setup.check_invariants_hold();
+
+    // Still cooling down, the returned EGLD goes to the other provider
+    sc_setup.b_mock.current_block().block_epoch(1u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    assert!(!sc_setup.is_provider_eligible(&failing_contract));
+    sc_setup.check_delegation_contract_values(&other_contract, exp18(100u64), exp18(0u64));
+
+    // Re-admitted after the cooldown, a repeated failure doubles it
+    sc_setup.b_mock.current_block().block_epoch(2u64);
+    sc_setup.add_liquidity(&user, exp18(10u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    assert!(!sc_setup.is_provider_eligible(&failing_contract));
+    let failures = sc_setup.get_provider_delegation_failures(&failing_contract);
+    assert_eq!(failures.total_failures, 2);
+    assert_eq!(failures.consecutive_failures, 2);
+    assert_eq!(failures.last_failure_epoch, 2);
+    assert_eq!(failures.readmission_epoch, 6);
+    sc_setup.check_invariants_hold();
+
+    // A successful delegation resets the back off, the history is kept
+    sc_setup.set_provider_config(&failing_contract, exp18(0u64));
+    sc_setup.b_mock.current_block().block_epoch(6u64);
+    sc_setup.add_liquidity(&user, exp18(10u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    assert!(sc_setup.is_provider_eligible(&failing_contract));
+    sc_setup.check_cooling_down_providers(&[]);
+    assert!(sc_setup.get_total_staked_from_ls_contract(&failing_contract) > BigUint::zero());
+    let failures = sc_setup.get_provider_delegation_failures(&failing_contract);
+    assert_eq!(failures.total_failures, 2);
+    assert_eq!(failures.consecutive_failures, 0);
+    assert_eq!(failures.readmission_epoch, 0);
+    sc_setup.check_invariants_hold();
+
+    // An admin decision replaces the automatic re-admission
+    sc_setup.set_provider_config(&failing_contract, exp18(1001u64));
+    sc_setup.add_liquidity(&user, exp18(10u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.check_cooling_down_providers(&[&failing_contract]);
+    sc_setup.update_staking_contract_params(
+        &OWNER_ADDRESS.to_address(),
+        &failing_contract,
+        1000,
+        1500,
+        3,
+        10_000,
+        false,
+    );
+    sc_setup.check_cooling_down_providers(&[]);
+    assert_eq!(
+        sc_setup
+            .get_provider_delegation_failures(&failing_contract)
+            .readmission_epoch,
+        0
+    );
+}
+
+#[test]
+fn liquid_staking_scoring_config_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let cheap_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 10_000);
+    let expensive_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 10_000);
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    // Only the whitelisting epoch is known until the provider is synced
+    let scoring_data = sc_setup.get_provider_scoring_data(&cheap_contract);
+    assert_eq!(scoring_data.service_fee, None);
+    assert_eq!(scoring_data.nr_jailed_nodes, None);
+    assert_eq!(scoring_data.added_epoch, Some(0));
+
+    sc_setup.set_provider_service_fee(&cheap_contract, 500, exp18(1500u64));
+    sc_setup.set_provider_service_fee(&expensive_contract, 1_500, exp18(1500u64));
+    for delegation_contract in [&cheap_contract, &expensive_contract] {
+        sc_setup.set_provider_node_states(
+            delegation_contract,
+            &[
+                (b"bls_key_1", b"staked"),
+                (b"bls_key_2", b"staked"),
+                (b"bls_key_3", b"jailed"),
+            ],
+        );
+    }
+    sc_setup.sync_provider(&user, &cheap_contract);
+    sc_setup.sync_provider(&user, &expensive_contract);
+    let scoring_data = sc_setup.get_provider_scoring_data(&cheap_contract);
+    assert_eq!(scoring_data.service_fee, Some(500));
+    assert_eq!(scoring_data.nr_jailed_nodes, Some(1));
+    assert_eq!(
+        sc_setup
+            .get_provider_scoring_data(&expensive_contract)
+            .service_fee,
+        Some(1_500)
+    );
+
+    // The default config leaves the new dimensions out of the scores
+    let default_allocations = sc_setup.get_provider_allocations();
+    sc_setup.set_scoring_config(&OWNER_ADDRESS.to_address(), default_scoring_config());
+    let allocations = sc_setup.get_provider_allocations();
+    assert_eq!(allocations[0].target, default_allocations[0].target);
+    assert_eq!(allocations[1].target, default_allocations[1].target);
+
+    // Normalized power curves leave room to the linear dimensions, the service fee alone
+    // sets the providers apart
+    let mut config = default_scoring_config();
+    config.stake_weight = 20;
+    config.apy_weight = 40;
+    config.service_fee_weight = 30;
+    config.nodes_weight = 10;
+    sc_setup.set_scoring_config(&OWNER_ADDRESS.to_address(), config);
+    let allocations = sc_setup.get_provider_allocations();
+    assert_eq!(allocations[0].address.to_address(), cheap_contract);
+    assert!(allocations[0].target > &allocations[1].target + &exp18(5u64));
+
+    // Providers below half of the service fee range get the full service fee score, the
+    // linear curves keep all the dimensions on the same scale
+    let mut config = default_scoring_config();
+    config.stake_curve = ScoringCurve::Linear;
+    config.apy_curve = ScoringCurve::Linear;
+    config.nodes_curve = ScoringCurve::Linear;
+    config.stake_weight = 20;
+    config.apy_weight = 20;
+    config.service_fee_weight = 50;
+    config.service_fee_curve = ScoringCurve::Step(5_000);
+    sc_setup.set_scoring_config(&OWNER_ADDRESS.to_address(), config.clone());
+    let allocations = sc_setup.get_provider_allocations();
+    assert_eq!(allocations[0].address.to_address(), cheap_contract);
+    assert!(allocations[0].target > allocations[1].target);
+
+    config.service_fee_curve = ScoringCurve::Logistic(4);
+    sc_setup.set_scoring_config(&OWNER_ADDRESS.to_address(), config.clone());
+    let allocations = sc_setup.get_provider_allocations();
+    assert!(allocations[0].target > allocations[1].target);
+
+    sc_setup.set_scoring_config_error(&user, config.clone(), ERROR_MISSING_ROLE);
+
+    let mut invalid_config = config.clone();
+    invalid_config.uptime_weight = 1;
+    sc_setup.set_scoring_config_error(
+        &OWNER_ADDRESS.to_address(),
+        invalid_config,
+        ERROR_WEIGHTS_MUST_SUM_TO_100,
+    );
+
+    let mut invalid_config = config.clone();
+    invalid_config.min_age = invalid_config.max_age;
+    sc_setup.set_scoring_config_error(
+        &OWNER_ADDRESS.to_address(),
+        invalid_config,
+        ERROR_INVALID_SCORING_CONFIG,
+    );
+
+    let mut invalid_config = config.clone();
+    invalid_config.stake_curve = ScoringCurve::Power(MAX_SCORING_CURVE_EXPONENT + 1);
+    sc_setup.set_scoring_config_error(
+        &OWNER_ADDRESS.to_address(),
+        invalid_config,
+        ERROR_INVALID_SCORING_CONFIG,
+    );
+
+    let mut invalid_config = config;
+    invalid_config.top_up_curve = ScoringCurve::Step(BPS + 1);
+    sc_setup.set_scoring_config_error(
+        &OWNER_ADDRESS.to_address(),
+        invalid_config,
+        ERROR_INVALID_SCORING_CONFIG,
+    );
+}
+
+#[test]
+fn liquid_staking_roles_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let operator = sc_setup.setup_new_user(TestAddress::new("operator"), 0u64);
+    let keeper = sc_setup.setup_new_user(TestAddress::new("keeper"), 0u64);
+
+    sc_setup.set_realized_apy_scoring_error(&operator, true, ERROR_MISSING_ROLE);
+
+    sc_setup.grant_role(&operator, Role::ScoringAdmin);
+    sc_setup.grant_role(&operator, Role::Keeper);
+    sc_setup.grant_role(&keeper, Role::Keeper);
+    sc_setup.check_address_roles(&operator, &[Role::ScoringAdmin, Role::Keeper]);
+    sc_setup.check_role_members(Role::Keeper, &[&operator, &keeper]);
+
+    sc_setup.set_realized_apy_scoring(&operator, true);
+    // Roles are distinct, a keeper cannot change the scoring
+    sc_setup.set_realized_apy_scoring_error(&keeper, false, ERROR_MISSING_ROLE);
+
+    sc_setup.revoke_role(&operator, Role::ScoringAdmin);
+    sc_setup.check_address_roles(&operator, &[Role::Keeper]);
+    sc_setup.check_role_members(Role::ScoringAdmin, &[]);
+    sc_setup.set_realized_apy_scoring_error(&operator, false, ERROR_MISSING_ROLE);
+}
@@ -2,13 +2,19 @@ mod contract_interactions;
 mod contract_setup;
 mod utils;
 use contract_setup::*;
-use multiversx_sc::{imports::OptionalValue, types::{BigUint, ManagedAddress, TestAddress}};
+use multiversx_sc::{
+    imports::OptionalValue,
+    types::{BigUint, ManagedAddress, TestAddress},
+};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 use utils::*;
 
 use liquid_staking::{
-    errors::{ERROR_INSUFFICIENT_PENDING_EGLD, ERROR_INSUFFICIENT_UNSTAKE_PENDING_EGLD},
+    errors::{
+        ERROR_INSTANT_UNDELEGATE_NOT_POSSIBLE, ERROR_INSUFFICIENT_EGLD_OUT,
+        ERROR_INSUFFICIENT_PENDING_EGLD, ERROR_INSUFFICIENT_UNSTAKE_PENDING_EGLD,
+    },
     structs::UnstakeTokenAttributes,
 };
 use multiversx_sc_scenario::DebugApi;
@@ -413,7 +419,7 @@ fn undelegate_remaining_amount_distribution_test_pick_first_provider() {
         8_000u64,
     );
 
-    let sc2= sc_setup.deploy_staking_contract(
+    let sc2 = sc_setup.deploy_staking_contract(
         &OWNER_ADDRESS.to_address(),
         1000,
         1000,
@@ -437,13 +443,20 @@ fn undelegate_remaining_amount_distribution_test_pick_first_provider() {
 
     // This should trigger the remaining amount redistribution
     sc_setup.b_mock.current_block().block_epoch(50u64);
-    sc_setup.un_delegate_pending_provider(&OWNER_ADDRESS.to_address(), OptionalValue::Some(total_staked_before.clone()), ManagedAddress::from_address(&sc2));
+    sc_setup.un_delegate_pending_provider(
+        &OWNER_ADDRESS.to_address(),
+        OptionalValue::Some(total_staked_before.clone()),
+        ManagedAddress::from_address(&sc2),
+    );
 
     let staked_amount_after = sc_setup.get_total_staked_from_ls_contract(&sc1);
 
     let staked_amount_after_second = sc_setup.get_total_staked_from_ls_contract(&sc2);
     println!("staked_amount_after: {:?}", staked_amount_after);
-    println!("staked_amount_after_second: {:?}", staked_amount_after_second);
+    println!(
+        "staked_amount_after_second: {:?}",
+        staked_amount_after_second
+    );
 
     assert_eq!(staked_amount_after_second, BigUint::zero());
     assert_eq!(staked_amount_after, staked_amount_before);
@@ -874,3 +887,128 @@ fn full_over_2_first_amount_un_delegate_test() {
 
     sc_setup.check_pending_ls_for_unstake_denominated(0);
 }
+
+#[test]
+fn undelegate_min_egld_out_error_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let first_user = sc_setup.setup_new_user(TestAddress::new("first_user"), 100u64);
+
+    sc_setup.add_liquidity(&first_user, exp18(100u64), OptionalValue::None);
+
+    // 10 xEGLD are worth 10 EGLD, asking for 11 EGLD reverts
+    sc_setup.remove_liquidity_with_limits_error(
+        &first_user,
+        LS_TOKEN_ID,
+        exp18(10u64),
+        OptionalValue::Some(exp18(11u64)),
+        OptionalValue::None,
+        ERROR_INSUFFICIENT_EGLD_OUT,
+    );
+
+    sc_setup.remove_liquidity_with_limits(
+        &first_user,
+        LS_TOKEN_ID,
+        exp18(10u64),
+        OptionalValue::Some(exp18(10u64)),
+        OptionalValue::None,
+    );
+
+    sc_setup.check_contract_storage(90, 90, 0, 0, 90, 0);
+    sc_setup.check_user_egld_balance(&first_user, exp18(10u64));
+}
+
+#[test]
+fn undelegate_instant_only_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let first_user = sc_setup.setup_new_user(TestAddress::new("first_user"), 100u64);
+
+    sc_setup.add_liquidity(&first_user, exp18(100u64), OptionalValue::None);
+
+    // The pending EGLD covers the full amount, no unstake MetaESDT is minted
+    sc_setup.remove_liquidity_with_limits(
+        &first_user,
+        LS_TOKEN_ID,
+        exp18(90u64),
+        OptionalValue::Some(exp18(90u64)),
+        OptionalValue::Some(true),
+    );
+
+    sc_setup.check_contract_storage(10, 10, 0, 0, 10, 0);
+    sc_setup.check_user_egld_balance(&first_user, exp18(90u64));
+}
+
+#[test]
+fn undelegate_instant_only_error_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let first_user = sc_setup.setup_new_user(TestAddress::new("first_user"), 100u64);
+
+    sc_setup.add_liquidity(&first_user, exp18(100u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    // Nothing is pending anymore, the request would end up in the unbonding period
+    sc_setup.remove_liquidity_with_limits_error(
+        &first_user,
+        LS_TOKEN_ID,
+        exp18(10u64),
+        OptionalValue::None,
+        OptionalValue::Some(true),
+        ERROR_INSTANT_UNDELEGATE_NOT_POSSIBLE,
+    );
+
+    // Without the flag the same request mints the unstake MetaESDT
+    sc_setup.remove_liquidity_with_limits(
+        &first_user,
+        LS_TOKEN_ID,
+        exp18(10u64),
+        OptionalValue::None,
+        OptionalValue::Some(false),
+    );
+
+    sc_setup.check_contract_storage(90, 90, 0, 0, 0, 10);
+}
+
+#[test]
+fn undelegate_for_receiver_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let vault = sc_setup.setup_new_user(TestAddress::new("vault"), 100u64);
+    let second_user = sc_setup.setup_new_user(TestAddress::new("second_user"), 200u64);
+    let end_user = sc_setup.setup_new_user(TestAddress::new("end_user"), 0u64);
+
+    sc_setup.add_liquidity(&vault, exp18(100u64), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.add_liquidity(&second_user, exp17(905u64), OptionalValue::None);
+
+    // The vault burns the xEGLD, the end user receives both the instant EGLD and the unstake position
+    sc_setup.remove_liquidity_for(&vault, &end_user, LS_TOKEN_ID, exp18(90u64));
+
+    sc_setup.check_user_balance(&vault, LS_TOKEN_ID, exp18(10u64));
+    sc_setup.check_user_egld_balance(&vault, exp18(0u64));
+    sc_setup.check_user_egld_balance(&end_user, exp18(89u64));
+    sc_setup.check_user_nft_balance_denominated(
+        &end_user,
+        UNSTAKE_TOKEN_ID,
+        1,
+        exp18(1),
+        Some(UnstakeTokenAttributes::new(50, 60)),
+    );
+}
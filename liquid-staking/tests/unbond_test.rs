@@ -5,14 +5,21 @@ mod utils;
 use contract_setup::*;
 use liquid_staking::{
     errors::{
-        ERROR_BAD_PAYMENT_AMOUNT, ERROR_BAD_PAYMENT_TOKEN, ERROR_INSUFFICIENT_UNBONDED_AMOUNT,
-        ERROR_NOT_ACTIVE, ERROR_ROUNDS_NOT_PASSED, ERROR_UNSTAKE_PERIOD_NOT_PASSED,
+        ERROR_ACTION_PAUSED, ERROR_BAD_PAYMENT_AMOUNT, ERROR_BAD_PAYMENT_TOKEN,
+        ERROR_EARLY_REDEMPTION_DISABLED, ERROR_INSUFFICIENT_EGLD_OUT,
+        ERROR_INSUFFICIENT_PENDING_EGLD, ERROR_INSUFFICIENT_UNBONDED_AMOUNT,
+        ERROR_INVALID_BATCH_SIZE, ERROR_INVALID_SPLIT_AMOUNT, ERROR_INVALID_WITHDRAW_AMOUNT,
+        ERROR_NOTHING_TO_CLAIM, ERROR_NOT_ACTIVE, ERROR_NOT_WITHDRAW_TICKET_OWNER,
+        ERROR_NO_WITHDRAWABLE_PROVIDERS, ERROR_ROUNDS_NOT_PASSED, ERROR_UNBOND_PERIOD_PASSED,
+        ERROR_UNSTAKE_PERIOD_NOT_PASSED, ERROR_WITHDRAW_QUEUE_NOT_EMPTY,
+        ERROR_WITHDRAW_TICKET_TOO_SMALL,
     },
+    proxy::proxy_liquid_staking::PausableAction,
     structs::UnstakeTokenAttributes,
 };
 use multiversx_sc::{
     imports::OptionalValue,
-    types::{TestAddress, TestTokenIdentifier},
+    types::{ManagedAddress, TestAddress, TestTokenIdentifier},
 };
 use utils::*;
 
@@ -80,6 +87,38 @@ fn liquid_staking_unbond_success_test() {
     sc_setup.check_contract_storage(10, 10, 0, 0, 0, 0);
 }
 
+#[test]
+fn liquid_staking_withdraw_for_receiver_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let vault = sc_setup.setup_new_user(TestAddress::new("vault"), 100u64);
+    let end_user = sc_setup.setup_new_user(TestAddress::new("end_user"), 0u64);
+
+    sc_setup.add_liquidity(&vault, exp18(100u64), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&vault, LS_TOKEN_ID, exp18(90u64));
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(61u64);
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &delegation_contract);
+    sc_setup.check_contract_storage(10, 10, 0, 90, 0, 0);
+
+    // The vault sends the position, the end user receives the EGLD
+    sc_setup.withdraw_for(&vault, &end_user, UNSTAKE_TOKEN_ID, 1, exp18(90));
+
+    sc_setup.check_user_egld_balance(&end_user, exp18(90u64));
+    sc_setup.check_user_egld_balance(&vault, exp18(0u64));
+    sc_setup.check_contract_storage(10, 10, 0, 0, 0, 0);
+}
+
 #[test]
 fn liquid_staking_unbond_error_epoch_too_soon_test() {
     DebugApi::dummy();
@@ -219,6 +258,217 @@ fn liquid_staking_unbond_partial_withdraw_pending_test() {
     );
 }
 
+#[test]
+fn liquid_staking_withdraw_pending_batch_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(90u64));
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.check_provider_pending_unbonds(&delegation_contract, &[60]);
+
+    // Nothing is claimable before the unbond epoch, no promise is issued
+    sc_setup.b_mock.current_block().block_epoch(55u64);
+    sc_setup.check_withdrawable_providers(&[]);
+    sc_setup.withdraw_pending_batch_error(
+        &OWNER_ADDRESS.to_address(),
+        5,
+        ERROR_NO_WITHDRAWABLE_PROVIDERS,
+    );
+
+    sc_setup.b_mock.current_block().block_epoch(60u64);
+    sc_setup.check_withdrawable_providers(&[&delegation_contract]);
+    sc_setup.withdraw_pending_batch_error(&OWNER_ADDRESS.to_address(), 0, ERROR_INVALID_BATCH_SIZE);
+
+    assert_eq!(
+        sc_setup.withdraw_pending_batch(&OWNER_ADDRESS.to_address(), 5),
+        1
+    );
+    sc_setup.check_contract_storage(10, 10, 0, 90, 0, 0);
+    sc_setup.check_delegation_contract_values(&delegation_contract, exp18(10), exp18(0));
+    sc_setup.check_provider_pending_unbonds(&delegation_contract, &[]);
+
+    // The withdrawn provider is not called again
+    sc_setup.check_withdrawable_providers(&[]);
+    sc_setup.withdraw_pending_batch_error(
+        &OWNER_ADDRESS.to_address(),
+        5,
+        ERROR_NO_WITHDRAWABLE_PROVIDERS,
+    );
+}
+
+#[test]
+fn liquid_staking_provider_unbonding_ledger_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    // Two un-delegations from the same provider, unbonding in different epochs
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(40u64));
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(52u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(50u64));
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.check_provider_pending_unbonds(&delegation_contract, &[60, 62]);
+    sc_setup.check_provider_unbonding_amounts(&delegation_contract, 0, 90);
+
+    sc_setup.b_mock.current_block().block_epoch(61u64);
+    sc_setup.check_provider_unbonding_amounts(&delegation_contract, 40, 50);
+    sc_setup.check_unbonding_ledger(&[(&delegation_contract, 40, 50)]);
+
+    // The withdrawn EGLD consumes the ledger entries
+    sc_setup.b_mock.current_block().block_epoch(62u64);
+    sc_setup.check_provider_unbonding_amounts(&delegation_contract, 90, 0);
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &delegation_contract);
+
+    sc_setup.check_contract_storage(10, 10, 0, 90, 0, 0);
+    sc_setup.check_provider_pending_unbonds(&delegation_contract, &[]);
+    sc_setup.check_provider_unbonding_amounts(&delegation_contract, 0, 0);
+    sc_setup.check_unbonding_ledger(&[]);
+}
+
+#[test]
+fn liquid_staking_withdraw_queue_fifo_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 40u64);
+    let second_user = sc_setup.setup_new_user(TestAddress::new("second_user"), 100u64);
+    let third_user = sc_setup.setup_new_user(TestAddress::new("third_user"), 90u64);
+
+    sc_setup.add_liquidity(&user, exp18(40u64), OptionalValue::None);
+    sc_setup.add_liquidity(&second_user, exp18(100u64), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    // Only the first position is un-delegated from the provider
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(40u64));
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(51u64);
+    sc_setup.remove_liquidity(&second_user, LS_TOKEN_ID, exp18(90u64));
+
+    // Both positions are unbonded, the second user queues first
+    sc_setup.b_mock.current_block().block_epoch(61u64);
+    sc_setup.request_withdraw_error(&user, 1, exp17(5u64), ERROR_WITHDRAW_TICKET_TOO_SMALL);
+    let second_ticket = sc_setup.request_withdraw(&second_user, &[(2, 90)]);
+    let first_ticket = sc_setup.request_withdraw(&user, &[(1, 30)]);
+    sc_setup.check_withdraw_queue_length(2);
+    sc_setup.check_withdraw_ticket(second_ticket, 90, 0);
+    sc_setup.check_withdraw_ticket(first_ticket, 30, 0);
+
+    // The 40 EGLD arriving from the provider go to the oldest ticket
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &delegation_contract);
+    sc_setup.check_withdraw_ticket(second_ticket, 90, 40);
+    sc_setup.check_withdraw_ticket(first_ticket, 30, 0);
+    sc_setup.check_contract_storage(10, 10, 0, 0, 0, 90);
+
+    sc_setup.claim_withdraw_error(&user, &[first_ticket], ERROR_NOTHING_TO_CLAIM);
+    sc_setup.claim_withdraw_error(&user, &[second_ticket], ERROR_NOT_WITHDRAW_TICKET_OWNER);
+
+    // The rest of the first position cannot skip the queue
+    sc_setup.withdraw_error(
+        &user,
+        UNSTAKE_TOKEN_ID,
+        1,
+        exp18(10),
+        ERROR_WITHDRAW_QUEUE_NOT_EMPTY,
+    );
+
+    sc_setup.claim_withdraw(&second_user, &[second_ticket]);
+    sc_setup.check_user_egld_balance(&second_user, exp18(40u64));
+    sc_setup.check_withdraw_ticket(second_ticket, 50, 0);
+
+    // A delegation netted against the pending un-delegations fills the queue, the rest stays withdrawable
+    sc_setup.add_liquidity(&third_user, exp18(90u64), OptionalValue::None);
+    sc_setup.check_withdraw_queue_length(0);
+    sc_setup.check_contract_storage(100, 100, 0, 10, 0, 0);
+
+    sc_setup.withdraw(&user, UNSTAKE_TOKEN_ID, 1, exp18(10));
+    sc_setup.claim_withdraw(&second_user, &[second_ticket]);
+    sc_setup.claim_withdraw(&user, &[first_ticket]);
+    sc_setup.check_user_egld_balance(&second_user, exp18(90u64));
+    sc_setup.check_user_egld_balance(&user, exp18(40u64));
+    sc_setup.check_contract_storage(100, 100, 0, 0, 0, 0);
+}
+
+#[test]
+fn liquid_staking_withdraw_queue_leftover_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 60u64);
+    let small_user = sc_setup.setup_new_user(TestAddress::new("small_user"), 1u64);
+
+    sc_setup.add_liquidity(&user, exp18(60u64), OptionalValue::None);
+    sc_setup.add_liquidity(&small_user, exp18(1u64), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_round(14000u64);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(51u64));
+    sc_setup.remove_liquidity(&small_user, LS_TOKEN_ID, exp17(5u64));
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    // More tickets than a single fill touches
+    sc_setup.b_mock.current_block().block_epoch(60u64);
+    for _ in 0..51 {
+        sc_setup.request_withdraw(&user, &[(1, 1)]);
+    }
+
+    // Too small for a ticket, and the queue is owed everything that arrived
+    sc_setup.request_withdraw_error(&small_user, 1, exp17(5u64), ERROR_WITHDRAW_TICKET_TOO_SMALL);
+    sc_setup.withdraw_error(
+        &small_user,
+        UNSTAKE_TOKEN_ID,
+        1,
+        exp17(5),
+        ERROR_WITHDRAW_QUEUE_NOT_EMPTY,
+    );
+
+    // The fill stops with one ticket left, only the EGLD above what it is owed is withdrawable
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &delegation_contract);
+    sc_setup.check_withdraw_queue_length(1);
+    sc_setup.withdraw(&small_user, UNSTAKE_TOKEN_ID, 1, exp17(5));
+    sc_setup.check_user_egld_balance(&small_user, exp17(5u64));
+
+    // The last ticket still gets its EGLD
+    sc_setup.fill_withdraw_queue(&OWNER_ADDRESS.to_address());
+    sc_setup.check_withdraw_queue_length(0);
+}
+
 #[test]
 fn delegate_pending_error_rounds_not_passed_test() {
     DebugApi::dummy();
@@ -351,3 +601,393 @@ fn liquid_staking_unbond_error_bad_token_test() {
         .set_esdt_balance(&user, BAD_TOKEN_ID.as_bytes(), exp18(100));
     sc_setup.withdraw_error(&user, BAD_TOKEN_ID, 0, exp18(100), ERROR_BAD_PAYMENT_TOKEN);
 }
+
+#[test]
+fn liquid_staking_early_redemption_netting_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+    let rewards_user = sc_setup.setup_new_user(TestAddress::new("rewards_user"), 20u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(10u64));
+    sc_setup.add_rewards(&rewards_user, exp18(20u64));
+    sc_setup.check_contract_storage(90, 110, 0, 0, 20, 10);
+
+    // 10% right after un-delegation, 1% right before the unbond epoch, fee kept by the protocol
+    sc_setup.set_early_redemption_config(1000, 100, false);
+    sc_setup.check_early_redemption_quote(1, exp18(10u64), exp18(9u64), exp18(1u64));
+
+    sc_setup.redeem_early(&user, 1, exp18(10u64), OptionalValue::Some(exp18(9u64)));
+
+    // The pending un-delegation is cancelled, the stake stays with the pool
+    sc_setup.check_contract_storage(90, 110, 1, 0, 10, 0);
+    sc_setup.check_pending_egld_for_restake(0);
+    sc_setup.check_user_egld_balance(&user, exp18(9u64));
+}
+
+#[test]
+fn liquid_staking_early_redemption_restake_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+    let rewards_user = sc_setup.setup_new_user(TestAddress::new("rewards_user"), 30u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(20u64));
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.add_rewards(&rewards_user, exp18(30u64));
+    sc_setup.check_contract_storage(80, 110, 0, 0, 30, 0);
+
+    // Half of the unbonding period left, 5% fee accrued to the xEGLD holders
+    sc_setup.set_early_redemption_config(1000, 0, true);
+    sc_setup.b_mock.current_block().block_epoch(55u64);
+    sc_setup.check_early_redemption_quote(1, exp18(20u64), exp18(19u64), exp18(1u64));
+
+    sc_setup.redeem_early(&user, 1, exp18(20u64), OptionalValue::None);
+
+    sc_setup.check_contract_storage(80, 111, 0, 0, 11, 0);
+    sc_setup.check_pending_egld_for_restake(20);
+    sc_setup.check_user_egld_balance(&user, exp18(19u64));
+
+    // The unbonded EGLD returns to the pool instead of the withdrawable balance
+    sc_setup.b_mock.current_block().block_epoch(61u64);
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &delegation_contract);
+
+    sc_setup.check_contract_storage(80, 111, 0, 0, 31, 0);
+    sc_setup.check_pending_egld_for_restake(0);
+}
+
+#[test]
+fn liquid_staking_early_redemption_matured_claims_first_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let first_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 1000);
+    let second_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 3, 1000);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+    let late_user = sc_setup.setup_new_user(TestAddress::new("late_user"), 100u64);
+    let rewards_user = sc_setup.setup_new_user(TestAddress::new("rewards_user"), 30u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.add_liquidity(&late_user, exp18(100u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    // The first position unbonds at epoch 60 on the first provider
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(10u64));
+    sc_setup.un_delegate_pending_provider(
+        &OWNER_ADDRESS.to_address(),
+        OptionalValue::None,
+        ManagedAddress::from_address(&first_contract),
+    );
+
+    // The second one unbonds at epoch 65 on the second provider and is redeemed early
+    sc_setup.b_mock.current_block().block_epoch(55u64);
+    sc_setup.remove_liquidity(&late_user, LS_TOKEN_ID, exp18(20u64));
+    sc_setup.un_delegate_pending_provider(
+        &OWNER_ADDRESS.to_address(),
+        OptionalValue::None,
+        ManagedAddress::from_address(&second_contract),
+    );
+    sc_setup.add_rewards(&rewards_user, exp18(30u64));
+    sc_setup.set_early_redemption_config(1000, 0, true);
+    sc_setup.b_mock.current_block().block_epoch(56u64);
+    sc_setup.redeem_early(&late_user, 2, exp18(20u64), OptionalValue::None);
+    sc_setup.check_pending_egld_for_restake(20);
+
+    // The EGLD unbonded at epoch 60 belongs to the matured position, not to the redeemed one
+    sc_setup.b_mock.current_block().block_epoch(60u64);
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &first_contract);
+    sc_setup.check_pending_egld_for_restake(20);
+    sc_setup.withdraw(&user, UNSTAKE_TOKEN_ID, 1, exp18(10u64));
+    sc_setup.check_user_egld_balance(&user, exp18(10u64));
+
+    // The redeemed position goes back to the pool once its own EGLD is unbonded
+    sc_setup.b_mock.current_block().block_epoch(65u64);
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &second_contract);
+    sc_setup.check_pending_egld_for_restake(0);
+}
+
+#[test]
+fn liquid_staking_early_redemption_error_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+    let rewards_user = sc_setup.setup_new_user(TestAddress::new("rewards_user"), 20u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(10u64));
+
+    sc_setup.redeem_early_error(
+        &user,
+        1,
+        exp18(10u64),
+        OptionalValue::None,
+        ERROR_EARLY_REDEMPTION_DISABLED,
+    );
+
+    sc_setup.set_early_redemption_config(1000, 100, false);
+
+    // Nothing is pending to pay the position
+    sc_setup.redeem_early_error(
+        &user,
+        1,
+        exp18(10u64),
+        OptionalValue::None,
+        ERROR_INSUFFICIENT_PENDING_EGLD,
+    );
+
+    sc_setup.add_rewards(&rewards_user, exp18(20u64));
+
+    sc_setup.pause_actions(
+        &OWNER_ADDRESS.to_address(),
+        &[PausableAction::EarlyRedemption],
+    );
+    sc_setup.redeem_early_error(
+        &user,
+        1,
+        exp18(10u64),
+        OptionalValue::None,
+        ERROR_ACTION_PAUSED,
+    );
+    sc_setup.unpause_actions(
+        &OWNER_ADDRESS.to_address(),
+        &[PausableAction::EarlyRedemption],
+    );
+
+    sc_setup.redeem_early_error(
+        &user,
+        1,
+        exp18(10u64),
+        OptionalValue::Some(exp18(10u64)),
+        ERROR_INSUFFICIENT_EGLD_OUT,
+    );
+
+    sc_setup.b_mock.current_block().block_epoch(60u64);
+    sc_setup.redeem_early_error(
+        &user,
+        1,
+        exp18(10u64),
+        OptionalValue::None,
+        ERROR_UNBOND_PERIOD_PASSED,
+    );
+}
+
+#[test]
+fn liquid_staking_withdraw_amount_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(90u64));
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.withdraw_amount_error(
+        &user,
+        1,
+        exp18(90u64),
+        exp18(40u64),
+        ERROR_UNSTAKE_PERIOD_NOT_PASSED,
+    );
+
+    sc_setup.b_mock.current_block().block_epoch(61u64);
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &delegation_contract);
+    sc_setup.check_contract_storage(10, 10, 0, 90, 0, 0);
+
+    sc_setup.withdraw_amount_error(
+        &user,
+        1,
+        exp18(40u64),
+        exp18(50u64),
+        ERROR_INVALID_WITHDRAW_AMOUNT,
+    );
+
+    sc_setup.withdraw_amount(&user, 1, exp18(90u64), exp18(40u64));
+
+    sc_setup.check_contract_storage(10, 10, 0, 50, 0, 0);
+    sc_setup.check_user_egld_balance(&user, exp18(40u64));
+    sc_setup.check_user_nft_balance_denominated(
+        &user,
+        UNSTAKE_TOKEN_ID,
+        1,
+        exp18(50),
+        Some(UnstakeTokenAttributes::new(50, 60)),
+    );
+
+    sc_setup.withdraw_amount(&user, 1, exp18(50u64), exp18(50u64));
+
+    sc_setup.check_contract_storage(10, 10, 0, 0, 0, 0);
+    sc_setup.check_user_egld_balance(&user, exp18(90u64));
+}
+
+#[test]
+fn liquid_staking_split_unstake_tokens_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    let delegation_contract =
+        sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(90u64));
+
+    sc_setup.split_unstake_tokens_error(
+        &user,
+        1,
+        exp18(90u64),
+        exp18(90u64),
+        ERROR_INVALID_SPLIT_AMOUNT,
+    );
+    sc_setup.split_unstake_tokens_error(
+        &user,
+        1,
+        exp18(90u64),
+        exp18(0u64),
+        ERROR_INVALID_SPLIT_AMOUNT,
+    );
+
+    let (first_part, second_part) =
+        sc_setup.split_unstake_tokens(&user, 1, exp18(90u64), exp18(30u64));
+    assert_eq!(first_part, exp18(30u64));
+    assert_eq!(second_part, exp18(60u64));
+
+    // The split part moves to a new nonce with the attributes of the original position
+    sc_setup.check_user_nft_balance_denominated(
+        &user,
+        UNSTAKE_TOKEN_ID,
+        1,
+        exp18(60),
+        Some(UnstakeTokenAttributes::new(50, 60)),
+    );
+    sc_setup.check_user_nft_balance_denominated(
+        &user,
+        UNSTAKE_TOKEN_ID,
+        2,
+        exp18(30),
+        Some(UnstakeTokenAttributes::new(50, 60)),
+    );
+    sc_setup.check_contract_storage(10, 10, 0, 0, 0, 90);
+
+    // Each part is withdrawn on its own once unbonded
+    sc_setup.un_delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+    sc_setup.b_mock.current_block().block_epoch(60u64);
+    sc_setup.withdraw_pending(&OWNER_ADDRESS.to_address(), &delegation_contract);
+    sc_setup.withdraw(&user, UNSTAKE_TOKEN_ID, 2, exp18(30));
+    sc_setup.check_user_egld_balance(&user, exp18(30u64));
+    sc_setup.check_contract_storage(10, 10, 0, 60, 0, 0);
+}
+
+#[test]
+fn liquid_staking_merge_unstake_tokens_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(30u64));
+
+    sc_setup.b_mock.current_block().block_epoch(52u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(20u64));
+
+    sc_setup.merge_unstake_tokens(&user, &[(1, 30), (2, 20)]);
+
+    // Everything now unbonds with the latest position
+    sc_setup.check_user_nft_balance_denominated(
+        &user,
+        UNSTAKE_TOKEN_ID,
+        2,
+        exp18(50),
+        Some(UnstakeTokenAttributes::new(52, 62)),
+    );
+    sc_setup.check_contract_storage(50, 50, 0, 0, 0, 50);
+
+    sc_setup.b_mock.current_block().block_epoch(61u64);
+    sc_setup.withdraw_error(
+        &user,
+        UNSTAKE_TOKEN_ID,
+        2,
+        exp18(50),
+        ERROR_UNSTAKE_PERIOD_NOT_PASSED,
+    );
+}
+
+#[test]
+fn liquid_staking_split_nonce_epoch_cleanup_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(400);
+
+    sc_setup.deploy_staking_contract(&OWNER_ADDRESS.to_address(), 1000, 1000, 1500, 0, 0);
+
+    let user = sc_setup.setup_new_user(TestAddress::new("user"), 100u64);
+
+    sc_setup.add_liquidity(&user, exp18(100u64), OptionalValue::None);
+    sc_setup.delegate_pending(&OWNER_ADDRESS.to_address(), OptionalValue::None);
+
+    sc_setup.b_mock.current_block().block_epoch(50u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(50u64));
+
+    // Positions are not reshaped while the exits are paused
+    sc_setup.pause_actions(&OWNER_ADDRESS.to_address(), &[PausableAction::Withdraw]);
+    sc_setup.split_unstake_tokens_error(&user, 1, exp18(50u64), exp18(20u64), ERROR_ACTION_PAUSED);
+    sc_setup.unpause_actions(&OWNER_ADDRESS.to_address(), &[PausableAction::Withdraw]);
+
+    // The split nonce is the latest nonce when the next unbond epoch is created
+    sc_setup.split_unstake_tokens(&user, 1, exp18(50u64), exp18(20u64));
+    assert_eq!(sc_setup.get_unstake_token_nonce(60), 1);
+
+    sc_setup.b_mock.current_block().block_epoch(51u64);
+    sc_setup.remove_liquidity(&user, LS_TOKEN_ID, exp18(10u64));
+
+    // The previous epoch nonce is cleaned, not the split nonce
+    assert_eq!(sc_setup.get_unstake_token_nonce(60), 0);
+    assert_eq!(sc_setup.get_unstake_token_nonce(61), 3);
+    sc_setup.check_user_nft_balance_denominated(
+        &user,
+        UNSTAKE_TOKEN_ID,
+        2,
+        exp18(20),
+        Some(UnstakeTokenAttributes::new(50, 60)),
+    );
+}
@@ -6,10 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           57
+// Endpoints:                          138
 // Async Callback:                       1
-// Promise callbacks:                    6
-// Total number of exported functions:  66
+// Promise callbacks:                   13
+// Total number of exported functions: 153
 
 #![no_std]
 
@@ -22,45 +22,83 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         delegate => delegate
+        delegateWithMinOut => delegate_with_min_out
+        delegateFor => delegate_for
         unDelegate => un_delegate
+        unDelegateFor => un_delegate_for
         withdraw => withdraw
+        withdrawFor => withdraw_for
+        withdrawAmount => withdraw_amount
+        splitUnstakeTokens => split_unstake_tokens
+        mergeUnstakeTokens => merge_unstake_tokens
         getLsValueForPosition => get_ls_value_for_position
         getEgldPositionValue => get_egld_position_value
         getExchangeRate => get_exchange_rate
+        getTwapExchangeRate => get_twap_exchange_rate
+        getRateSnapshots => get_rate_snapshots
+        getExchangeRateGrowth => get_exchange_rate_growth
         getDelegationContractStakedAmount => get_delegation_contract_staked_amount
         getDelegationContractUnstakedAmount => get_delegation_contract_unstaked_amount
+        getProviderPendingUnbonds => get_provider_pending_unbonds
+        getProviderUnbondingAmounts => get_provider_unbonding_amounts
+        getProviderDelegationFailures => get_provider_delegation_failures
+        getUnbondingLedger => get_unbonding_ledger
+        getWithdrawableProviders => get_withdrawable_providers
+        getAccruedFees => get_accrued_fees
+        checkInvariants => check_invariants
         registerLsToken => register_ls_token
         registerUnstakeToken => register_unstake_token
         setStateActive => set_state_active
         setStateInactive => set_state_inactive
-        setAccumulatorContract => set_accumulator_contract
-        setFees => set_fees
+        pauseActions => pause_actions
+        unpauseActions => unpause_actions
+        isActionPaused => is_action_paused
         setMaxAddresses => set_max_addresses
-        setMaxSelectedProviders => set_max_selected_providers
-        setUnbondPeriod => set_unbond_period
-        addManagers => set_managers
-        removeManager => remove_manager
+        grantRole => grant_role
+        revokeRole => revoke_role
+        getAddressRoles => get_address_roles
         addLiquidityProvider => add_liquidity_provider
         removeLiquidityProviders => remove_liquidity_provider
         setScoringConfig => set_scoring_config
+        setRealizedApyScoring => set_realized_apy_scoring
         delegatePending => delegate_pending
         unDelegatePending => un_delegate_pending
+        rebalance => rebalance
+        delegateRebalancePending => delegate_rebalance_pending
+        getProviderAllocations => get_provider_allocations
         withdrawPending => withdraw_pending
+        withdrawPendingBatch => withdraw_pending_batch
+        reconcileProviders => reconcile_providers
         claimRewards => claim_rewards
         claimFees => claim_fees
         getDelegationAddressesList => delegation_addresses_list
         getUnDelegationAddressesList => un_delegation_addresses_list
         getDelegationContractInfo => delegation_contract_data
-        getManagers => managers
+        getRoleMembers => role_members
+        getPausedActions => paused_actions
         getLiquidityProviders => liquidity_providers
         getScoringConfig => scoring_config
         fees => fees
+        getLastFeesChangeEpoch => last_fees_change_epoch
         getAccumulatorContract => accumulator_contract
+        getFeeRecipients => fee_recipients
+        getFeeMode => fee_mode
+        getReferralFeeShare => referral_fee_share
+        getReferrerByCode => referrer_by_code
+        getUserReferrer => user_referrer
+        getUserReferredLs => user_referred_ls
+        getUserReferralExpiryEpoch => user_referral_expiry_epoch
+        getTotalReferredLs => total_referred_ls
+        getReferralRewardsReserve => referral_rewards_reserve
+        getInsuranceShare => insurance_share
+        getInsuranceReserve => insurance_reserve
+        getRecordedLosses => recorded_losses
         getState => state
         getLsTokenId => ls_token
         getLsSupply => ls_token_supply
         getVirtualEgldReserve => virtual_egld_reserve
         getFeesReserve => fees_reserve
+        getLsFeesReserve => ls_fees_reserve
         getTotalWithdrawnEgld => total_withdrawn_egld
         getUnstakeTokenId => unstake_token
         getPendingEGLDForDelegate => pending_egld
@@ -70,18 +108,68 @@ multiversx_sc_wasm_adapter::endpoints! {
         maxDelegationAddresses => max_delegation_addresses
         maxSelectedProviders => max_selected_providers
         unbondPeriod => unbond_period
+        getLastRateObservation => last_rate_observation
+        isRealizedApyScoring => realized_apy_scoring
+        getEarlyRedemptionConfig => early_redemption_config
+        getPendingEgldForRestake => pending_egld_for_restake
+        getWithdrawTicketsReserve => withdraw_tickets_reserve
+        getConfigTimelockDelay => config_timelock_delay
+        getProviderApplicationConfig => provider_application_config
+        getRetiringProviders => retiring_providers
+        getEvacuatingEgld => evacuating_egld
+        getRebalancePendingEgld => rebalance_pending_egld
+        getCoolingDownProviders => cooling_down_providers
         migrate => migrate
         migratePending => migrate_pending
         addRewards => add_rewards
-        setMigrationScAddress => add_migration_sc_address
         getMigrationScAddress => migration_sc_address
+        getProviderScoringData => get_provider_scoring_data
         whitelistDelegationContract => whitelist_delegation_contract
         changeDelegationContractAdmin => change_delegation_contract_admin
         changeDelegationContractParams => change_delegation_contract_params
+        retireProvider => retire_provider
+        syncProvider => sync_provider
+        getRealizedApr => get_realized_apr
+        getProviderRealizedApy => get_provider_realized_apy
+        getEpochSnapshots => get_epoch_snapshots
+        setEarlyRedemptionConfig => set_early_redemption_config
+        disableEarlyRedemption => disable_early_redemption
+        redeemEarly => redeem_early
+        getEarlyRedemptionQuote => get_early_redemption_quote
+        requestWithdraw => request_withdraw
+        claimWithdraw => claim_withdraw
+        fillWithdrawQueue => fill_withdraw_queue
+        getWithdrawTicket => get_withdraw_ticket
+        getUserWithdrawTickets => get_user_withdraw_tickets
+        getWithdrawQueueLength => get_withdraw_queue_length
+        proposeConfigChange => propose_config_change
+        executeConfigChange => execute_config_change
+        cancelConfigChange => cancel_config_change
+        getConfigProposal => get_config_proposal
+        getPendingConfigProposals => get_pending_config_proposals
+        registerReferrer => register_referrer
+        claimReferralRewards => claim_referral_rewards
+        releaseExpiredReferrals => release_expired_referrals
+        getReferrer => get_referrer
+        recordProviderLoss => record_provider_loss
+        socializeLosses => socialize_losses
+        setProviderApplicationConfig => set_provider_application_config
+        applyAsProvider => apply_as_provider
+        approveProviderApplication => approve_provider_application
+        cancelProviderApplication => cancel_provider_application
+        getProviderApplication => get_provider_application
+        getPendingProviderApplications => get_pending_provider_applications
         remove_liquidity_callback => remove_liquidity_callback
         add_liquidity_callback => add_liquidity_callback
+        rebalance_delegation_callback => rebalance_delegation_callback
         withdraw_tokens_callback => withdraw_tokens_callback
         claim_rewards_callback => claim_rewards_callback
+        reconcile_provider_callback => reconcile_provider_callback
+        evacuate_provider_callback => evacuate_provider_callback
+        evacuate_seed_callback => evacuate_seed_callback
+        sync_contract_config_callback => sync_contract_config_callback
+        sync_total_stake_callback => sync_total_stake_callback
+        sync_node_states_callback => sync_node_states_callback
         whitelist_delegation_contract_callback => whitelist_delegation_contract_callback
         instant_delegation_contract_callback => instant_delegation_contract_callback
     )